@@ -12,6 +12,8 @@ Event System Integration:
 from __future__ import annotations
 
 
+import asyncio
+import hmac
 import logging
 import os
 import sys
@@ -20,9 +22,10 @@ from contextlib import asynccontextmanager
 from datetime import datetime
 from typing import Annotated, Any
 
-from fastapi import FastAPI
+from fastapi import BackgroundTasks, Depends, FastAPI, HTTPException, Query, Request
 from fastapi.middleware.cors import CORSMiddleware
-from pydantic import BaseModel
+from fastapi.responses import JSONResponse, PlainTextResponse
+from pydantic import BaseModel, field_validator
 
 # Ensure backend is in path
 project_root = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
@@ -30,11 +33,15 @@ if project_root not in sys.path:
     sys.path.insert(0, project_root)
 
 from core.events import (
+    EVENT_SCHEMA_VERSION,
     EventType,
     ScraperEvent,
     create_emitter,
     event_bus,
 )
+from core.health import HEALTH_FAILED, runner_health
+from core.instance import load_runner_tags
+from core.sleep_inhibitor import sleep_inhibitor
 
 logger = logging.getLogger(__name__)
 
@@ -87,6 +94,7 @@ class JobState:
             self.total_skus = 0
             self.completed_skus = 0
             self.worker_stats = {}
+            runner_health.set_current_job(job_id)
             return self.stop_event
 
     def add_log(self, message: str):
@@ -133,6 +141,7 @@ class JobState:
             self.is_running = False
             self.progress = 100.0
             self.active_scrapers = set()
+            runner_health.set_current_job(None)
 
     def to_dict(self) -> dict:
         with self._lock:
@@ -226,6 +235,9 @@ class StatusResponse(BaseModel):
     completed_skus: int = 0
     eta_seconds: int | None = None
     workers: dict = {}
+    health: dict = {}
+    location_tag: str | None = None
+    labels: list[str] = []
 
 
 class StopResponse(BaseModel):
@@ -233,6 +245,12 @@ class StopResponse(BaseModel):
     message: str
 
 
+class QuickCheckRequest(BaseModel):
+    scraper: str
+    skus: list[str]
+    headless: bool = True
+
+
 # =============================================================================
 # Scraper Runner (Background Task)
 # =============================================================================
@@ -249,6 +267,7 @@ def run_scraper_job(
     job_id: str,
 ):
     """Run the scraper in a background task."""
+    sleep_inhibitor.acquire(f"job {job_id}")
     try:
         from api.debug_context import debug_context
         from scrapers.runtime import run_scraping
@@ -324,6 +343,8 @@ def run_scraper_job(
             emitter.job_failed(error=str(e))
         except Exception:
             pass  # Don't fail if event emission fails
+    finally:
+        sleep_inhibitor.release()
 
 
 # =============================================================================
@@ -366,6 +387,45 @@ async def health_check():
     return {"status": "healthy", "version": "1.0.0"}
 
 
+@app.get("/healthz")
+async def healthz(request: Request):
+    """Runner health for external monitors. Returns 503 when the runner is failed.
+
+    Set HEALTHZ_TOKEN to require an `Authorization: Bearer <token>` header.
+    """
+    token = os.environ.get("HEALTHZ_TOKEN")
+    if token and not hmac.compare_digest(request.headers.get("authorization", ""), f"Bearer {token}"):
+        return JSONResponse(status_code=401, content={"detail": "Invalid or missing bearer token"})
+
+    snapshot = runner_health.snapshot()
+    status_code = 503 if snapshot["status"] == HEALTH_FAILED else 200
+    return JSONResponse(status_code=status_code, content=snapshot)
+
+
+@app.get("/api-requests")
+async def api_requests():
+    """Metadata of the last coordinator API requests (never bodies), with their success rate."""
+    from core.api_request_log import api_request_log
+
+    return {"requests": api_request_log.entries(), "success_rate": api_request_log.success_rate()}
+
+
+@app.get("/version")
+async def version():
+    """Sidecar, Python, Playwright and browser versions plus OS/arch, for support triage."""
+    from core.version_info import collect_version_info
+
+    return collect_version_info()
+
+
+@app.get("/metrics")
+async def metrics():
+    """Prometheus metrics. Uses the same samples as the heartbeat metrics push."""
+    from scrapers.ai_metrics import get_metrics_collector
+
+    return PlainTextResponse(get_metrics_collector().get_prometheus_metrics(), media_type="text/plain; version=0.0.4")
+
+
 @app.post("/scrape", response_model=ScrapeResponse)
 async def start_scrape(
     request: ScrapeRequest,
@@ -412,6 +472,8 @@ async def start_scrape(
 async def get_status(job_state: JobStateDep):
     """Get the current scraper status."""
     state = job_state.to_dict()
+    state["health"] = runner_health.snapshot()
+    state.update(load_runner_tags())
     return StatusResponse(**state)
 
 
@@ -436,6 +498,39 @@ async def stop_scrape(job_state: JobStateDep):
     )
 
 
+# One quick check at a time; they share the runner with any full job
+_quick_check_lock = asyncio.Lock()
+
+
+def _quick_check_reserved_slot() -> bool:
+    """QUICK_CHECK_RESERVED_SLOT=true lets quick checks run alongside a full job."""
+    return os.environ.get("QUICK_CHECK_RESERVED_SLOT", "false").lower() in ("1", "true", "yes")
+
+
+@app.post("/quick-check")
+async def quick_check_endpoint(request: QuickCheckRequest, job_state: JobStateDep):
+    """Scrape up to five SKUs with one scraper and return the records inline, bypassing the job queue."""
+    from runner.quick_check import QuickCheckRefused, quick_check
+    from runner.selector_tester import load_scraper_config
+
+    if job_state.is_running and not _quick_check_reserved_slot():
+        return JSONResponse(status_code=409, content={"error": "runner_busy", "message": "A scraping job is running", "job_id": job_state.job_id})
+    if _quick_check_lock.locked():
+        return JSONResponse(status_code=409, content={"error": "runner_busy", "message": "A quick check is already running"})
+
+    try:
+        config = load_scraper_config(request.scraper)
+    except FileNotFoundError as e:
+        raise HTTPException(status_code=404, detail=str(e)) from e
+
+    async with _quick_check_lock:
+        with sleep_inhibitor.hold(f"quick check for {config.name}"):
+            try:
+                return await quick_check(config, request.skus, headless=request.headless)
+            except QuickCheckRefused as e:
+                return JSONResponse(status_code=409, content=e.to_dict())
+
+
 @app.get("/scrapers")
 async def list_scrapers():
     """List available scrapers from local YAML configs."""
@@ -467,6 +562,9 @@ class EventsResponse(BaseModel):
     events: list[dict]
     total: int
     has_more: bool
+    last_seq: int = 0
+    oldest_seq: int | None = None
+    gap: bool = False
 
 
 @app.get("/events")
@@ -475,9 +573,17 @@ async def get_events(
     event_types: str | None = Query(None, description="Comma-separated event types to filter"),
     since: str | None = Query(None, description="ISO timestamp to get events after"),
     limit: int = Query(100, ge=1, le=500, description="Maximum events to return"),
+    since_seq: int | None = Query(None, ge=0, description="Sequence number to get events after (for catch-up after reload)"),
 ):
     """Get structured events from the event bus.
 
+    Every event carries a monotonically increasing `seq`. After a reload the
+    frontend can pass the last `seq` it saw (or 0) to replay what it missed,
+    then keep polling with the returned `last_seq` without gaps or duplicates.
+    If events after `since_seq` were already evicted from the buffer, `gap` is
+    true and `oldest_seq` is the oldest event still available; the frontend
+    should resync its state rather than trust the replay.
+
     This endpoint replaces log parsing with typed, JSON events that the frontend
     can consume directly without regex.
 
@@ -503,6 +609,7 @@ async def get_events(
         event_types=type_filter,
         since=since,
         limit=limit + 1,  # Get one extra to check if there's more
+        since_seq=since_seq,
     )
 
     has_more = len(events) > limit
@@ -513,6 +620,9 @@ async def get_events(
         events=events,
         total=len(events),
         has_more=has_more,
+        last_seq=events[-1]["seq"] if events else (since_seq or event_bus.last_seq),
+        oldest_seq=event_bus.oldest_seq(job_id),
+        gap=since_seq is not None and event_bus.has_gap(since_seq, job_id),
     )
 
 
@@ -520,6 +630,7 @@ async def get_events(
 async def list_event_types():
     """List all available event types."""
     return {
+        "schema_version": EVENT_SCHEMA_VERSION,
         "event_types": [e.value for e in EventType],
         "categories": {
             "job": [e.value for e in EventType if e.value.startswith("job.")],
@@ -529,6 +640,8 @@ async def list_event_types():
             "selector": [e.value for e in EventType if e.value.startswith("selector.")],
             "data": [e.value for e in EventType if e.value.startswith("data.")],
             "system": [e.value for e in EventType if e.value.startswith("system.")],
+            "api": [e.value for e in EventType if e.value.startswith("api.")],
+            "auth": [e.value for e in EventType if e.value.startswith("auth.")],
         },
     }
 
@@ -633,5 +746,6 @@ async def get_debug_snapshots(
 if __name__ == "__main__":
     import uvicorn
 
+    host = os.environ.get("API_HOST", "0.0.0.0")
     port = int(os.environ.get("API_PORT", "8000"))
-    uvicorn.run(app, host="0.0.0.0", port=port)
+    uvicorn.run(app, host=host, port=port)
@@ -8,15 +8,27 @@ Runners authenticate with a single API key issued from the admin panel.
 from __future__ import annotations
 
 import asyncio
+import gzip
 import json
 import logging
 import os
+import re
 import time
-from dataclasses import dataclass
+from collections.abc import Callable, Container
+from dataclasses import asdict, dataclass, field
+from pathlib import Path
 from typing import Any
 
 import httpx
 
+from core.api_request_log import api_request_log
+from core.health import read_version, runner_health
+from core.instance import load_failover_priority, load_runner_tags
+from core.results_manifest import build_manifest, chunk_digest, sign_manifest
+from core.server_rejections import ROW_VALIDATION_STATUSES, error_message, parse_row_rejections, server_rejections
+from core.settings_manager import PROJECT_ROOT
+from core.version_info import collect_version_info
+
 logger = logging.getLogger(__name__)
 
 # Retry configuration constants
@@ -24,6 +36,19 @@ DEFAULT_MAX_RETRIES = 3
 RETRY_BACKOFF_MULTIPLIER = 2  # Exponential backoff: 1s, 2s, 4s, 8s
 RETRY_INITIAL_DELAY = 1.0  # Initial delay in seconds
 
+# Result sets with more SKUs than this are uploaded as gzipped chunks
+DEFAULT_UPLOAD_CHUNK_ROWS = 1000
+UPLOAD_STATE_DIR = PROJECT_ROOT / "data" / "uploads"
+# Statuses the coordinator answers with for an upload session it no longer has
+UPLOAD_EXPIRED_STATUSES = {404, 410}
+
+# Metrics push in heartbeats, for runners the server can't scrape (disabled by default)
+DEFAULT_METRICS_PUSH_INTERVAL = 300  # seconds
+
+# Capability negotiation; servers without this endpoint get the legacy behavior
+CAPABILITIES_ENDPOINT = "/api/admin/scraper-network/capabilities"
+UPLOAD_SCHEMA_VERSION = "1"
+
 
 @dataclass
 class ScraperConfig:
@@ -40,6 +65,14 @@ class ScraperConfig:
     retries: int = 3
     validation: dict[str, Any] | None = None
 
+    def source(self) -> dict[str, Any]:
+        """The scraper config as the coordinator sent it, minus display-only fields and runner-injected options."""
+        source = {key: value for key, value in asdict(self).items() if key not in ("display_name", "disabled")}
+        if source["options"]:
+            # Options with a leading underscore (e.g. _credentials) are injected by the runner
+            source["options"] = {key: value for key, value in source["options"].items() if not key.startswith("_")}
+        return source
+
 
 @dataclass
 class JobConfig:
@@ -68,6 +101,8 @@ class ClaimedChunk:
     max_workers: int = 3
     lease_token: str | None = None
     lease_expires_at: str | None = None
+    # Chunks still waiting on the coordinator after this claim, when it reports them
+    pending_chunks: int | None = None
 
 
 class AuthenticationError(Exception):
@@ -82,6 +117,64 @@ class ConnectionError(Exception):
     pass
 
 
+class IncompatibleServerError(Exception):
+    """Raised when the coordinator does not support this runner version."""
+
+    pass
+
+
+class StaleScraperConfigError(Exception):
+    """Raised when results were produced with a scraper config the coordinator no longer accepts."""
+
+    pass
+
+
+class UploadExpiredError(Exception):
+    """Raised when the coordinator no longer knows a chunked upload session."""
+
+    pass
+
+
+@dataclass
+class ServerCapabilities:
+    """What the coordinator says it supports, fetched once on startup."""
+
+    version: str | None = None
+    min_runner_version: str | None = None
+    upload_schema_versions: list[str] = field(default_factory=list)
+    # Endpoint name -> path; a renamed endpoint keeps its name with a new path
+    endpoints: dict[str, str | None] = field(default_factory=dict)
+    # Scraper name -> config hashes results are still accepted from; unlisted scrapers are unrestricted
+    config_hashes: dict[str, list[str]] = field(default_factory=dict)
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> ServerCapabilities:
+        endpoints = data.get("endpoints") or {}
+        if isinstance(endpoints, list):
+            endpoints = {name: None for name in endpoints}
+        return cls(
+            version=data.get("version"),
+            min_runner_version=data.get("min_runner_version"),
+            upload_schema_versions=[str(v) for v in data.get("upload_schema_versions") or []],
+            endpoints=dict(endpoints),
+            config_hashes={name: [str(h) for h in hashes] for name, hashes in (data.get("config_hashes") or {}).items()},
+        )
+
+
+def _version_tuple(version: str) -> tuple[int, ...]:
+    """Parse 'v1.2.3' / '1.2.3-beta' / '1.4.0-rc1' into (1, 2, 3) / (1, 4, 0)."""
+    parts = []
+    for part in version.lstrip("vV").split("."):
+        match = re.match(r"\d+", part)
+        if not match:
+            break
+        parts.append(int(match.group(0)))
+        # A pre-release suffix ends the numeric version ("2.0rc10" is 2.0, not 2.010)
+        if match.end() < len(part):
+            break
+    return tuple(parts)
+
+
 class ConfigFetchError(Exception):
     def __init__(
         self,
@@ -173,14 +266,30 @@ class ScraperAPIClient:
         self.api_url = api_url or os.environ.get("SCRAPER_API_URL", "")
         self.api_key = api_key or os.environ.get("SCRAPER_API_KEY", "")
         self.runner_name = runner_name or os.environ.get("RUNNER_NAME", "unknown-runner")
+        self.instance_id: str | None = os.environ.get("RUNNER_INSTANCE_ID") or None
+        self.runner_tags = load_runner_tags()
+        self.failover_priority = load_failover_priority()
         self.timeout = timeout
         self.max_retries = max_retries if max_retries is not None else int(os.environ.get("SCRAPER_API_MAX_RETRIES", str(DEFAULT_MAX_RETRIES)))
+        self.metrics_push_enabled = os.environ.get("METRICS_PUSH_ENABLED", "").lower() in ("1", "true")
+        self.metrics_push_interval = float(os.environ.get("METRICS_PUSH_INTERVAL_SECONDS", str(DEFAULT_METRICS_PUSH_INTERVAL)))
+        self.metrics_push_max_series = int(os.environ.get("METRICS_PUSH_MAX_SERIES", "200"))
+        self._last_metrics_push = 0.0
+        self.capabilities: ServerCapabilities | None = None
 
         if not self.api_url:
             logger.warning("SCRAPER_API_URL not configured")
         if not self.api_key:
             logger.warning("SCRAPER_API_KEY not configured")
 
+    def _with_tags(self, payload: dict[str, Any]) -> dict[str, Any]:
+        """Add this runner's location tag and labels, when set, so HQ can segment by store."""
+        if self.runner_tags.get("location_tag"):
+            payload["location_tag"] = self.runner_tags["location_tag"]
+        if self.runner_tags.get("labels"):
+            payload["labels"] = self.runner_tags["labels"]
+        return payload
+
     def health_check(self) -> bool:
         """
         Perform a quick health check to verify API connectivity.
@@ -201,10 +310,14 @@ class ScraperAPIClient:
             raise ConnectionError(error_msg)
 
         health_url = f"{self.api_url.rstrip('/')}/api/health"
+        started = time.monotonic()
+        status: int | None = None
+        error_class: str | None = None
 
         try:
             with httpx.Client(timeout=self.timeout) as client:
                 response = client.get(health_url, headers=self._get_headers())
+                status = response.status_code
 
                 if response.status_code == 200:
                     logger.info(f"[API Client] Health check passed: {self.api_url}")
@@ -215,21 +328,107 @@ class ScraperAPIClient:
                     raise ConnectionError(error_msg)
 
         except httpx.NetworkError as e:
+            error_class = type(e).__name__
             error_msg = f"Health check failed: Network error - {str(e)}"
             logger.error(f"[API Client] {error_msg}")
             raise ConnectionError(error_msg)
         except httpx.TimeoutException as e:
+            error_class = type(e).__name__
             error_msg = f"Health check failed: Request timed out ({self.timeout}s) - {str(e)}"
             logger.error(f"[API Client] {error_msg}")
             raise ConnectionError(error_msg)
         except httpx.HTTPStatusError as e:
+            error_class = type(e).__name__
             error_msg = f"Health check failed: HTTP error {e.response.status_code} - {str(e)}"
             logger.error(f"[API Client] {error_msg}")
             raise ConnectionError(error_msg)
         except Exception as e:
+            error_class = type(e).__name__
             error_msg = f"Health check failed: Unexpected error - {str(e)}"
             logger.error(f"[API Client] {error_msg}")
             raise ConnectionError(error_msg)
+        finally:
+            api_request_log.record("GET", "/api/health", status, (time.monotonic() - started) * 1000, error=error_class)
+
+    def negotiate_capabilities(self) -> ServerCapabilities | None:
+        """Fetch and cache the coordinator's capabilities.
+
+        Returns None (keeping the legacy endpoints and payloads) when the server
+        predates the capabilities endpoint or it can't be reached.
+
+        Raises:
+            IncompatibleServerError: If the server requires a newer runner or
+                accepts none of the upload schema versions this runner sends.
+        """
+        try:
+            data = self._make_request("GET", CAPABILITIES_ENDPOINT, max_retries=0)
+        except httpx.HTTPStatusError as e:
+            if e.response.status_code != 404:
+                logger.warning(f"[API Client] Capabilities request failed ({e.response.status_code}); using legacy behavior")
+            self.capabilities = None
+            return None
+        except Exception as e:
+            logger.warning(f"[API Client] Could not fetch capabilities: {e}; using legacy behavior")
+            self.capabilities = None
+            return None
+
+        capabilities = ServerCapabilities.from_dict(data if isinstance(data, dict) else {})
+
+        runner_version = read_version()
+        required = capabilities.min_runner_version
+        if required and runner_version != "unknown" and _version_tuple(runner_version) < _version_tuple(required):
+            raise IncompatibleServerError(f"Server requires runner >= {required} (this runner is {runner_version}). Update the runner.")
+        if capabilities.upload_schema_versions and UPLOAD_SCHEMA_VERSION not in capabilities.upload_schema_versions:
+            raise IncompatibleServerError(
+                f"Server accepts upload schema {', '.join(capabilities.upload_schema_versions)}, "
+                f"but this runner ({runner_version}) sends {UPLOAD_SCHEMA_VERSION}. Update the runner."
+            )
+
+        self.capabilities = capabilities
+        logger.info(f"[API Client] Negotiated capabilities with server {capabilities.version or 'unknown'}: {', '.join(capabilities.endpoints) or 'no endpoint list'}")
+        return capabilities
+
+    def supports(self, endpoint_name: str) -> bool:
+        """Whether the server supports an optional endpoint (assumed yes without capabilities)."""
+        if self.capabilities is None or not self.capabilities.endpoints:
+            return True
+        return endpoint_name in self.capabilities.endpoints
+
+    def check_provenance(self, results: dict[str, Any] | None) -> None:
+        """Refuse results whose scraper config hash the coordinator no longer accepts.
+
+        Raises:
+            StaleScraperConfigError: Naming each stale scraper and its hash.
+        """
+        stale = self._stale_scrapers(results)
+        if stale:
+            raise StaleScraperConfigError(f"Stale scraper config: results produced with outdated config for {', '.join(stale)}; refusing upload")
+
+    def _stale_scrapers(self, results: dict[str, Any] | None) -> list[str]:
+        """Each scraper in the results' provenance whose config hash is no longer accepted, with its hash."""
+        if self.capabilities is None or not self.capabilities.config_hashes or not results:
+            return []
+        scrapers = (results.get("provenance") or {}).get("scrapers") or {}
+        return [
+            f"{name} ({info.get('config_hash')})"
+            for name, info in scrapers.items()
+            if name in self.capabilities.config_hashes and info.get("config_hash") not in self.capabilities.config_hashes[name]
+        ]
+
+    def _stale_results_error(self, results: dict[str, Any] | None) -> str | None:
+        """The refusal message for results from a stale scraper config, or None if they may be uploaded."""
+        try:
+            self.check_provenance(results)
+        except StaleScraperConfigError as e:
+            logger.error(str(e))
+            return str(e)
+        return None
+
+    def _endpoint(self, name: str, default: str) -> str:
+        """Path for a named endpoint, honoring renames advertised by the server."""
+        if self.capabilities is not None:
+            return self.capabilities.endpoints.get(name) or default
+        return default
 
     def _get_headers(self) -> dict[str, str]:
         """Get headers for authenticated requests."""
@@ -245,71 +444,97 @@ class ScraperAPIClient:
         self,
         method: str,
         endpoint: str,
-        payload: str | None = None,
+        payload: str | bytes | None = None,
+        extra_headers: dict[str, str] | None = None,
+        max_retries: int | None = None,
     ) -> dict[str, Any]:
         """
         Make an authenticated HTTP request with retry logic and exponential backoff.
 
         Retries on transient failures (network errors, timeouts, 5xx errors).
         Fails immediately on non-retryable errors (4xx client errors, auth failures).
+        Pass max_retries to override the client default (e.g. 0 when the caller retries).
         """
         url = f"{self.api_url.rstrip('/')}{endpoint}"
         headers = self._get_headers()
+        if extra_headers:
+            headers.update(extra_headers)
+        max_retries = self.max_retries if max_retries is None else max_retries
 
         last_exception: Exception | None = None
         delay = RETRY_INITIAL_DELAY
 
-        for attempt in range(self.max_retries + 1):
-            try:
-                with httpx.Client(timeout=self.timeout) as client:
-                    if method.upper() == "GET":
-                        response = client.get(url, headers=headers)
-                    else:
-                        response = client.post(url, headers=headers, content=payload)
-
-                    # Authentication failure - not retryable
-                    if response.status_code == 401:
-                        raise AuthenticationError("Invalid API key")
-
-                    # Raise for status on HTTP errors
-                    response.raise_for_status()
-                    return response.json()
-
-            except httpx.HTTPStatusError as e:
-                status_code = e.response.status_code
-                is_retryable = _is_retryable_error(status_code, e)
-
-                if not is_retryable or attempt >= self.max_retries:
-                    # Non-retryable error or max retries exceeded
-                    raise
-
-                last_exception = e
-                logger.warning(
-                    f"API request failed (attempt {attempt + 1}/{self.max_retries + 1}): {status_code} - {e.response.text[:200]}. Retrying in {delay:.1f}s..."
-                )
+        started = time.monotonic()
+        attempt = 0
+        last_status: int | None = None
+        error_class: str | None = None
 
-            except (httpx.NetworkError, httpx.TimeoutException) as e:
-                if attempt >= self.max_retries:
+        try:
+            for attempt in range(max_retries + 1):
+                last_status = None
+                try:
+                    with httpx.Client(timeout=self.timeout) as client:
+                        if method.upper() == "GET":
+                            response = client.get(url, headers=headers)
+                        else:
+                            response = client.post(url, headers=headers, content=payload)
+
+                        # Any response short of a server error means the coordinator is reachable
+                        last_status = response.status_code
+                        if response.status_code < 500:
+                            runner_health.record_api_success()
+
+                        # Authentication failure - not retryable
+                        if response.status_code == 401:
+                            raise AuthenticationError("Invalid API key")
+
+                        # Raise for status on HTTP errors
+                        response.raise_for_status()
+                        return response.json()
+
+                except httpx.HTTPStatusError as e:
+                    status_code = e.response.status_code
+                    is_retryable = _is_retryable_error(status_code, e)
+
+                    if not is_retryable or attempt >= max_retries:
+                        # Non-retryable error or max retries exceeded
+                        if status_code >= 500:
+                            runner_health.record_api_failure()
+                        raise
+
+                    last_exception = e
+                    logger.warning(
+                        f"API request failed (attempt {attempt + 1}/{max_retries + 1}): {status_code} - {e.response.text[:200]}. Retrying in {delay:.1f}s..."
+                    )
+
+                except (httpx.NetworkError, httpx.TimeoutException) as e:
+                    if attempt >= max_retries:
+                        runner_health.record_api_failure()
+                        raise
+
+                    last_exception = e
+                    logger.warning(
+                        f"API request failed (attempt {attempt + 1}/{max_retries + 1}): {type(e).__name__} - {str(e)[:200]}. Retrying in {delay:.1f}s..."
+                    )
+
+                except Exception as e:
+                    # Other exceptions (e.g., JSON decode errors) - not retryable
                     raise
 
-                last_exception = e
-                logger.warning(
-                    f"API request failed (attempt {attempt + 1}/{self.max_retries + 1}): {type(e).__name__} - {str(e)[:200]}. Retrying in {delay:.1f}s..."
-                )
-
-            except Exception as e:
-                # Other exceptions (e.g., JSON decode errors) - not retryable
-                raise
-
-            # Wait before retrying with exponential backoff
-            if attempt < self.max_retries:
-                time.sleep(delay)
-                delay *= RETRY_BACKOFF_MULTIPLIER
+                # Wait before retrying with exponential backoff
+                if attempt < max_retries:
+                    time.sleep(delay)
+                    delay *= RETRY_BACKOFF_MULTIPLIER
 
-        # This should not be reached, but just in case
-        if last_exception:
-            raise last_exception
-        raise Exception("Unexpected error in retry loop")
+            # This should not be reached, but just in case
+            if last_exception:
+                raise last_exception
+            raise Exception("Unexpected error in retry loop")
+        except Exception as e:
+            error_class = type(e).__name__
+            raise
+        finally:
+            api_request_log.record(method, endpoint, last_status, (time.monotonic() - started) * 1000, retries=attempt, error=error_class)
 
     def get_job_config(self, job_id: str) -> JobConfig | None:
         """Fetch job details and scraper configurations from the coordinator."""
@@ -366,11 +591,19 @@ class ScraperAPIClient:
         results: dict[str, Any] | None = None,
         error_message: str | None = None,
     ) -> bool:
-        """Submit scrape results to the callback endpoint."""
+        """Submit scrape results to the callback endpoint.
+
+        Results from a scraper config the coordinator no longer accepts are
+        not sent; the job is reported failed with the refusal instead.
+        """
         if not self.api_url:
             logger.error("API client not configured - missing URL")
             return False
 
+        stale_error = self._stale_results_error(results)
+        if stale_error:
+            status, results, error_message = "failed", None, stale_error
+
         payload_dict: dict[str, Any] = {
             "job_id": job_id,
             "status": status,
@@ -380,28 +613,334 @@ class ScraperAPIClient:
         if lease_token:
             payload_dict["lease_token"] = lease_token
 
+        signed_manifest = None
         if results:
             payload_dict["results"] = results
+            if self.capabilities is not None:
+                payload_dict["schema_version"] = UPLOAD_SCHEMA_VERSION
+            if "data" in results:
+                signed_manifest = self._sign_results(results, job_id=job_id)
+                payload_dict["manifest"] = signed_manifest
         if error_message:
             payload_dict["error_message"] = error_message
 
-        payload = json.dumps(payload_dict)
+        payload = json.dumps(self._with_tags(payload_dict))
 
         try:
-            self._make_request("POST", "/api/admin/scraping/callback", payload=payload)
+            self._make_request("POST", self._endpoint("callback", "/api/admin/scraping/callback"), payload=payload)
             logger.info(f"Submitted results for job {job_id}: status={status}")
-            return True
+            if signed_manifest:
+                results["results_manifest"] = signed_manifest
+            if results:
+                runner_health.record_upload()
+            return stale_error is None
 
         except AuthenticationError as e:
             logger.error(f"Authentication failed: {e}")
             return False
         except httpx.HTTPStatusError as e:
+            if results and e.response.status_code in ROW_VALIDATION_STATUSES and self._set_aside_named_rows(job_id, results, e.response):
+                # Every retry drops at least one row, so this always ends
+                return self.submit_results(job_id, status, runner_name=runner_name, lease_token=lease_token, results=results)
             logger.error(f"Failed to submit results: {e.response.status_code} - {e.response.text}")
             return False
         except Exception as e:
             logger.error(f"Error submitting results: {e}")
             return False
 
+    @staticmethod
+    def _sign_results(results: dict[str, Any], job_id: str | None = None, chunk_id: str | None = None) -> dict[str, Any]:
+        """Signed manifest for results sent in one request, all rows as a single chunk."""
+        data = results.get("data") or {}
+        set_aside = ScraperAPIClient._set_aside_skus(results, data)
+        return sign_manifest(build_manifest(job_id, [data] if data else [], len(data), results.get("provenance"), chunk_id=chunk_id, set_aside=set_aside))
+
+    @staticmethod
+    def _set_aside_skus(results: dict[str, Any], committed: Container[str]) -> list[str]:
+        """SKUs the coordinator set aside for the job that are not among the committed rows."""
+        rejected = (results.get("server_rejections") or {}).get("skus") or []
+        return sorted({sku for sku in rejected if sku not in committed})
+
+    def submit_results_chunked(
+        self,
+        job_id: str,
+        results: dict[str, Any],
+        runner_name: str | None = None,
+        lease_token: str | None = None,
+        chunk_rows: int | None = None,
+        progress_callback: Callable[[int, int], None] | None = None,
+    ) -> bool:
+        """Submit completed results, splitting large result sets into gzipped chunks.
+
+        Small result sets (and coordinators without upload sessions) fall back to a
+        single submit_results call. Upload state is persisted after every
+        acknowledged chunk, so calling this again for the same job after an
+        interruption resumes from the first unacknowledged chunk. A session is only
+        resumed for the same data, and one the coordinator has expired is started over.
+
+        Args:
+            progress_callback: Called as callback(chunks_done, total_chunks) after each chunk.
+        """
+        chunk_rows = chunk_rows or int(os.environ.get("UPLOAD_CHUNK_ROWS", str(DEFAULT_UPLOAD_CHUNK_ROWS)))
+        rows = list((results.get("data") or {}).items())
+        # submit_results reports (and logs) a stale-config refusal instead of uploading
+        if len(rows) <= chunk_rows or not self.supports("uploads") or self._stale_scrapers(results):
+            return self.submit_results(job_id, "completed", runner_name=runner_name, lease_token=lease_token, results=results)
+
+        started = time.time()
+        chunks = [dict(rows[i : i + chunk_rows]) for i in range(0, len(rows), chunk_rows)]
+        state_path = UPLOAD_STATE_DIR / f"{job_id}.json"
+        content_hash = chunk_digest(dict(rows))
+        state = self._load_upload_state(state_path, len(rows), chunk_rows, content_hash)
+
+        for restarted in (False, True):
+            if state is None:
+                upload_id = self.begin_upload(job_id, total_rows=len(rows), total_chunks=len(chunks), chunk_rows=chunk_rows)
+                if not upload_id:
+                    logger.warning(f"Chunked upload unavailable for job {job_id}; falling back to a single request")
+                    return self.submit_results(job_id, "completed", runner_name=runner_name, lease_token=lease_token, results=results)
+                state = {"upload_id": upload_id, "total_rows": len(rows), "chunk_rows": chunk_rows, "content_hash": content_hash, "acked": [], "retries": 0}
+                self._save_upload_state(state_path, state)
+            else:
+                logger.info(f"Resuming upload {state['upload_id']} for job {job_id}: {len(state['acked'])}/{len(chunks)} chunks already acknowledged")
+
+            try:
+                return self._send_upload_chunks(job_id, results, chunks, chunk_rows, state, state_path, started, runner_name, lease_token, progress_callback)
+            except UploadExpiredError:
+                if restarted:
+                    logger.error(f"Upload session for job {job_id} expired again; giving up")
+                    return False
+                logger.warning(f"Upload {state['upload_id']} for job {job_id} expired on the server; starting a new session")
+                state = None
+        return False
+
+    def _send_upload_chunks(
+        self,
+        job_id: str,
+        results: dict[str, Any],
+        chunks: list[dict[str, Any]],
+        chunk_rows: int,
+        state: dict[str, Any],
+        state_path: Path,
+        started: float,
+        runner_name: str | None,
+        lease_token: str | None,
+        progress_callback: Callable[[int, int], None] | None,
+    ) -> bool:
+        """Upload the chunks not yet acknowledged in state and commit. Raises UploadExpiredError if the session is gone."""
+        resumed_chunks = len(state["acked"])
+        for index, chunk in enumerate(chunks):
+            if index in state["acked"]:
+                continue
+            ok, retries, set_aside = self._upload_chunk_with_retry(state["upload_id"], index, chunk, job_id)
+            state["retries"] += retries
+            if set_aside:
+                state.setdefault("set_aside", {})[str(index)] = set_aside
+            if not ok:
+                self._save_upload_state(state_path, state)
+                logger.error(f"Upload {state['upload_id']} for job {job_id} stopped at chunk {index}; rerun to resume")
+                return False
+            state["acked"].append(index)
+            self._save_upload_state(state_path, state)
+            if progress_callback:
+                progress_callback(len(state["acked"]), len(chunks))
+
+        # The manifest covers what the coordinator accepted, so set-aside rows are left out
+        dropped = {sku for skus in (state.get("set_aside") or {}).values() for sku in skus}
+        committed = [{sku: row for sku, row in chunk.items() if sku not in dropped} for chunk in chunks]
+        upload_stats = {
+            "chunks": len(chunks),
+            "rows": sum(len(chunk) for chunk in committed),
+            "retries": state["retries"],
+            "resumed_chunks": resumed_chunks,
+            "duration_ms": int((time.time() - started) * 1000),
+        }
+        rejections = server_rejections.summary(job_id)
+        if rejections:
+            results["server_rejections"] = rejections
+        summary = {key: value for key, value in results.items() if key != "data"}
+        set_aside = self._set_aside_skus(results, {sku for chunk in committed for sku in chunk})
+        signed_manifest = sign_manifest(build_manifest(job_id, committed, chunk_rows, results.get("provenance"), set_aside=set_aside))
+        if not self.commit_upload(
+            state["upload_id"], job_id, summary, upload_stats, runner_name=runner_name, lease_token=lease_token, manifest=signed_manifest
+        ):
+            return False
+
+        # Same as a single-request upload: set-aside rows live in the rejections file, not the results
+        data = results.get("data") or {}
+        for sku in dropped:
+            data.pop(sku, None)
+        results["upload_stats"] = upload_stats
+        results["results_manifest"] = signed_manifest
+        try:
+            state_path.unlink()
+        except FileNotFoundError:
+            pass
+        runner_health.record_upload()
+        return True
+
+    def begin_upload(self, job_id: str, total_rows: int, total_chunks: int, chunk_rows: int) -> str | None:
+        """Open a chunked upload session. Returns the upload id, or None if unsupported."""
+        payload = json.dumps(
+            self._with_tags(
+                {
+                    "job_id": job_id,
+                    "runner_name": self.runner_name,
+                    "total_rows": total_rows,
+                    "total_chunks": total_chunks,
+                    "chunk_rows": chunk_rows,
+                    "encoding": "gzip",
+                }
+            )
+        )
+        try:
+            data = self._make_request("POST", self._endpoint("uploads", "/api/scraper/v1/uploads"), payload=payload)
+            return data.get("upload_id")
+        except httpx.HTTPStatusError as e:
+            logger.warning(f"Failed to begin upload for job {job_id}: {e.response.status_code} - {e.response.text[:200]}")
+            return None
+        except Exception as e:
+            logger.warning(f"Error beginning upload for job {job_id}: {e}")
+            return None
+
+    def _upload_chunk_with_retry(self, upload_id: str, index: int, rows: dict[str, Any], job_id: str) -> tuple[bool, int, list[str]]:
+        """POST one gzipped chunk, retrying it on its own. Returns (ok, retries used, SKUs set aside).
+
+        Rows the server names as invalid are set aside (see core.server_rejections)
+        and the rest of the chunk is sent again, so a bad row can't stall the upload.
+        A validation error that names no rows fails the chunk like any other rejection.
+        """
+        delay = RETRY_INITIAL_DELAY
+        attempt = 0
+        set_aside: list[str] = []
+
+        while True:
+            body = gzip.compress(json.dumps({"chunk_index": index, "rows": rows}).encode("utf-8"))
+            try:
+                self._make_request(
+                    "POST",
+                    f"{self._endpoint('uploads', '/api/scraper/v1/uploads')}/{upload_id}/chunks/{index}",
+                    payload=body,
+                    extra_headers={"Content-Encoding": "gzip"},
+                    max_retries=0,
+                )
+                return True, attempt, set_aside
+            except AuthenticationError as e:
+                logger.error(f"Authentication failed uploading chunk {index}: {e}")
+                return False, attempt, set_aside
+            except httpx.HTTPStatusError as e:
+                if e.response.status_code in UPLOAD_EXPIRED_STATUSES:
+                    raise UploadExpiredError(upload_id) from e
+                if e.response.status_code in ROW_VALIDATION_STATUSES and rows:
+                    remaining = self._set_aside_chunk_rows(job_id, index, rows, e.response)
+                    if remaining is None:
+                        return False, attempt, set_aside
+                    set_aside += [sku for sku in rows if sku not in remaining]
+                    if not remaining:
+                        return True, attempt, set_aside
+                    rows = remaining
+                    continue
+                if not _is_retryable_error(e.response.status_code, e):
+                    logger.error(f"Chunk {index} rejected: {e.response.status_code} - {e.response.text[:200]}")
+                    return False, attempt, set_aside
+                logger.warning(f"Chunk {index} failed (attempt {attempt + 1}/{self.max_retries + 1}): {e.response.status_code}")
+            except Exception as e:
+                logger.warning(f"Chunk {index} failed (attempt {attempt + 1}/{self.max_retries + 1}): {type(e).__name__} - {e}")
+
+            if attempt >= self.max_retries:
+                return False, attempt, set_aside
+            time.sleep(delay)
+            delay *= RETRY_BACKOFF_MULTIPLIER
+            attempt += 1
+
+    @staticmethod
+    def _set_aside_chunk_rows(job_id: str, index: int, rows: dict[str, Any], response: httpx.Response) -> dict[str, Any] | None:
+        """Record the rows the server rejected and return the rest. None if the rejection names no rows in the chunk."""
+        reasons = parse_row_rejections(response)
+        rejected = {sku: row for sku, row in rows.items() if sku in reasons}
+        if not rejected:
+            logger.error(f"Chunk {index} rejected without naming rows: {response.status_code} - {error_message(response)}")
+            return None
+        server_rejections.record(job_id, rejected, reasons, response.status_code)
+        return {sku: row for sku, row in rows.items() if sku not in rejected}
+
+    @staticmethod
+    def _set_aside_named_rows(job_id: str, results: dict[str, Any], response: httpx.Response) -> bool:
+        """Move rows the server named as invalid out of results into the rejections file. False if it named none."""
+        data = results.get("data") or {}
+        reasons = parse_row_rejections(response)
+        rejected = {sku: data[sku] for sku in reasons if sku in data}
+        if not rejected:
+            return False
+        server_rejections.record(job_id, rejected, reasons, response.status_code)
+        for sku in rejected:
+            del data[sku]
+        results["server_rejections"] = server_rejections.summary(job_id)
+        return True
+
+    def commit_upload(
+        self,
+        upload_id: str,
+        job_id: str,
+        summary: dict[str, Any],
+        upload_stats: dict[str, Any],
+        runner_name: str | None = None,
+        lease_token: str | None = None,
+        manifest: dict[str, Any] | None = None,
+    ) -> bool:
+        """Finalize a chunked upload, sending the non-data parts of the results and the signed manifest (see core.results_manifest).
+
+        Raises:
+            UploadExpiredError: If the coordinator no longer knows the upload session.
+        """
+        payload_dict: dict[str, Any] = {
+            "job_id": job_id,
+            "status": "completed",
+            "runner_name": runner_name or self.runner_name,
+            "results": summary,
+            "upload_stats": upload_stats,
+        }
+        if lease_token:
+            payload_dict["lease_token"] = lease_token
+        if manifest:
+            payload_dict["manifest"] = manifest
+        payload = json.dumps(self._with_tags(payload_dict))
+
+        try:
+            self._make_request("POST", f"{self._endpoint('uploads', '/api/scraper/v1/uploads')}/{upload_id}/commit", payload=payload)
+            logger.info(f"Committed upload {upload_id} for job {job_id}: {upload_stats['chunks']} chunks, {upload_stats['retries']} retries")
+            return True
+        except httpx.HTTPStatusError as e:
+            if e.response.status_code in UPLOAD_EXPIRED_STATUSES:
+                raise UploadExpiredError(upload_id) from e
+            logger.error(f"Failed to commit upload {upload_id}: {e.response.status_code} - {e.response.text}")
+            return False
+        except Exception as e:
+            logger.error(f"Error committing upload {upload_id}: {e}")
+            return False
+
+    @staticmethod
+    def _load_upload_state(path: Path, total_rows: int, chunk_rows: int, content_hash: str) -> dict[str, Any] | None:
+        """Load a persisted upload session if it was for the same rows, split the same way."""
+        try:
+            state = json.loads(path.read_text())
+        except (OSError, json.JSONDecodeError):
+            return None
+        if state.get("total_rows") != total_rows or state.get("chunk_rows") != chunk_rows or not state.get("upload_id"):
+            return None
+        if state.get("content_hash") != content_hash:
+            logger.info(f"Discarding upload state in {path.name}: the results changed since it was started")
+            return None
+        return state
+
+    @staticmethod
+    def _save_upload_state(path: Path, state: dict[str, Any]) -> None:
+        try:
+            path.parent.mkdir(parents=True, exist_ok=True)
+            path.write_text(json.dumps(state))
+        except OSError as e:
+            logger.warning(f"Could not persist upload state to {path}: {e}")
+
     def update_status(
         self,
         job_id: str,
@@ -432,7 +971,7 @@ class ScraperAPIClient:
         payload = json.dumps(payload_dict)
 
         try:
-            data = self._make_request("POST", "/api/scraper/v1/claim-chunk", payload=payload)
+            data = self._make_request("POST", self._endpoint("claim_chunk", "/api/scraper/v1/claim-chunk"), payload=payload)
 
             chunk = data.get("chunk")
             if not chunk:
@@ -450,6 +989,7 @@ class ScraperAPIClient:
                 max_workers=chunk.get("max_workers", 3),
                 lease_token=chunk.get("lease_token"),
                 lease_expires_at=chunk.get("lease_expires_at"),
+                pending_chunks=data.get("pending_chunks"),
             )
 
         except AuthenticationError as e:
@@ -469,28 +1009,42 @@ class ScraperAPIClient:
         results: dict[str, Any] | None = None,
         error_message: str | None = None,
     ) -> bool:
-        """Submit results for a completed chunk."""
+        """Submit results for a completed chunk, or a failure if they came from a stale scraper config."""
         if not self.api_url:
             logger.error("API client not configured - missing URL")
             return False
 
+        stale_error = self._stale_results_error(results)
+        if stale_error:
+            status, results, error_message = "failed", None, stale_error
+
         payload_dict: dict[str, Any] = {
             "chunk_id": chunk_id,
             "status": status,
             "runner_name": self.runner_name,
         }
 
+        signed_manifest = None
         if results:
             payload_dict["results"] = results
+            if self.capabilities is not None:
+                payload_dict["schema_version"] = UPLOAD_SCHEMA_VERSION
+            if "data" in results:
+                signed_manifest = self._sign_results(results, chunk_id=chunk_id)
+                payload_dict["manifest"] = signed_manifest
         if error_message:
             payload_dict["error_message"] = error_message
 
-        payload = json.dumps(payload_dict)
+        payload = json.dumps(self._with_tags(payload_dict))
 
         try:
-            self._make_request("POST", "/api/scraper/v1/chunk-callback", payload=payload)
+            self._make_request("POST", self._endpoint("chunk_callback", "/api/scraper/v1/chunk-callback"), payload=payload)
             logger.info(f"Submitted results for chunk {chunk_id}: status={status}")
-            return True
+            if signed_manifest:
+                results["results_manifest"] = signed_manifest
+            if results:
+                runner_health.record_upload()
+            return stale_error is None
 
         except AuthenticationError as e:
             logger.error(f"Authentication failed: {e}")
@@ -502,12 +1056,39 @@ class ScraperAPIClient:
             logger.error(f"Error submitting chunk results: {e}")
             return False
 
+    def release_chunk(self, chunk_id: str, reason: str, lease_token: str | None = None) -> bool:
+        """Hand a claimed chunk back to the queue for another runner, e.g. after a local failure.
+
+        Returns False if the coordinator refused or doesn't support releasing;
+        the caller should then report the chunk failed as usual.
+        """
+        if not self.api_url:
+            logger.error("API client not configured - missing URL")
+            return False
+
+        payload_dict: dict[str, Any] = {"chunk_id": chunk_id, "runner_name": self.runner_name, "reason": reason}
+        if lease_token:
+            payload_dict["lease_token"] = lease_token
+        payload = json.dumps(self._with_tags(payload_dict))
+
+        try:
+            self._make_request("POST", self._endpoint("release_chunk", "/api/scraper/v1/release-chunk"), payload=payload)
+            logger.info(f"Released chunk {chunk_id} back to the queue: {reason}")
+            return True
+        except httpx.HTTPStatusError as e:
+            logger.warning(f"Failed to release chunk {chunk_id}: {e.response.status_code} - {e.response.text[:200]}")
+            return False
+        except Exception as e:
+            logger.warning(f"Error releasing chunk {chunk_id}: {e}")
+            return False
+
     def submit_chunk_progress(
         self,
         chunk_id: str,
         sku: str,
         scraper_name: str,
         data: dict[str, Any],
+        config_hash: str | None = None,
     ) -> bool:
         """Submit incremental progress for a single SKU within a chunk.
 
@@ -520,6 +1101,8 @@ class ScraperAPIClient:
             sku: The SKU that was just processed
             scraper_name: Name of the scraper that processed it
             data: The scraped data for this SKU
+            config_hash: Source config hash of the scraper; progress from a
+                config the coordinator no longer accepts is not sent
 
         Returns:
             True if successfully recorded, False otherwise
@@ -528,6 +1111,9 @@ class ScraperAPIClient:
             logger.error("API client not configured - missing URL")
             return False
 
+        if config_hash is not None and self._stale_results_error({"provenance": {"scrapers": {scraper_name: {"config_hash": config_hash}}}}):
+            return False
+
         payload_dict: dict[str, Any] = {
             "chunk_id": chunk_id,
             "status": "in_progress",
@@ -542,7 +1128,7 @@ class ScraperAPIClient:
         payload = json.dumps(payload_dict)
 
         try:
-            self._make_request("POST", "/api/scraper/v1/chunk-callback", payload=payload)
+            self._make_request("POST", self._endpoint("chunk_callback", "/api/scraper/v1/chunk-callback"), payload=payload)
             logger.debug(f"Submitted progress for chunk {chunk_id}, SKU {sku}")
             return True
 
@@ -581,7 +1167,7 @@ class ScraperAPIClient:
             # We use _make_raw_request to get access to headers if needed,
             # but _make_request is standard. Let's use httpx directly for header access
             # or rely on heartbeat for name sync. Heartbeat is safer.
-            data = self._make_request("POST", "/api/scraper/v1/poll", payload=payload)
+            data = self._make_request("POST", self._endpoint("poll", "/api/scraper/v1/poll"), payload=payload)
 
             job_data = data.get("job")
             if not job_data:
@@ -633,6 +1219,24 @@ class ScraperAPIClient:
             logger.error(f"Error polling for work: {e}")
             return None
 
+    def _metrics_snapshot_due(self) -> dict[str, Any] | None:
+        """Return a metrics push snapshot if pushing is enabled and the interval has elapsed since the last delivered one."""
+        if not self.metrics_push_enabled:
+            return None
+        now = time.monotonic()
+        if self._last_metrics_push and now - self._last_metrics_push < self.metrics_push_interval:
+            return None
+
+        from scrapers.ai_metrics import get_metrics_collector
+
+        return get_metrics_collector().get_push_snapshot(max_series=self.metrics_push_max_series)
+
+    def _metrics_push_delivered(self) -> None:
+        from scrapers.ai_metrics import get_metrics_collector
+
+        self._last_metrics_push = time.monotonic()
+        get_metrics_collector().acknowledge_push()
+
     def heartbeat(
         self,
         current_job_id: str | None = None,
@@ -655,24 +1259,37 @@ class ScraperAPIClient:
 
         payload_dict: dict[str, Any] = {
             "runner_name": self.runner_name,
+            "health": runner_health.snapshot(),
+            "version_info": collect_version_info(),
         }
+        if self.instance_id:
+            payload_dict["instance_id"] = self.instance_id
         if current_job_id:
             payload_dict["current_job_id"] = current_job_id
         if lease_token:
             payload_dict["lease_token"] = lease_token
         if status:
             payload_dict["status"] = status
+        if self.failover_priority is not None:
+            payload_dict["failover_priority"] = self.failover_priority
+        metrics = self._metrics_snapshot_due()
+        if metrics:
+            payload_dict["metrics"] = metrics
 
-        payload = json.dumps(payload_dict)
+        payload = json.dumps(self._with_tags(payload_dict))
 
         try:
-            response_data = self._make_request("POST", "/api/scraper/v1/heartbeat", payload=payload)
+            response_data = self._make_request("POST", self._endpoint("heartbeat", "/api/scraper/v1/heartbeat"), payload=payload)
 
             enforced_name = response_data.get("enforced_runner_name")
             if enforced_name and self.runner_name != enforced_name:
                 logger.info(f"Runner name sync: '{self.runner_name}' -> '{enforced_name}'")
                 self.runner_name = enforced_name
 
+            # Only a delivered snapshot moves the delta baseline; otherwise the next heartbeat covers this interval too
+            if metrics:
+                self._metrics_push_delivered()
+
             logger.debug(f"Heartbeat sent for {self.runner_name}")
             return True
 
@@ -0,0 +1,73 @@
+"""
+Rolling record of outbound coordinator API requests.
+
+"Is it even talking to the server?" should be answerable without digging
+through log files. Every request made through ScraperAPIClient is recorded
+here with its method, path, status, latency, retries and error class. Query
+strings, headers and bodies are never kept. Only the last MAX_API_REQUEST_ENTRIES
+requests are retained, and their success rate is part of the health snapshot.
+
+Set DEBUG_API_TRAFFIC=true to also stream each entry live as an api.request
+event.
+"""
+
+from __future__ import annotations
+
+import logging
+import os
+import threading
+from collections import deque
+from datetime import datetime, timezone
+from typing import Any
+
+from core.events import EventSeverity, EventType, ScraperEvent, event_bus
+
+logger = logging.getLogger(__name__)
+
+MAX_API_REQUEST_ENTRIES = 200
+
+
+class ApiRequestLog:
+    """Thread-safe ring buffer of request metadata."""
+
+    def __init__(self, max_entries: int = MAX_API_REQUEST_ENTRIES, stream: bool | None = None) -> None:
+        self.stream = stream if stream is not None else os.environ.get("DEBUG_API_TRAFFIC", "false").lower() in ("1", "true", "yes")
+        self._entries: deque[dict[str, Any]] = deque(maxlen=max_entries)
+        self._lock = threading.Lock()
+
+    def record(self, method: str, endpoint: str, status: int | None, latency_ms: float, retries: int = 0, error: str | None = None) -> dict[str, Any]:
+        """Add one request. The endpoint's query string is dropped, since it can carry secrets."""
+        entry = {
+            "timestamp": datetime.now(timezone.utc).isoformat(),
+            "method": method.upper(),
+            "path": endpoint.split("?", 1)[0],
+            "status": status,
+            "latency_ms": round(latency_ms, 1),
+            "retries": retries,
+            "error": error,
+            "ok": error is None and status is not None and status < 400,
+        }
+        with self._lock:
+            self._entries.append(entry)
+        if self.stream:
+            try:
+                event_bus.emit(ScraperEvent(event_type=EventType.API_REQUEST, severity=EventSeverity.DEBUG, data=dict(entry)))
+            except Exception as e:
+                logger.debug(f"Could not stream API request event: {e}")
+        return entry
+
+    def entries(self) -> list[dict[str, Any]]:
+        """Recorded requests, oldest first."""
+        with self._lock:
+            return [dict(entry) for entry in self._entries]
+
+    def success_rate(self) -> float | None:
+        """Share of recorded requests that succeeded, or None before the first request."""
+        with self._lock:
+            if not self._entries:
+                return None
+            return round(sum(1 for entry in self._entries if entry["ok"]) / len(self._entries), 3)
+
+
+# Process-wide log shared by every client instance
+api_request_log = ApiRequestLog()
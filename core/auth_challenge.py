@@ -0,0 +1,87 @@
+"""
+Interactive login challenges answered from the desktop app.
+
+Some supplier portals email a one-time code after the password and won't let
+a headless run in without it. When the login step finds the scraper's
+`otp_field` on the page, the job emits an auth.challenge event and waits for
+the code on the control channel (see core.pacing):
+
+    {"type": "auth_response", "scraper": "phillips", "value": "123456"}
+
+`scraper` may be left out while only one challenge is waiting. If nobody
+answers within the login's `otp_timeout_seconds` the login fails. A job that
+nobody can answer, because its job_config doesn't set `interactive_auth` or
+there is no control channel (CLI and debug runs), fails straight away with
+"interactive auth required" instead of hanging.
+
+A login that got past the challenge is cached like any other (see
+core.session_cache), so the following chunks skip the code.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import json
+import logging
+import threading
+from typing import Any
+
+from core.pacing import pacing_control
+
+logger = logging.getLogger(__name__)
+
+
+class AuthChallenges:
+    """Thread-safe hand-off of challenge responses from the control channel to the waiting login."""
+
+    def __init__(self) -> None:
+        self._pending: set[str] = set()
+        self._responses: dict[str, str] = {}
+        self._condition = threading.Condition()
+
+    def can_answer(self, context: dict[str, Any] | None) -> bool:
+        """Whether someone can answer a challenge during this run."""
+        return bool((context or {}).get("interactive_auth")) and pacing_control.listening
+
+    def submit(self, scraper: str, value: str) -> None:
+        with self._condition:
+            self._responses[scraper] = value
+            self._condition.notify_all()
+
+    def handle_line(self, line: str) -> bool:
+        """Apply one control line. Returns True if it was a challenge response."""
+        try:
+            command = json.loads(line)
+        except json.JSONDecodeError:
+            return False
+        if not isinstance(command, dict) or command.get("type") != "auth_response":
+            return False
+
+        value = command.get("value")
+        with self._condition:
+            scraper = command.get("scraper") or (next(iter(self._pending)) if len(self._pending) == 1 else None)
+        if not isinstance(scraper, str) or not isinstance(value, str) or not value.strip():
+            logger.warning("[Auth] Ignoring auth response without a scraper or value")
+            return False
+        self.submit(scraper, value.strip())
+        logger.info(f"[Auth] Received challenge response for {scraper}")
+        return True
+
+    def _wait(self, scraper: str, timeout: float) -> str | None:
+        with self._condition:
+            self._condition.wait_for(lambda: scraper in self._responses, timeout=timeout)
+            self._pending.discard(scraper)
+            return self._responses.pop(scraper, None)
+
+    async def wait_for_response(self, scraper: str, timeout: float) -> str | None:
+        """The response for the scraper's challenge, or None if none came within timeout seconds."""
+        with self._condition:
+            # A response left over from an earlier challenge doesn't answer this one
+            self._responses.pop(scraper, None)
+            self._pending.add(scraper)
+        return await asyncio.to_thread(self._wait, scraper, timeout)
+
+
+# Process-wide challenge state; reads its responses from the pacing control listener
+auth_challenges = AuthChallenges()
+pacing_control.add_handler(auth_challenges.handle_line)
@@ -0,0 +1,157 @@
+"""
+Per-scraper cooldown after a supplier blocks us.
+
+When a scraper ends a run blocked (captcha or access denied), retrying an hour
+later only digs the hole deeper. Each consecutive blocked run puts the scraper
+into a longer cooldown (2h, 8h, then 24h), during which run_job defers it the
+same way it defers maintenance windows. A clean successful run resets the
+escalation. State is kept in a small JSON file next to the instance id, so it
+survives daemon restarts.
+
+Usage:
+    python -m core.block_cooldown status
+    python -m core.block_cooldown clear phillips
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import logging
+import os
+import sys
+import tempfile
+import threading
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+from typing import Any
+
+from core.instance import INSTANCE_DIR
+
+logger = logging.getLogger(__name__)
+
+# Failure categories that mean the supplier is actively blocking us
+BLOCKED_CATEGORIES = {"captcha_detected", "access_denied"}
+
+# Cooldown after the 1st, 2nd and 3rd+ consecutive blocked run
+COOLDOWN_SCHEDULE = (timedelta(hours=2), timedelta(hours=8), timedelta(hours=24))
+
+
+def _parse_until(entry: dict[str, Any]) -> datetime | None:
+    """The entry's cooldown end, or None if it has none or it can't be parsed."""
+    try:
+        return datetime.fromisoformat(entry["until"]) if entry.get("until") else None
+    except (TypeError, ValueError):
+        return None
+
+
+class BlockCooldowns:
+    """Tracks consecutive blocks per scraper and when each may run again."""
+
+    def __init__(self, path: Path | None = None) -> None:
+        self.path = path or INSTANCE_DIR / "block_cooldowns.json"
+        self._lock = threading.Lock()
+
+    def _load(self) -> dict[str, dict[str, Any]]:
+        try:
+            data = json.loads(self.path.read_text())
+        except FileNotFoundError:
+            return {}
+        except (OSError, json.JSONDecodeError) as e:
+            logger.warning(f"Ignoring unreadable block cooldown file {self.path}: {e}")
+            return {}
+        if not isinstance(data, dict):
+            return {}
+        malformed = [scraper for scraper, entry in data.items() if not isinstance(entry, dict)]
+        if malformed:
+            logger.warning(f"Ignoring malformed block cooldown entries in {self.path}: {', '.join(malformed)}")
+        return {scraper: entry for scraper, entry in data.items() if isinstance(entry, dict)}
+
+    def _save(self, data: dict[str, dict[str, Any]]) -> None:
+        try:
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+            # Write to a sibling temp file and swap it in so a crash never leaves a torn file
+            fd, tmp = tempfile.mkstemp(dir=self.path.parent, prefix=f".{self.path.name}.", suffix=".tmp")
+            try:
+                with os.fdopen(fd, "w", encoding="utf-8") as f:
+                    f.write(json.dumps(data, indent=2, sort_keys=True))
+                os.replace(tmp, self.path)
+            except BaseException:
+                Path(tmp).unlink(missing_ok=True)
+                raise
+        except OSError as e:
+            logger.warning(f"Could not persist block cooldowns to {self.path}: {e}")
+
+    def cooldown_until(self, scraper: str, now: datetime | None = None) -> datetime | None:
+        """End of the scraper's active cooldown, or None if it may run."""
+        with self._lock:
+            entry = self._load().get(scraper)
+        until = _parse_until(entry) if entry else None
+        return until if until and until > (now or datetime.now(timezone.utc)) else None
+
+    def record_blocked(self, scraper: str, now: datetime | None = None) -> datetime:
+        """Start (or escalate) a cooldown for a blocked scraper and return when it ends."""
+        now = now or datetime.now(timezone.utc)
+        with self._lock:
+            data = self._load()
+            try:
+                blocks = int(data.get(scraper, {}).get("consecutive_blocks", 0)) + 1
+            except (TypeError, ValueError):
+                blocks = 1
+            until = now + COOLDOWN_SCHEDULE[min(blocks, len(COOLDOWN_SCHEDULE)) - 1]
+            data[scraper] = {"consecutive_blocks": blocks, "blocked_at": now.isoformat(), "until": until.isoformat()}
+            self._save(data)
+        logger.warning(f"[Runner] {scraper}: blocked {blocks} run(s) in a row, in block cooldown until {until.isoformat()}")
+        return until
+
+    def record_success(self, scraper: str) -> None:
+        """Reset the escalation after a clean run."""
+        with self._lock:
+            data = self._load()
+            if data.pop(scraper, None) is not None:
+                self._save(data)
+                logger.info(f"[Runner] {scraper}: clean run, block cooldown escalation reset")
+
+    def clear(self, scraper: str) -> bool:
+        """Lift a scraper's cooldown, e.g. once the supplier has whitelisted us. False if it had none."""
+        with self._lock:
+            data = self._load()
+            if data.pop(scraper, None) is None:
+                return False
+            self._save(data)
+        logger.info(f"[Runner] {scraper}: block cooldown cleared")
+        return True
+
+    def status(self, now: datetime | None = None) -> dict[str, dict[str, Any]]:
+        """Every tracked scraper with its consecutive blocks, cooldown end and whether it's active."""
+        now = now or datetime.now(timezone.utc)
+        with self._lock:
+            data = self._load()
+        result = {}
+        for scraper, entry in data.items():
+            until = _parse_until(entry)
+            result[scraper] = {**entry, "active": until is not None and until > now}
+        return result
+
+
+block_cooldowns = BlockCooldowns()
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Show or clear per-scraper block cooldowns")
+    sub = parser.add_subparsers(dest="command", required=True)
+    sub.add_parser("status", help="Print block cooldowns as JSON")
+    clear = sub.add_parser("clear", help="Lift a scraper's cooldown")
+    clear.add_argument("scraper")
+    args = parser.parse_args()
+
+    if args.command == "status":
+        print(json.dumps(block_cooldowns.status(), indent=2))
+        return
+    if not block_cooldowns.clear(args.scraper):
+        print(f"{args.scraper} has no block cooldown", file=sys.stderr)
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,107 @@
+"""
+Keep a running job from filling the disk.
+
+Preflight refuses to start a job without HEALTH_MIN_FREE_DISK_MB free, but a
+long job can still run the disk full halfway through, and then result writes,
+logs and uploads all fail at once. While a job runs, free space is re-checked
+between SKUs, at most every DISK_CHECK_INTERVAL_SECONDS (default 60):
+
+- below HEALTH_WARN_FREE_DISK_MB a warning is raised once per job, so someone
+  can free space before it matters
+- below HEALTH_MIN_FREE_DISK_MB the job pauses before the next SKU, keeping
+  everything scraped so far, and resumes by itself once space is freed
+
+A pause lasts at most DISK_PAUSE_MAX_SECONDS (default 1800) and ends early on
+a stop request. Either way the guard gives up: the job holds back its remaining
+SKUs and the daemon releases the chunk to another runner rather than sitting on
+its lease.
+
+Both thresholds are shared with core.health, so the same critical level also
+fails the health check and stops the daemon from claiming new work.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+import os
+import time
+from typing import Callable
+
+from core.health import RunnerHealth, runner_health
+from core.job_pause import JobPause, job_pause
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_DISK_CHECK_INTERVAL_SECONDS = 60
+DISK_POLL_INTERVAL_SECONDS = 30
+DEFAULT_DISK_PAUSE_MAX_SECONDS = 1800
+
+# (level, message, free_mb) for each automatic action, so the job log records it
+DiskNotice = Callable[[str, str, float | None], None]
+
+
+class DiskGuard:
+    """Between-SKU free space checks for one job."""
+
+    def __init__(
+        self,
+        health: RunnerHealth | None = None,
+        check_interval: float | None = None,
+        poll_interval: float = DISK_POLL_INTERVAL_SECONDS,
+        max_pause: float | None = None,
+        pause: JobPause | None = None,
+    ) -> None:
+        self.health = health or runner_health
+        self.check_interval = (
+            check_interval
+            if check_interval is not None
+            else float(os.environ.get("DISK_CHECK_INTERVAL_SECONDS", str(DEFAULT_DISK_CHECK_INTERVAL_SECONDS)))
+        )
+        self.poll_interval = poll_interval
+        self.max_pause = (
+            max_pause if max_pause is not None else float(os.environ.get("DISK_PAUSE_MAX_SECONDS", str(DEFAULT_DISK_PAUSE_MAX_SECONDS)))
+        )
+        self.pause = pause or job_pause
+        # Set once a pause hit max_pause or a stop request; every later check then fails at once
+        self.gave_up = False
+        self._last_check: float | None = None
+        self._warned = False
+
+    async def guard(self, position: str, notify: DiskNotice) -> float:
+        """
+        Check free space if a check is due, pausing while it is critically low. Returns the seconds spent paused.
+
+        If the pause ends without space being freed, gave_up is set and the caller should stop the job.
+        """
+        if self.gave_up:
+            return 0.0
+        now = time.monotonic()
+        if self._last_check is not None and now - self._last_check < self.check_interval:
+            return 0.0
+        self._last_check = now
+
+        free_mb = self.health.free_disk_mb()
+        if free_mb is None:
+            return 0.0
+        if free_mb < self.health.min_free_disk_mb:
+            notify("error", f"Only {free_mb:.0f}MB free, need {self.health.min_free_disk_mb}MB; pausing before {position} until space is freed", free_mb)
+            started = time.monotonic()
+            while free_mb is not None and free_mb < self.health.min_free_disk_mb:
+                remaining = self.max_pause - (time.monotonic() - started)
+                if remaining <= 0 or await asyncio.to_thread(self.pause.wait_for_stop, min(self.poll_interval, remaining)):
+                    self.gave_up = True
+                    break
+                free_mb = self.health.free_disk_mb()
+            paused_for = time.monotonic() - started
+            self._last_check = time.monotonic()
+            if self.gave_up:
+                reason = "stop requested" if self.pause.stopping else f"still only {free_mb:.0f}MB free"
+                notify("error", f"Giving up on disk space at {position} after {paused_for:.0f}s paused ({reason})", free_mb)
+            else:
+                notify("info", f"Disk space recovered, resuming at {position} after {paused_for:.0f}s paused", free_mb)
+            return paused_for
+        if free_mb < self.health.warn_free_disk_mb and not self._warned:
+            self._warned = True
+            notify("warning", f"Disk space low: {free_mb:.0f}MB free; the job pauses below {self.health.min_free_disk_mb}MB", free_mb)
+        return 0.0
@@ -27,6 +27,10 @@ from typing import Any
 
 logger = logging.getLogger(__name__)
 
+# Current structured event schema version. Bump on any breaking payload change
+# and keep docs/event-schema-v2.json in sync so consumers can detect drift.
+EVENT_SCHEMA_VERSION = "2.0"
+
 
 # =============================================================================
 # Event Types
@@ -56,6 +60,7 @@ class EventType(str, Enum):
     SCRAPER_FAILED = "scraper.failed"
     SCRAPER_BROWSER_INIT = "scraper.browser_init"
     SCRAPER_BROWSER_RESTART = "scraper.browser_restart"
+    SCRAPER_SELECTOR_DRIFT = "scraper.selector_drift"
 
     # SKU Processing
     SKU_PROCESSING = "sku.processing"
@@ -83,6 +88,14 @@ class EventType(str, Enum):
 
     # Login Status
     LOGIN_SELECTOR_STATUS = "login.selector_status"
+    AUTH_CHALLENGE = "auth.challenge"
+
+    # Request pacing (signals for the desktop app's adaptive pacing controller)
+    REQUEST_COMPLETED = "request.completed"
+    PACING_CHANGED = "pacing.changed"
+
+    # Coordinator API traffic (metadata only, streamed with DEBUG_API_TRAFFIC)
+    API_REQUEST = "api.request"
 
     # Step Events (v2)
     STEP_STARTED = "step.started"
@@ -123,6 +136,7 @@ class ScraperEvent:
     - event_id: Unique identifier for this event
     - severity: The severity level of the event
     - version: Schema version ("1.0" or "2.0")
+    - seq: Monotonic sequence number, assigned by the EventBus on emit
 
     Plus optional context-specific fields in the `data` dict.
     """
@@ -134,6 +148,7 @@ class ScraperEvent:
     severity: EventSeverity = EventSeverity.INFO
     data: dict[str, Any] = field(default_factory=dict)
     version: str = "1.0"
+    seq: int | None = None
 
     def to_dict(self) -> dict[str, Any]:
         """Convert event to JSON-serializable dictionary."""
@@ -148,6 +163,8 @@ class ScraperEvent:
         # Only include version in output for v2 events to maintain v1 compatibility
         if self.version == "2.0":
             result["version"] = self.version
+        if self.seq is not None:
+            result["seq"] = self.seq
         return result
 
     def to_json(self) -> str:
@@ -165,6 +182,7 @@ class ScraperEvent:
             severity=EventSeverity(d.get("severity", "info")),
             data=d.get("data", {}),
             version=d.get("version", "1.0"),
+            seq=d.get("seq"),
         )
 
     def __str__(self) -> str:
@@ -195,6 +213,7 @@ class EventBus:
     - Event buffering (keeps last N events per job)
     - Thread-safe event emission
     - Optional event persistence to JSON file
+    - Monotonic sequence numbers so a reloaded consumer can resume without gaps
     """
 
     def __init__(self, buffer_size: int = 500, persist_path: Path | None = None, max_jobs: int = 100):
@@ -209,6 +228,10 @@ class EventBus:
         self._job_events: dict[str, list[ScraperEvent]] = {}
         # Order of job IDs for cleanup
         self._job_order: list[str] = []
+        # Next sequence number to assign on emit
+        self._next_seq = 1
+        # Highest sequence number dropped from each buffer (None key = global buffer)
+        self._evicted_seq: dict[str | None, int] = {}
 
     def subscribe(self, callback: EventCallback) -> None:
         """Register a callback to receive events."""
@@ -228,9 +251,15 @@ class EventBus:
         Thread-safe. Events are buffered and optionally persisted.
         """
         with self._lock:
+            # Sequence numbers are assigned exactly once, here; the event is immutable afterwards
+            if event.seq is None:
+                object.__setattr__(event, "seq", self._next_seq)
+                self._next_seq += 1
+
             # Add to global buffer
             self._events.append(event)
             if len(self._events) > self._buffer_size:
+                self._record_eviction(None, self._events[: -self._buffer_size])
                 self._events = self._events[-self._buffer_size :]
 
             # Add to per-job buffer
@@ -240,6 +269,7 @@ class EventBus:
                     if len(self._job_order) >= self._max_jobs:
                         oldest_job = self._job_order.pop(0)
                         self._job_events.pop(oldest_job, None)
+                        self._evicted_seq.pop(oldest_job, None)
 
                     self._job_events[event.job_id] = []
                     self._job_order.append(event.job_id)
@@ -252,6 +282,7 @@ class EventBus:
                 self._job_events[event.job_id].append(event)
                 # Limit per-job buffer
                 if len(self._job_events[event.job_id]) > self._buffer_size:
+                    self._record_eviction(event.job_id, self._job_events[event.job_id][: -self._buffer_size])
                     self._job_events[event.job_id] = self._job_events[event.job_id][-self._buffer_size :]
 
             # Notify subscribers
@@ -265,12 +296,35 @@ class EventBus:
             if self._persist_path:
                 self._persist_event(event)
 
+    def _record_eviction(self, job_id: str | None, dropped: list[ScraperEvent]) -> None:
+        """Remember the newest sequence number dropped from a buffer. Caller holds the lock."""
+        seqs = [e.seq for e in dropped if e.seq is not None]
+        if seqs:
+            self._evicted_seq[job_id] = max(self._evicted_seq.get(job_id, 0), *seqs)
+
+    def has_gap(self, since_seq: int, job_id: str | None = None) -> bool:
+        """Whether events after since_seq have already been evicted from the buffer.
+
+        A consumer resuming from since_seq cannot replay those events and should
+        resync from current state instead of assuming it has seen everything.
+        """
+        with self._lock:
+            key = job_id if job_id and job_id in self._job_events else None
+            return since_seq < self._evicted_seq.get(key, 0)
+
+    def oldest_seq(self, job_id: str | None = None) -> int | None:
+        """Sequence number of the oldest event still buffered, or None when empty."""
+        with self._lock:
+            events = self._job_events[job_id] if job_id and job_id in self._job_events else self._events
+            return next((e.seq for e in events if e.seq is not None), None)
+
     def get_events(
         self,
         job_id: str | None = None,
         event_types: list[EventType] | None = None,
         since: str | None = None,
         limit: int = 100,
+        since_seq: int | None = None,
     ) -> list[ScraperEvent]:
         """Retrieve buffered events with optional filtering.
 
@@ -279,6 +333,10 @@ class EventBus:
             event_types: Filter to specific event types
             since: ISO timestamp to filter events after
             limit: Maximum number of events to return
+            since_seq: Only return events with a sequence number greater than this.
+                       The oldest matching events are returned first so a caller
+                       can page forward without gaps. Events evicted from the
+                       buffer cannot be returned; check has_gap() to detect that.
         """
         with self._lock:
             if job_id and job_id in self._job_events:
@@ -291,18 +349,28 @@ class EventBus:
             events = [e for e in events if e.event_type in event_types]
         if since:
             events = [e for e in events if e.timestamp > since]
+        if since_seq is not None:
+            events = [e for e in events if e.seq is not None and e.seq > since_seq]
+            return events[:limit]
 
         return events[-limit:]
 
+    @property
+    def last_seq(self) -> int:
+        """Sequence number of the most recently emitted event (0 if none)."""
+        with self._lock:
+            return self._next_seq - 1
+
     def get_events_as_dicts(
         self,
         job_id: str | None = None,
         event_types: list[EventType] | None = None,
         since: str | None = None,
         limit: int = 100,
+        since_seq: int | None = None,
     ) -> list[dict[str, Any]]:
         """Retrieve buffered events as dictionaries (for JSON serialization)."""
-        events = self.get_events(job_id, event_types, since, limit)
+        events = self.get_events(job_id, event_types, since, limit, since_seq)
         return [e.to_dict() for e in events]
 
     def clear_job(self, job_id: str) -> None:
@@ -478,6 +546,22 @@ class EventEmitter:
             reason=reason,
         )
 
+    def selector_drift(
+        self,
+        scraper: str,
+        missing_ratio: float,
+        missing: list[dict[str, Any]],
+        aborted: bool = False,
+    ) -> ScraperEvent:
+        return self._emit(
+            EventType.SCRAPER_SELECTOR_DRIFT,
+            severity=EventSeverity.ERROR if aborted else EventSeverity.WARNING,
+            scraper=scraper,
+            missing_ratio=round(missing_ratio, 3),
+            missing=missing,
+            aborted=aborted,
+        )
+
     # SKU processing events
     def sku_processing(self, scraper: str, worker_id: str, sku: str) -> ScraperEvent:
         return self._emit(
@@ -666,6 +750,44 @@ class EventEmitter:
             status=status,
         )
 
+    def auth_challenge(self, scraper: str, challenge_type: str, timeout_seconds: int) -> ScraperEvent:
+        return self._emit(
+            EventType.AUTH_CHALLENGE,
+            severity=EventSeverity.WARNING,
+            scraper=scraper,
+            challenge_type=challenge_type,
+            timeout_seconds=timeout_seconds,
+        )
+
+    # Pacing events
+    def request_completed(
+        self,
+        scraper: str,
+        url: str,
+        status_code: int | None,
+        latency_ms: int,
+        delay_ms: int,
+        sku: str | None = None,
+    ) -> ScraperEvent:
+        return self._emit(
+            EventType.REQUEST_COMPLETED,
+            scraper=scraper,
+            sku=sku,
+            url=url,
+            status_code=status_code,
+            latency_ms=latency_ms,
+            delay_ms=delay_ms,
+        )
+
+    def pacing_changed(self, scraper: str, delay_ms: int, previous_delay_ms: int, reason: str | None = None) -> ScraperEvent:
+        return self._emit(
+            EventType.PACING_CHANGED,
+            scraper=scraper,
+            delay_ms=delay_ms,
+            previous_delay_ms=previous_delay_ms,
+            reason=reason,
+        )
+
     # Step events (v2)
     def step_started(
         self,
@@ -689,7 +811,7 @@ class EventEmitter:
                     "name": name or action,
                 },
             },
-            version="2.0",
+            version=EVENT_SCHEMA_VERSION,
         )
         self._bus.emit(event)
         return event
@@ -742,7 +864,7 @@ class EventEmitter:
             job_id=self._job_id,
             severity=EventSeverity.INFO,
             data=data,
-            version="2.0",
+            version=EVENT_SCHEMA_VERSION,
         )
         self._bus.emit(event)
         return event
@@ -792,7 +914,7 @@ class EventEmitter:
                     "retryable": retryable,
                 },
             },
-            version="2.0",
+            version=EVENT_SCHEMA_VERSION,
         )
         self._bus.emit(event)
         return event
@@ -822,7 +944,7 @@ class EventEmitter:
                 },
                 "reason": reason,
             },
-            version="2.0",
+            version=EVENT_SCHEMA_VERSION,
         )
         self._bus.emit(event)
         return event
@@ -860,7 +982,7 @@ class EventEmitter:
             job_id=self._job_id,
             severity=EventSeverity.INFO if found else EventSeverity.WARNING,
             data=data,
-            version="2.0",
+            version=EVENT_SCHEMA_VERSION,
         )
         self._bus.emit(event)
         return event
@@ -895,7 +1017,7 @@ class EventEmitter:
             job_id=self._job_id,
             severity=EventSeverity.INFO if status == "SUCCESS" else EventSeverity.WARNING,
             data=data,
-            version="2.0",
+            version=EVENT_SCHEMA_VERSION,
         )
         self._bus.emit(event)
         return event
@@ -929,7 +1051,7 @@ class EventEmitter:
 _default_persist_path = Path(__file__).parent.parent.parent / "data" / "events" / "events.jsonl"
 
 # Global event bus - accessible across the application
-event_bus = EventBus(buffer_size=1000, persist_path=_default_persist_path)
+event_bus = EventBus(buffer_size=2000, persist_path=_default_persist_path)
 
 
 def create_emitter(job_id: str) -> EventEmitter:
@@ -0,0 +1,257 @@
+"""
+Runner health determination.
+
+A single place that decides whether this runner is healthy, so the sidecar
+`/healthz` endpoint, `/status`, and the coordinator heartbeat all agree.
+
+The runner is considered failed when:
+- free disk space under the data directory drops below HEALTH_MIN_FREE_DISK_MB
+- the coordinator API has been unreachable for longer than HEALTH_API_UNREACHABLE_MINUTES
+
+It is degraded while free disk space is below HEALTH_WARN_FREE_DISK_MB. Below
+HEALTH_MIN_FREE_DISK_MB new work is deferred as well, until space is freed;
+running jobs pause themselves (see core.disk_guard).
+
+With PAUSE_ON_BATTERY set, new work is also deferred while the machine runs on
+battery below PAUSE_ON_BATTERY_BELOW_PERCENT. That doesn't make the runner
+unhealthy; the snapshot just reports it under "power", along with whether
+sleep is currently being prevented for a running job.
+
+The snapshot also carries the success rate of recent coordinator requests
+(see core.api_request_log), for a quick read on connectivity.
+"""
+
+from __future__ import annotations
+
+import faulthandler
+import logging
+import os
+import shutil
+import signal
+import sys
+import threading
+import time
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any
+
+from core.api_request_log import api_request_log
+from core.settings_manager import PROJECT_ROOT
+from core.sleep_inhibitor import sleep_inhibitor
+
+try:
+    import psutil
+
+    HAS_PSUTIL = True
+except ImportError:
+    HAS_PSUTIL = False
+
+logger = logging.getLogger(__name__)
+
+HEALTH_HEALTHY = "healthy"
+HEALTH_DEGRADED = "degraded"
+HEALTH_FAILED = "failed"
+
+DEFAULT_API_UNREACHABLE_MINUTES = 10
+DEFAULT_MIN_FREE_DISK_MB = 500
+DEFAULT_WARN_FREE_DISK_MB = 2000
+DEFAULT_MIN_BATTERY_PERCENT = 50
+
+
+def read_version() -> str:
+    """Read the runner version from the VERSION file."""
+    version_file = PROJECT_ROOT / "VERSION"
+    if version_file.exists():
+        return version_file.read_text().strip()
+    return "unknown"
+
+
+def _iso(timestamp: float | None) -> str | None:
+    if timestamp is None:
+        return None
+    return datetime.fromtimestamp(timestamp, tz=timezone.utc).isoformat()
+
+
+class RunnerHealth:
+    """Thread-safe tracker for runner-level health signals."""
+
+    def __init__(
+        self,
+        version: str | None = None,
+        disk_path: Path | None = None,
+        api_unreachable_minutes: int | None = None,
+        min_free_disk_mb: int | None = None,
+        warn_free_disk_mb: int | None = None,
+        pause_on_battery: bool | None = None,
+        min_battery_percent: int | None = None,
+    ) -> None:
+        self.version = version or read_version()
+        self.disk_path = disk_path or PROJECT_ROOT
+        self.api_unreachable_seconds = 60 * (
+            api_unreachable_minutes
+            if api_unreachable_minutes is not None
+            else int(os.environ.get("HEALTH_API_UNREACHABLE_MINUTES", str(DEFAULT_API_UNREACHABLE_MINUTES)))
+        )
+        self.min_free_disk_mb = (
+            min_free_disk_mb if min_free_disk_mb is not None else int(os.environ.get("HEALTH_MIN_FREE_DISK_MB", str(DEFAULT_MIN_FREE_DISK_MB)))
+        )
+        # Never below the critical threshold, so low always comes before full
+        self.warn_free_disk_mb = max(
+            self.min_free_disk_mb,
+            warn_free_disk_mb if warn_free_disk_mb is not None else int(os.environ.get("HEALTH_WARN_FREE_DISK_MB", str(DEFAULT_WARN_FREE_DISK_MB))),
+        )
+        self.pause_on_battery = (
+            pause_on_battery if pause_on_battery is not None else os.environ.get("PAUSE_ON_BATTERY", "").lower() in ("1", "true", "yes")
+        )
+        self.min_battery_percent = (
+            min_battery_percent
+            if min_battery_percent is not None
+            else int(os.environ.get("PAUSE_ON_BATTERY_BELOW_PERCENT", str(DEFAULT_MIN_BATTERY_PERCENT)))
+        )
+
+        self.started_at = time.time()
+        self.current_job: str | None = None
+        self.queue_depth = 0
+        self.last_successful_upload: float | None = None
+        self.last_api_success: float | None = None
+        # Start of the current run of consecutive API failures, None while reachable
+        self.api_unreachable_since: float | None = None
+        self._lock = threading.Lock()
+
+    def record_api_success(self) -> None:
+        with self._lock:
+            self.last_api_success = time.time()
+            self.api_unreachable_since = None
+
+    def record_api_failure(self) -> None:
+        with self._lock:
+            if self.api_unreachable_since is None:
+                self.api_unreachable_since = time.time()
+
+    def record_upload(self) -> None:
+        with self._lock:
+            self.last_successful_upload = time.time()
+
+    def set_current_job(self, job_id: str | None) -> None:
+        with self._lock:
+            self.current_job = job_id
+
+    def set_queue_depth(self, depth: int) -> None:
+        with self._lock:
+            self.queue_depth = max(0, depth)
+
+    def free_disk_mb(self) -> float | None:
+        try:
+            return shutil.disk_usage(self.disk_path).free / (1024 * 1024)
+        except OSError as e:
+            logger.warning(f"Could not read disk usage for {self.disk_path}: {e}")
+            return None
+
+    def battery(self) -> tuple[float, bool] | None:
+        """Battery (percent, on_battery), or None on machines without one."""
+        if not HAS_PSUTIL:
+            return None
+        try:
+            battery = psutil.sensors_battery()
+        except Exception as e:
+            logger.debug(f"Could not read battery status: {e}")
+            return None
+        if battery is None:
+            return None
+        return float(battery.percent), not battery.power_plugged
+
+    def check_power_monitoring(self) -> bool:
+        """Warn at startup when PAUSE_ON_BATTERY is set but the battery can't be read. Returns False in that case."""
+        if self.pause_on_battery and not HAS_PSUTIL:
+            logger.warning("PAUSE_ON_BATTERY is set but psutil is not installed; work will not pause on battery")
+            return False
+        return True
+
+    def defer_work_reason(self, battery: tuple[float, bool] | None = None, free_mb: float | None = None) -> str | None:
+        """Why new work shouldn't be claimed right now, or None to go ahead."""
+        free_mb = free_mb if free_mb is not None else self.free_disk_mb()
+        if free_mb is not None and free_mb < self.min_free_disk_mb:
+            return f"disk_full: {free_mb:.0f}MB free, need {self.min_free_disk_mb}MB"
+        if not self.pause_on_battery:
+            return None
+        battery = battery if battery is not None else self.battery()
+        if battery is None:
+            return None
+        percent, on_battery = battery
+        if on_battery and percent < self.min_battery_percent:
+            return f"on_battery: {percent:.0f}% (below {self.min_battery_percent}%)"
+        return None
+
+    def snapshot(self, now: float | None = None) -> dict[str, Any]:
+        """Evaluate health and return the shared JSON view."""
+        now = now if now is not None else time.time()
+        free_mb = self.free_disk_mb()
+        battery = self.battery()
+        defer_reason = self.defer_work_reason(battery, free_mb)
+
+        with self._lock:
+            reasons: list[str] = []
+            status = HEALTH_HEALTHY
+
+            if free_mb is not None and free_mb < self.min_free_disk_mb:
+                reasons.append(f"disk_full: {free_mb:.0f}MB free, need {self.min_free_disk_mb}MB")
+                status = HEALTH_FAILED
+            elif free_mb is not None and free_mb < self.warn_free_disk_mb:
+                reasons.append(f"disk_low: {free_mb:.0f}MB free")
+                status = HEALTH_DEGRADED
+
+            api_reachable = self.api_unreachable_since is None
+            if not api_reachable:
+                unreachable_for = now - self.api_unreachable_since
+                if unreachable_for > self.api_unreachable_seconds:
+                    reasons.append(f"api_unreachable: {unreachable_for / 60:.0f} minutes")
+                    status = HEALTH_FAILED
+                else:
+                    reasons.append("api_unreachable")
+                    if status == HEALTH_HEALTHY:
+                        status = HEALTH_DEGRADED
+
+            return {
+                "status": status,
+                "version": self.version,
+                "current_job": self.current_job,
+                "queue_depth": self.queue_depth,
+                "last_successful_upload": _iso(self.last_successful_upload),
+                "api_reachable": api_reachable,
+                "api_success_rate": api_request_log.success_rate(),
+                "reasons": reasons,
+                "uptime_seconds": int(now - self.started_at),
+                "disk": {
+                    "free_mb": round(free_mb) if free_mb is not None else None,
+                    "warn_below_mb": self.warn_free_disk_mb,
+                    "critical_below_mb": self.min_free_disk_mb,
+                },
+                "power": {
+                    "on_battery": battery[1] if battery else False,
+                    "battery_percent": battery[0] if battery else None,
+                    "pause_on_battery": self.pause_on_battery,
+                    "deferring_work": defer_reason,
+                    "sleep_inhibited": sleep_inhibitor.held,
+                },
+            }
+
+    def is_failed(self) -> bool:
+        return self.snapshot()["status"] == HEALTH_FAILED
+
+
+def register_state_dump_signal() -> bool:
+    """Dump every thread's stack to stderr on SIGUSR1.
+
+    The desktop app's stall watchdog sends this soft interrupt before deciding
+    whether to kill a hung run. faulthandler writes from the C signal handler,
+    so the dump works even when the main thread is stuck in a blocking call.
+    Not available on Windows.
+    """
+    if not hasattr(signal, "SIGUSR1"):
+        return False
+    faulthandler.register(signal.SIGUSR1, file=sys.stderr, all_threads=True)
+    return True
+
+
+# Process-wide tracker shared by the API client, daemon and sidecar server
+runner_health = RunnerHealth()
@@ -0,0 +1,294 @@
+"""
+Runner instance identity and single-instance locking.
+
+Each runner gets a stable instance_id, created on first run and recorded
+together with the OS user that created it. Heartbeats carry the id so the
+coordinator can tell apart two runners that share a hostname-derived name.
+
+Optional location tag and labels (RUNNER_LOCATION_TAG, RUNNER_LABELS) say which
+store a runner belongs to, so HQ can segment heartbeats, uploads and metrics.
+RUNNER_FAILOVER_PRIORITY ranks runners that cover for each other: 0 is the
+primary, and standby machines use higher numbers.
+
+The lock file stops a second daemon for the same user from starting and
+clobbering the first. The file itself stays put; the lock is an OS lock on it
+(flock, or msvcrt.locking on Windows), which the OS drops when its holder
+exits, so a crashed process never leaves a stale lock behind and there is no
+check-then-delete race between two runners starting at once. The holder's PID
+is written into the file for the error message.
+"""
+
+from __future__ import annotations
+
+import getpass
+import json
+import logging
+import os
+import re
+import secrets
+import uuid
+from pathlib import Path
+from typing import Any
+
+from core.settings_manager import PROJECT_ROOT
+
+try:
+    import psutil
+
+    HAS_PSUTIL = True
+except ImportError:
+    HAS_PSUTIL = False
+
+logger = logging.getLogger(__name__)
+
+INSTANCE_DIR = PROJECT_ROOT / "data"
+MAX_LABELS = 10
+TAG_PATTERN = re.compile(r"^[a-z0-9][a-z0-9_-]*$")
+
+
+class AlreadyRunningError(RuntimeError):
+    """Raised when another runner instance already holds the lock."""
+
+    pass
+
+
+def current_os_user() -> str:
+    """Return the current OS username, or 'unknown' if it can't be determined."""
+    try:
+        return getpass.getuser()
+    except Exception:
+        return "unknown"
+
+
+def load_instance_id(instance_dir: Path | None = None) -> str:
+    """Return this runner's instance_id, creating it on first run.
+
+    RUNNER_INSTANCE_ID takes precedence so the desktop app can pass its own id.
+    If the recorded OS user differs from the current one, a fresh id is created
+    for this user instead of silently sharing the other user's identity.
+    """
+    env_id = os.environ.get("RUNNER_INSTANCE_ID")
+    if env_id:
+        return env_id
+
+    instance_dir = instance_dir or INSTANCE_DIR
+    user = current_os_user()
+    path = instance_dir / f"instance-{user}.json"
+
+    if path.exists():
+        try:
+            data = json.loads(path.read_text())
+        except (OSError, json.JSONDecodeError) as e:
+            logger.warning(f"Ignoring unreadable instance file {path}: {e}")
+            data = {}
+        if data.get("os_user") not in (None, user):
+            logger.warning(f"Instance file {path} belongs to OS user '{data.get('os_user')}', not '{user}'; starting fresh")
+        elif data.get("instance_id"):
+            return str(data["instance_id"])
+
+    instance_id = str(uuid.uuid4())
+    try:
+        instance_dir.mkdir(parents=True, exist_ok=True)
+        path.write_text(json.dumps({"instance_id": instance_id, "os_user": user}))
+        logger.info(f"Created runner instance_id {instance_id} for OS user '{user}'")
+    except OSError as e:
+        logger.warning(f"Could not persist instance_id to {path}: {e}")
+    return instance_id
+
+
+def load_redaction_key(instance_dir: Path | None = None) -> bytes:
+    """Return this runner's secret for hashing redacted fields, creating it on first run.
+
+    REDACTION_HASH_KEY takes precedence, e.g. to share one key across a store's
+    runners so their hashes compare. The key never leaves the machine, so a
+    hashed price can't be recovered by hashing every possible amount.
+    """
+    env_key = os.environ.get("REDACTION_HASH_KEY")
+    if env_key:
+        return env_key.encode("utf-8")
+
+    instance_dir = instance_dir or INSTANCE_DIR
+    path = instance_dir / f"redaction-key-{current_os_user()}"
+    try:
+        key = path.read_text().strip()
+        if key:
+            return key.encode("utf-8")
+    except OSError:
+        pass
+
+    key = secrets.token_hex(32)
+    try:
+        instance_dir.mkdir(parents=True, exist_ok=True)
+        path.write_text(key)
+        if os.name != "nt":
+            path.chmod(0o600)
+    except OSError as e:
+        logger.warning(f"Could not persist redaction key to {path}: {e}; hashes will change after a restart")
+    return key.encode("utf-8")
+
+
+def load_runner_tags() -> dict[str, object]:
+    """This runner's location tag and labels from the environment.
+
+    Tags must be lowercase with no spaces; invalid ones are dropped with a
+    warning, as are labels beyond the first MAX_LABELS.
+    """
+    location_tag = os.environ.get("RUNNER_LOCATION_TAG", "").strip() or None
+    if location_tag and not TAG_PATTERN.match(location_tag):
+        logger.warning(f"Ignoring invalid RUNNER_LOCATION_TAG '{location_tag}': use lowercase letters, digits, '-' or '_'")
+        location_tag = None
+
+    labels: list[str] = []
+    for label in os.environ.get("RUNNER_LABELS", "").split(","):
+        label = label.strip()
+        if not label or label in labels:
+            continue
+        if not TAG_PATTERN.match(label):
+            logger.warning(f"Ignoring invalid runner label '{label}': use lowercase letters, digits, '-' or '_'")
+            continue
+        labels.append(label)
+    if len(labels) > MAX_LABELS:
+        logger.warning(f"Only the first {MAX_LABELS} runner labels are used; ignoring {', '.join(labels[MAX_LABELS:])}")
+        labels = labels[:MAX_LABELS]
+
+    return {"location_tag": location_tag, "labels": labels}
+
+
+def load_failover_priority() -> int | None:
+    """This runner's failover priority from the environment, or None if it takes no part in failover."""
+    raw = os.environ.get("RUNNER_FAILOVER_PRIORITY", "").strip()
+    if not raw:
+        return None
+    try:
+        priority = int(raw)
+    except ValueError:
+        priority = -1
+    if priority < 0:
+        logger.warning(f"Ignoring invalid RUNNER_FAILOVER_PRIORITY '{raw}': use 0 for the primary and higher numbers for standbys")
+        return None
+    return priority
+
+
+# Windows API constants for _windows_pid_alive
+PROCESS_QUERY_LIMITED_INFORMATION = 0x1000
+ERROR_ACCESS_DENIED = 5
+STILL_ACTIVE = 259
+
+
+def _windows_pid_alive(pid: int, kernel32: Any = None) -> bool:
+    """Whether a Windows process is still running, without psutil."""
+    import ctypes
+
+    if kernel32 is None:
+        kernel32 = ctypes.windll.kernel32  # type: ignore[attr-defined]
+    handle = kernel32.OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, False, pid)
+    if not handle:
+        # Access denied means the process exists but belongs to someone else
+        return kernel32.GetLastError() == ERROR_ACCESS_DENIED
+    try:
+        exit_code = ctypes.c_ulong()
+        if not kernel32.GetExitCodeProcess(handle, ctypes.byref(exit_code)):
+            return True
+        return exit_code.value == STILL_ACTIVE
+    finally:
+        kernel32.CloseHandle(handle)
+
+
+def _pid_alive(pid: int) -> bool:
+    if pid <= 0:
+        return False
+    if HAS_PSUTIL:
+        return psutil.pid_exists(pid)
+    if os.name == "nt":
+        # os.kill(pid, 0) terminates the process on Windows
+        return _windows_pid_alive(pid)
+    try:
+        os.kill(pid, 0)
+    except ProcessLookupError:
+        return False
+    except PermissionError:
+        # Process exists but belongs to someone else
+        return True
+    except OSError:
+        return False
+    return True
+
+
+def _lock_fd(fd: int) -> None:
+    """Take an exclusive, non-blocking OS lock on fd. Raises OSError if someone else holds it."""
+    if os.name == "nt":
+        import msvcrt
+
+        os.lseek(fd, 0, os.SEEK_SET)
+        msvcrt.locking(fd, msvcrt.LK_NBLCK, 1)
+    else:
+        import fcntl
+
+        fcntl.flock(fd, fcntl.LOCK_EX | fcntl.LOCK_NB)
+
+
+def _unlock_fd(fd: int) -> None:
+    if os.name == "nt":
+        import msvcrt
+
+        os.lseek(fd, 0, os.SEEK_SET)
+        msvcrt.locking(fd, msvcrt.LK_UNLCK, 1)
+    else:
+        import fcntl
+
+        fcntl.flock(fd, fcntl.LOCK_UN)
+
+
+class InstanceLock:
+    """Per-user lock file that prevents two runner instances from running at once."""
+
+    def __init__(self, name: str = "daemon", instance_dir: Path | None = None) -> None:
+        self.path = (instance_dir or INSTANCE_DIR) / f"{name}-{current_os_user()}.lock"
+        self._fd: int | None = None
+
+    def acquire(self) -> None:
+        """Take the lock.
+
+        Raises:
+            AlreadyRunningError: If another process (or another lock in this one) holds it.
+        """
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        fd = os.open(self.path, os.O_CREAT | os.O_RDWR, 0o644)
+        try:
+            _lock_fd(fd)
+        except OSError:
+            os.close(fd)
+            owner_pid = self._read_owner_pid()
+            owner = f"pid {owner_pid}" if owner_pid is not None else "unknown pid"
+            raise AlreadyRunningError(f"Another runner instance is already running ({owner}, lock {self.path})")
+
+        os.ftruncate(fd, 0)
+        os.lseek(fd, 0, os.SEEK_SET)
+        os.write(fd, str(os.getpid()).encode("ascii"))
+        self._fd = fd
+
+    def release(self) -> None:
+        if self._fd is None:
+            return
+        # The file is kept: unlinking it would let a waiting process lock the old file while a new one is created
+        try:
+            os.ftruncate(self._fd, 0)
+            _unlock_fd(self._fd)
+        except OSError:
+            pass
+        finally:
+            os.close(self._fd)
+            self._fd = None
+
+    def _read_owner_pid(self) -> int | None:
+        try:
+            return int(self.path.read_text().strip())
+        except (OSError, ValueError):
+            return None
+
+    def __enter__(self) -> InstanceLock:
+        self.acquire()
+        return self
+
+    def __exit__(self, *exc_info) -> None:
+        self.release()
@@ -0,0 +1,110 @@
+"""
+Pause and resume a running job from the control channel.
+
+When the buyer needs the machine's bandwidth for a while, the desktop app
+pauses the job instead of cancelling it and losing the progress made so far.
+The commands share the pacing control channel on stdin:
+
+    {"type": "pause", "reason": "bandwidth needed"}
+    {"type": "resume"}
+
+A pause takes effect between SKUs: the in-flight SKU finishes, then the job
+idles with its browser open until it's resumed. Terminating the process still
+cancels a paused job, and a daemon shutdown ends the pause with the remaining
+SKUs held back. Time spent paused is reported as `paused_seconds` so the active
+elapsed time can exclude it.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import json
+import logging
+import threading
+import time
+
+from core.pacing import pacing_control
+
+logger = logging.getLogger(__name__)
+
+# How often a paused job re-checks for a shutdown (it wakes at once on resume)
+PAUSE_POLL_SECONDS = 1.0
+
+
+class JobPause:
+    """Thread-safe pause flag for the job running in this process."""
+
+    def __init__(self, poll_interval: float = PAUSE_POLL_SECONDS) -> None:
+        self._running = threading.Event()
+        self._running.set()
+        self._stop = threading.Event()
+        self._reason: str | None = None
+        self.poll_interval = poll_interval
+
+    @property
+    def paused(self) -> bool:
+        return not self._running.is_set()
+
+    @property
+    def stopping(self) -> bool:
+        return self._stop.is_set()
+
+    def request_stop(self) -> None:
+        """End any pause without resuming, e.g. on daemon shutdown. The job then stops at the paused SKU."""
+        self._stop.set()
+
+    def wait_for_stop(self, timeout: float) -> bool:
+        """Block up to timeout seconds for a stop request. Returns True if one was made."""
+        return self._stop.wait(timeout)
+
+    def pause(self, reason: str | None = None) -> None:
+        self._reason = reason
+        self._running.clear()
+        logger.info(f"[Runner] Pause requested ({reason or 'no reason given'}), pausing after the current SKU")
+
+    def resume(self) -> None:
+        if self.paused:
+            logger.info("[Runner] Resume requested")
+        self._running.set()
+
+    def handle_line(self, line: str) -> bool:
+        """Apply one control line. Returns True if it was a pause or resume command."""
+        try:
+            command = json.loads(line)
+        except json.JSONDecodeError:
+            return False
+        if not isinstance(command, dict):
+            return False
+        if command.get("type") == "pause":
+            self.pause(command.get("reason"))
+            return True
+        if command.get("type") == "resume":
+            self.resume()
+            return True
+        return False
+
+    async def wait_while_paused(self, position: str) -> float:
+        """
+        Idle until resumed if a pause is pending. Returns the seconds spent paused.
+
+        Waits in bounded slices so a stop request or task cancellation is noticed
+        within poll_interval. If it returns while still paused, a stop was requested.
+        """
+        if not self.paused:
+            return 0.0
+        logger.warning(f"[Runner] Job paused before {position} ({self._reason or 'no reason given'})")
+        started = time.monotonic()
+        while not self.stopping:
+            if await asyncio.to_thread(self._running.wait, self.poll_interval):
+                break
+        paused_for = time.monotonic() - started
+        if self.paused:
+            logger.warning(f"[Runner] Stop requested while paused at {position} after {paused_for:.0f}s")
+        else:
+            logger.info(f"[Runner] Job resumed at {position} after {paused_for:.0f}s paused")
+        return paused_for
+
+
+# Process-wide pause state; reads its commands from the pacing control listener
+job_pause = JobPause()
+pacing_control.add_handler(job_pause.handle_line)
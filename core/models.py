@@ -4,6 +4,8 @@ Unified Product Data Models
 This module defines Pydantic models for the entire product data pipeline:
 - ExcelInputProduct: Source of truth for SKU and Price (FROZEN)
 - RawScrapedProduct: Scraper output with auto-cleaning validators
+- ParsedPrice: Scraped price as integer cents plus currency code
+- ParsedAvailability: Scraped stock status as an enum plus quantity and restock date
 
 CRITICAL: SKU and Price from Excel are immutable throughout the pipeline.
 Scrapers and LLM consolidation only provide enrichment data (name, brand, etc.).
@@ -11,6 +13,12 @@ Scrapers and LLM consolidation only provide enrichment data (name, brand, etc.).
 
 from __future__ import annotations
 
+import re
+from collections.abc import Iterable
+from datetime import date
+from decimal import Decimal, InvalidOperation
+from enum import Enum
+from functools import lru_cache
 from typing import Any
 
 from pydantic import BaseModel, ConfigDict, Field, field_validator
@@ -53,6 +61,316 @@ class ExcelInputProduct(BaseModel):
         return False
 
 
+# =============================================================================
+# PRICE PARSING - Localized supplier price strings
+# =============================================================================
+
+
+class PriceKind(str, Enum):
+    """What a scraped price string actually told us."""
+
+    PRICED = "priced"
+    CALL_FOR_PRICE = "call_for_price"
+    MAP = "map"  # Below minimum advertised price; shown only in cart or after login
+
+
+class PriceParseError(ValueError):
+    """Raised when a price string cannot be interpreted. Keeps the original text."""
+
+    def __init__(self, raw: str, reason: str):
+        self.raw = raw
+        self.reason = reason
+        super().__init__(f"Unparseable price '{raw}': {reason}")
+
+
+class ParsedPrice(BaseModel):
+    """
+    Scraped price normalized to integer cents.
+
+    Attributes:
+        kind: Whether a number was shown or a sentinel like "Call for price"
+        amount_cents: Price in minor units (the lower bound for ranges)
+        max_cents: Upper bound for ranges like "$10.99 - $12.99", else None
+        currency: ISO 4217 code, defaulting to USD when no symbol/code is shown.
+            A code we don't know (e.g. "CHF 12.00") is kept as shown.
+        raw: The original scraped string
+    """
+
+    model_config = ConfigDict(frozen=True)
+
+    kind: PriceKind
+    amount_cents: int | None = None
+    max_cents: int | None = None
+    currency: str | None = None
+    raw: str
+
+
+CURRENCY_SYMBOLS = {
+    "C$": "CAD",
+    "CA$": "CAD",
+    "US$": "USD",
+    "$": "USD",
+    "€": "EUR",
+    "£": "GBP",
+}
+CURRENCY_CODES = {"USD", "EUR", "GBP", "CAD"}
+DEFAULT_CURRENCY = "USD"
+
+_CALL_FOR_PRICE_RE = re.compile(r"\bcall\b|contact us|request (a )?quote|price on request", re.IGNORECASE)
+_MAP_RE = re.compile(r"\bmap\b|(in|to) cart|log\s?in (to|for) (see )?pric", re.IGNORECASE)
+_CODE_RE = re.compile(r"\b([A-Z]{3})\b")
+# An uppercase three-letter code right next to the number, e.g. "CHF 12.00" or "12.00 CHF"
+_ADJACENT_CODE_RE = re.compile(r"\b([A-Z]{3})\s*(?=[\d.,])|(?<=\d)\s*([A-Z]{3})\b")
+# Digits with optional thousands/decimal separators, or a bare fraction like ".99";
+# spaces between digit groups are removed first
+_NUMBER_RE = re.compile(r"\d(?:[\d.,']*\d)?|(?<!\d)[.,]\d{1,2}(?!\d)")
+_RANGE_SEPARATOR_RE = re.compile(r"^\s*(?:-|–|—|to)\s*$", re.IGNORECASE)
+
+
+def _detect_currency(text: str) -> str | None:
+    for code in _CODE_RE.findall(text.upper()):
+        if code in CURRENCY_CODES:
+            return code
+    for symbol, code in CURRENCY_SYMBOLS.items():
+        if symbol in text:
+            return code
+    return None
+
+
+def _unknown_currency_code(text: str) -> str | None:
+    """A currency code shown next to the number that isn't in CURRENCY_CODES."""
+    match = _ADJACENT_CODE_RE.search(text)
+    return (match.group(1) or match.group(2)) if match else None
+
+
+def _number_to_cents(token: str, raw: str) -> int:
+    """Convert '1.234,56', '1,234.56', '1234', '12,5' etc. to cents.
+
+    The last '.' or ',' is the decimal separator when followed by one or two
+    digits; a separator followed by exactly three digits is a thousands separator.
+    """
+    token = token.replace("'", "")
+    integer_part, fraction = token, ""
+
+    last_sep = max(token.rfind("."), token.rfind(","))
+    if last_sep != -1 and len(token) - last_sep - 1 in (1, 2):
+        integer_part, fraction = token[:last_sep], token[last_sep + 1 :]
+
+    groups = re.split(r"[.,]", integer_part)
+    if len(groups) > 1:
+        if len({sep for sep in integer_part if sep in ".,"}) > 1:
+            raise PriceParseError(raw, "mixed thousands separators")
+        if not (1 <= len(groups[0]) <= 3) or any(len(g) != 3 for g in groups[1:]):
+            raise PriceParseError(raw, "malformed digit grouping")
+
+    try:
+        amount = Decimal("".join(groups) + "." + (fraction or "0"))
+    except InvalidOperation as e:
+        raise PriceParseError(raw, "not a number") from e
+    return int((amount * 100).to_integral_value())
+
+
+def parse_price(value: Any, default_currency: str = DEFAULT_CURRENCY) -> ParsedPrice | None:
+    """
+    Parse a scraped price into integer cents plus a currency code.
+
+    Handles thousands separators in either convention ("1,234.56" and
+    "1.234,56 €"), currency symbols and ISO codes, ranges ("$10.99 - $12.99"),
+    and "Call for price" / MAP sentinels, which map to a PriceKind rather than zero.
+
+    Returns:
+        ParsedPrice, or None for empty values
+
+    Raises:
+        PriceParseError: If the value cannot be interpreted
+    """
+    if value is None or isinstance(value, bool):
+        return None
+    if isinstance(value, ParsedPrice):
+        return value
+    if isinstance(value, int | float | Decimal):
+        try:
+            cents = int((Decimal(str(value)) * 100).to_integral_value())
+        except InvalidOperation as e:
+            raise PriceParseError(str(value), "not a number") from e
+        return ParsedPrice(kind=PriceKind.PRICED, amount_cents=cents, currency=default_currency, raw=str(value))
+
+    raw = str(value)
+    text = raw.strip()
+    if not text:
+        return None
+
+    # Sentinels win over any digits they contain, e.g. "Call 800-555-1234 for price"
+    if _MAP_RE.search(text):
+        return ParsedPrice(kind=PriceKind.MAP, raw=raw)
+    if _CALL_FOR_PRICE_RE.search(text):
+        return ParsedPrice(kind=PriceKind.CALL_FOR_PRICE, raw=raw)
+
+    # "1 234,56" / non-breaking space thousands groups
+    compact = re.sub(r"(?<=\d)[\s\u00a0\u202f](?=\d{3}\b)", "", text)
+    matches = list(_NUMBER_RE.finditer(compact))
+
+    if not matches:
+        raise PriceParseError(raw, "no number found")
+
+    if len(matches) > 2:
+        raise PriceParseError(raw, "too many numbers")
+    if len(matches) == 2:
+        between = compact[matches[0].end() : matches[1].start()]
+        # Strip a repeated currency marker from the upper bound, e.g. "$10.99 - $12.99"
+        between = re.sub(r"[^\w\s\-–—]|\b[A-Z]{3}\b", "", between)
+        if not _RANGE_SEPARATOR_RE.match(between):
+            raise PriceParseError(raw, "multiple numbers without a range separator")
+
+    # An unknown code is reported as shown rather than assumed to be the default currency
+    currency = _detect_currency(compact) or _unknown_currency_code(compact) or default_currency
+    amounts = [_number_to_cents(m.group(0), raw) for m in matches]
+
+    if len(amounts) == 2:
+        low, high = sorted(amounts)
+        return ParsedPrice(kind=PriceKind.PRICED, amount_cents=low, max_cents=high, currency=currency, raw=raw)
+    return ParsedPrice(kind=PriceKind.PRICED, amount_cents=amounts[0], currency=currency, raw=raw)
+
+
+# =============================================================================
+# AVAILABILITY NORMALIZATION - Supplier stock status strings
+# =============================================================================
+
+
+class AvailabilityStatus(str, Enum):
+    """Stock status downstream systems understand."""
+
+    IN_STOCK = "in_stock"
+    OUT_OF_STOCK = "out_of_stock"
+    BACKORDERED = "backordered"
+    DISCONTINUED = "discontinued"
+    CALL_FOR_AVAILABILITY = "call_for_availability"
+
+
+class ParsedAvailability(BaseModel):
+    """
+    Scraped availability normalized to a status.
+
+    Attributes:
+        status: Normalized stock status
+        quantity: Units available when the supplier shows a count ("QTY 12+" is 12)
+        restock_date: Expected restock date for backorders, when shown
+        raw: The original scraped string
+        matched_rule: Index of the scraper mapping rule that matched, None for the built-in heuristics
+    """
+
+    model_config = ConfigDict(frozen=True)
+
+    status: AvailabilityStatus
+    quantity: int | None = None
+    restock_date: date | None = None
+    raw: str
+    matched_rule: int | None = None
+
+
+_MONTH_NAMES = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"]
+
+_NUMERIC_DATE_RE = re.compile(r"\b(\d{1,2})/(\d{1,2})(?:/(\d{2}|\d{4}))?\b")
+_ISO_DATE_RE = re.compile(r"\b(\d{4})-(\d{2})-(\d{2})\b")
+_MONTH_DATE_RE = re.compile(r"\b([a-z]{3,9})\.?\s+(\d{1,2})(?:st|nd|rd|th)?(?:,?\s+(\d{4}))?\b", re.IGNORECASE)
+_QUANTITY_RE = re.compile(r"\b(?:qty|quantity)\s*:?\s*(\d+)\+?|\b(\d+)\+?\s*(?:in stock|available|left|on hand)\b", re.IGNORECASE)
+
+# Built-in fallbacks, tried in order once no scraper rule matched. Negative phrases come first.
+_AVAILABILITY_HEURISTICS = [
+    (re.compile(r"discontinued|no longer (available|carried|offered)", re.IGNORECASE), AvailabilityStatus.DISCONTINUED),
+    (re.compile(r"back\s?-?order|pre-?order|expected|coming soon", re.IGNORECASE), AvailabilityStatus.BACKORDERED),
+    (re.compile(r"out of stock|sold out|not (currently )?(in stock|available)|unavailable", re.IGNORECASE), AvailabilityStatus.OUT_OF_STOCK),
+    (re.compile(r"\bcall\b|contact us", re.IGNORECASE), AvailabilityStatus.CALL_FOR_AVAILABILITY),
+    (re.compile(r"in stock|available|ships|ready|\bqty\b|\bleft\b|on hand", re.IGNORECASE), AvailabilityStatus.IN_STOCK),
+]
+
+
+def _resolve_date(year: int | None, month: int, day: int, today: date) -> date | None:
+    """A date with the year inferred as the next occurrence when the supplier leaves it out."""
+    try:
+        if year is not None:
+            return date(year + 2000 if year < 100 else year, month, day)
+        candidate = date(today.year, month, day)
+        return candidate if candidate >= today else date(today.year + 1, month, day)
+    except ValueError:
+        return None
+
+
+def parse_restock_date(text: str, today: date | None = None) -> date | None:
+    """Find a restock date like "3/15", "03/15/2026", "2026-03-15" or "Mar 15" in text."""
+    today = today or date.today()
+    if match := _ISO_DATE_RE.search(text):
+        return _resolve_date(int(match.group(1)), int(match.group(2)), int(match.group(3)), today)
+    if match := _NUMERIC_DATE_RE.search(text):
+        return _resolve_date(int(match.group(3)) if match.group(3) else None, int(match.group(1)), int(match.group(2)), today)
+    for match in _MONTH_DATE_RE.finditer(text):
+        prefix = match.group(1).lower()[:3]
+        if prefix in _MONTH_NAMES:
+            month = _MONTH_NAMES.index(prefix) + 1
+            return _resolve_date(int(match.group(3)) if match.group(3) else None, month, int(match.group(2)), today)
+    return None
+
+
+def _parse_quantity(text: str) -> int | None:
+    digits = re.sub(r"\D", "", text)
+    return int(digits) if digits else None
+
+
+@lru_cache(maxsize=256)
+def _compile_rule(pattern: str) -> re.Pattern[str]:
+    return re.compile(pattern, re.IGNORECASE)
+
+
+def normalize_availability(
+    value: Any,
+    rules: Iterable[tuple[str, str]] = (),
+    today: date | None = None,
+) -> ParsedAvailability | None:
+    """
+    Normalize a scraped availability string.
+
+    Scraper rules are (regex, status) pairs tried in order against the raw
+    string. A rule may capture `quantity` and `restock_date` named groups. When
+    no rule matches, built-in heuristics cover common phrasings, picking up a
+    quantity ("QTY 12+", "3 left") and restock date ("Backordered til 3/15").
+
+    Returns:
+        ParsedAvailability, or None for empty values and strings nothing recognises
+    """
+    if value is None or isinstance(value, bool):
+        return None
+    if isinstance(value, ParsedAvailability):
+        return value
+    raw = str(value)
+    text = " ".join(raw.split())
+    if not text:
+        return None
+
+    for index, (pattern, status) in enumerate(rules):
+        match = _compile_rule(pattern).search(text)
+        if match is None:
+            continue
+        groups = match.groupdict()
+        return ParsedAvailability(
+            status=AvailabilityStatus(status),
+            quantity=_parse_quantity(groups["quantity"]) if groups.get("quantity") else None,
+            restock_date=parse_restock_date(groups["restock_date"], today) if groups.get("restock_date") else None,
+            raw=raw,
+            matched_rule=index,
+        )
+
+    for pattern, status in _AVAILABILITY_HEURISTICS:
+        if not pattern.search(text):
+            continue
+        quantity_match = _QUANTITY_RE.search(text)
+        quantity = int(quantity_match.group(1) or quantity_match.group(2)) if quantity_match else None
+        if status is AvailabilityStatus.IN_STOCK and quantity == 0:
+            status = AvailabilityStatus.OUT_OF_STOCK
+        restock_date = parse_restock_date(text, today) if status is AvailabilityStatus.BACKORDERED else None
+        return ParsedAvailability(status=status, quantity=quantity, restock_date=restock_date, raw=raw)
+    return None
+
+
 # =============================================================================
 # SCRAPER OUTPUT MODEL - Enrichment Data Only
 # =============================================================================
@@ -73,7 +391,7 @@ class RawScrapedProduct(BaseModel):
         weight: Product weight (auto-cleaned from strings like "5 lbs")
         description: Product description
         images: List of image URLs
-        scraped_price: Reference only - NOT used in final product
+        scraped_price: Reference only - NOT used in final product (see ParsedPrice)
         image_quality: Quality score for images (0-100)
     """
 
@@ -92,7 +410,7 @@ class RawScrapedProduct(BaseModel):
     product_type: str | None = None
 
     # Scraped price is stored but IGNORED in final output
-    scraped_price: float | None = Field(
+    scraped_price: ParsedPrice | None = Field(
         default=None, description="Reference only - NOT used in final product"
     )
 
@@ -101,14 +419,9 @@ class RawScrapedProduct(BaseModel):
 
     @field_validator("scraped_price", mode="before")
     @classmethod
-    def clean_price(cls, v: Any) -> float | None:
-        """Clean price string to float. For reference only."""
-        if v is None:
-            return None
-        if isinstance(v, str):
-            clean = "".join(c for c in v if c.isdigit() or c == ".")
-            return float(clean) if clean else None
-        return float(v) if v else None
+    def clean_price(cls, v: Any) -> ParsedPrice | None:
+        """Parse price string to cents + currency. For reference only."""
+        return parse_price(v)
 
     @field_validator("weight", mode="before")
     @classmethod
@@ -145,5 +458,5 @@ class RawScrapedProduct(BaseModel):
             "Category": self.category,
             "ProductType": self.product_type,
             # Scraped price stored for reference but marked clearly
-            "ScrapedPrice": self.scraped_price,
+            "ScrapedPrice": self.scraped_price.model_dump(mode="json") if self.scraped_price else None,
         }
@@ -0,0 +1,115 @@
+"""
+Adaptive request pacing control channel.
+
+The desktop app runs the pacing controller: it watches the request.completed
+events (status codes and latency) and decides when to slow down or speed back
+up. Its decisions arrive here as JSON lines on stdin:
+
+    {"type": "pacing", "scraper": "phillips", "delay_ms": 2500, "reason": "429 from supplier"}
+
+Only scrapers with `adaptive_pacing: true` read the delay; everything else
+keeps its static waits. Other commands on the same channel (pause and resume,
+see core.job_pause; login codes, see core.auth_challenge) register their own
+handlers; lines nobody recognises are ignored.
+"""
+
+from __future__ import annotations
+
+import json
+import logging
+import sys
+import threading
+from collections.abc import Callable
+from typing import IO
+
+logger = logging.getLogger(__name__)
+
+# Upper bound on a single requested delay, so a bad command can't stall a run
+MAX_DELAY_MS = 120_000
+
+
+class PacingControl:
+    """Thread-safe per-scraper request delay, updated from the control channel."""
+
+    def __init__(self) -> None:
+        self._delays: dict[str, int] = {}
+        self._reasons: dict[str, str | None] = {}
+        self._applied: dict[str, int] = {}
+        self._lock = threading.Lock()
+        self._listener: threading.Thread | None = None
+        self._handlers: list[Callable[[str], bool]] = [self.handle_line]
+
+    def delay_ms(self, scraper: str) -> int:
+        with self._lock:
+            return self._delays.get(scraper, 0)
+
+    def reason(self, scraper: str) -> str | None:
+        with self._lock:
+            return self._reasons.get(scraper)
+
+    def apply(self, scraper: str) -> tuple[int, int]:
+        """Current delay for the next request, and the delay the previous request used."""
+        with self._lock:
+            delay_ms = self._delays.get(scraper, 0)
+            previous_delay_ms = self._applied.get(scraper, 0)
+            self._applied[scraper] = delay_ms
+            return delay_ms, previous_delay_ms
+
+    def set_delay(self, scraper: str, delay_ms: int, reason: str | None = None) -> None:
+        delay_ms = max(0, min(int(delay_ms), MAX_DELAY_MS))
+        with self._lock:
+            self._delays[scraper] = delay_ms
+            self._reasons[scraper] = reason
+
+    def handle_line(self, line: str) -> bool:
+        """Apply one control line. Returns True if it was a valid pacing command."""
+        try:
+            command = json.loads(line)
+        except json.JSONDecodeError:
+            return False
+        if not isinstance(command, dict) or command.get("type") != "pacing":
+            return False
+
+        scraper = command.get("scraper")
+        delay_ms = command.get("delay_ms")
+        if not isinstance(scraper, str) or not isinstance(delay_ms, int | float) or isinstance(delay_ms, bool):
+            logger.warning(f"[Pacing] Ignoring malformed pacing command: {line.strip()[:200]}")
+            return False
+
+        self.set_delay(scraper, int(delay_ms), command.get("reason"))
+        logger.info(f"[Pacing] {scraper}: delay set to {self.delay_ms(scraper)}ms ({command.get('reason') or 'no reason given'})")
+        return True
+
+    @property
+    def listening(self) -> bool:
+        """Whether control lines are being read, i.e. someone can answer a command."""
+        return self._listener is not None and self._listener.is_alive()
+
+    def add_handler(self, handler: Callable[[str], bool]) -> None:
+        """Also offer control lines to handler, which returns True for lines it applied."""
+        self._handlers.append(handler)
+
+    def start_listener(self, stream: IO[str] | None = None) -> None:
+        """Read control lines from stdin in a background thread (once per process)."""
+        if self._listener is not None and self._listener.is_alive():
+            return
+        stream = stream or sys.stdin
+
+        def _listen() -> None:
+            try:
+                for line in stream:
+                    if not line.strip():
+                        continue
+                    for handler in self._handlers:
+                        if handler(line):
+                            break
+            except (OSError, ValueError):
+                # stdin unavailable (detached process) - no control channel
+                pass
+
+        self._listener = threading.Thread(target=_listen, name="pacing-control", daemon=True)
+        self._listener.start()
+
+
+# Process-wide pacing state shared by the runner and navigate actions
+pacing_control = PacingControl()
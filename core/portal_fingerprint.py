@@ -0,0 +1,190 @@
+"""
+Login page fingerprints to spot supplier portal redesigns.
+
+Half our breakages start with a supplier quietly reworking their login page.
+Whenever a scraper actually fills in its login form, the page's structure is
+fingerprinted: the title, the names/ids/types of form fields and buttons, and
+a few meta tags. Field values never take part, and hidden fields that look like
+CSRF tokens or nonces are skipped entirely, so per-request tokens don't trip it.
+
+The first fingerprint becomes the baseline. A different one flags the scraper
+as changed, with the fields added and removed, until someone acknowledges it,
+which makes the new fingerprint the baseline. State is kept next to the
+instance id.
+
+Usage:
+    python -m core.portal_fingerprint status
+    python -m core.portal_fingerprint acknowledge phillips
+"""
+
+from __future__ import annotations
+
+import argparse
+import hashlib
+import json
+import logging
+import re
+import sys
+import threading
+from datetime import datetime, timezone
+from html.parser import HTMLParser
+from pathlib import Path
+from typing import Any
+
+from core.instance import INSTANCE_DIR
+
+logger = logging.getLogger(__name__)
+
+FIELD_TAGS = {"form", "input", "select", "textarea", "button"}
+META_NAMES = {"generator", "application-name", "viewport"}
+# Hidden fields carrying per-request values, sometimes under per-request names
+_TOKEN_NAME_RE = re.compile(r"csrf|xsrf|token|nonce|authenticity|verification|^[0-9a-f]{16,}$|^__", re.IGNORECASE)
+
+
+class _StructureParser(HTMLParser):
+    def __init__(self) -> None:
+        super().__init__(convert_charrefs=True)
+        self.fields: list[str] = []
+        self.meta: dict[str, str] = {}
+        self.title = ""
+        self._in_title = False
+
+    def handle_starttag(self, tag: str, attrs: list[tuple[str, str | None]]) -> None:
+        attributes = {name: value or "" for name, value in attrs}
+        if tag == "title":
+            self._in_title = True
+        elif tag == "meta" and attributes.get("name", "").lower() in META_NAMES:
+            self.meta[attributes["name"].lower()] = attributes.get("content", "")
+        elif tag in FIELD_TAGS:
+            kind = attributes.get("type", "").lower()
+            name = attributes.get("name", "")
+            if kind == "hidden" and (not name or _TOKEN_NAME_RE.search(name)):
+                return
+            self.fields.append(f"{tag}[type={kind}][name={name}][id={attributes.get('id', '')}]")
+
+    def handle_endtag(self, tag: str) -> None:
+        if tag == "title":
+            self._in_title = False
+
+    def handle_data(self, data: str) -> None:
+        if self._in_title:
+            self.title += data
+
+
+def fingerprint_page(html: str) -> dict[str, Any]:
+    """Structural fingerprint of a login page, with a hash over all of it."""
+    parser = _StructureParser()
+    parser.feed(html)
+    structure = {"title": " ".join(parser.title.split()), "fields": sorted(set(parser.fields)), "meta": parser.meta}
+    digest = hashlib.sha256(json.dumps(structure, sort_keys=True).encode()).hexdigest()
+    return {"hash": digest, **structure}
+
+
+class PortalFingerprints:
+    """Baseline and pending-change fingerprints per scraper."""
+
+    def __init__(self, path: Path | None = None) -> None:
+        self.path = path or INSTANCE_DIR / "portal_fingerprints.json"
+        self._lock = threading.Lock()
+
+    def _load(self) -> dict[str, dict[str, Any]]:
+        try:
+            data = json.loads(self.path.read_text())
+        except FileNotFoundError:
+            return {}
+        except (OSError, json.JSONDecodeError) as e:
+            logger.warning(f"Ignoring unreadable portal fingerprint file {self.path}: {e}")
+            return {}
+        return data if isinstance(data, dict) else {}
+
+    def _save(self, data: dict[str, dict[str, Any]]) -> None:
+        try:
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+            self.path.write_text(json.dumps(data, indent=2, sort_keys=True))
+        except OSError as e:
+            logger.warning(f"Could not persist portal fingerprints to {self.path}: {e}")
+
+    def record(self, scraper: str, fingerprint: dict[str, Any], now: datetime | None = None) -> dict[str, Any] | None:
+        """Compare a fresh fingerprint with the baseline. Returns the change when it differs."""
+        now = now or datetime.now(timezone.utc)
+        with self._lock:
+            data = self._load()
+            entry = data.get(scraper)
+            if entry is None:
+                data[scraper] = {"baseline": fingerprint, "recorded_at": now.isoformat()}
+                self._save(data)
+                return None
+            baseline = entry["baseline"]
+            if fingerprint["hash"] == baseline.get("hash"):
+                return None
+            pending = entry.get("change")
+            if pending and pending["fingerprint"]["hash"] == fingerprint["hash"]:
+                return pending
+            change = {
+                "detected_at": now.isoformat(),
+                "fingerprint": fingerprint,
+                "added_fields": sorted(set(fingerprint["fields"]) - set(baseline.get("fields", []))),
+                "removed_fields": sorted(set(baseline.get("fields", [])) - set(fingerprint["fields"])),
+                "title": {"before": baseline.get("title"), "after": fingerprint["title"]},
+            }
+            entry["change"] = change
+            self._save(data)
+        logger.warning(
+            f"[Runner] {scraper}: login page changed (added {change['added_fields'] or 'none'}, removed {change['removed_fields'] or 'none'})"
+        )
+        return change
+
+    def pending_change(self, scraper: str) -> dict[str, Any] | None:
+        """The unacknowledged login page change for a scraper, if any."""
+        with self._lock:
+            return (self._load().get(scraper) or {}).get("change")
+
+    def acknowledge(self, scraper: str) -> bool:
+        """Adopt the changed fingerprint as the new baseline. False if nothing was pending."""
+        with self._lock:
+            data = self._load()
+            entry = data.get(scraper)
+            if not entry or not entry.get("change"):
+                return False
+            change = entry.pop("change")
+            entry["baseline"] = change["fingerprint"]
+            entry["recorded_at"] = change["detected_at"]
+            self._save(data)
+        logger.info(f"[Runner] {scraper}: login page change acknowledged")
+        return True
+
+    def status(self) -> dict[str, dict[str, Any]]:
+        """Every fingerprinted scraper with when its baseline was taken and any pending change."""
+        with self._lock:
+            data = self._load()
+        return {
+            scraper: {
+                "recorded_at": entry.get("recorded_at"),
+                "changed": bool(entry.get("change")),
+                **({"change": {k: v for k, v in entry["change"].items() if k != "fingerprint"}} if entry.get("change") else {}),
+            }
+            for scraper, entry in data.items()
+        }
+
+
+portal_fingerprints = PortalFingerprints()
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Show or acknowledge supplier login page changes")
+    sub = parser.add_subparsers(dest="command", required=True)
+    sub.add_parser("status", help="Print login page fingerprints as JSON")
+    acknowledge = sub.add_parser("acknowledge", help="Accept a scraper's changed login page as the new baseline")
+    acknowledge.add_argument("scraper")
+    args = parser.parse_args()
+
+    if args.command == "status":
+        print(json.dumps(portal_fingerprints.status(), indent=2))
+        return
+    if not portal_fingerprints.acknowledge(args.scraper):
+        print(f"{args.scraper} has no pending login page change", file=sys.stderr)
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
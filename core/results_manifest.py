@@ -0,0 +1,220 @@
+"""
+Signed manifests for uploaded results.
+
+Compliance needs to show that pricing data wasn't altered between the runner
+and HQ. Before a chunked upload is committed, a manifest of the results is
+built and the commit carries it:
+
+    {"version": 1, "job_id": "...", "created_at": "...", "chunk_rows": 500,
+     "total_rows": 1200, "provenance": {...},
+     "chunks": [{"index": 0, "rows": 500, "first_sku": "SKU1", "sha256": "..."}, ...],
+     "set_aside": ["SKU7"]}
+
+Each chunk hash is the SHA-256 of the chunk's rows as canonical JSON (sorted
+keys, no whitespace, UTF-8), in upload order, so anyone holding the data can
+recompute it. The manifest is signed with the runner's Ed25519 key over its own
+canonical JSON. The key is read from RESULTS_SIGNING_KEY (base64 of the 32-byte
+private key); the desktop app keeps it in the keychain and passes it in. It is
+read once, when this module is imported, and the variable is then removed from
+the environment so browsers and other child processes never see it. Without a
+key the manifest is still sent, unsigned.
+
+Uploads sent in one request (submit_results, submit_chunk_results) carry a
+manifest too, with all their rows as a single chunk.
+
+The manifest covers exactly the rows the coordinator accepted, on both paths.
+Rows it set aside as invalid (see core.server_rejections) are left out of the
+chunk hashes and listed by SKU under "set_aside" (omitted when there are none),
+and they are dropped from the results' "data" too, so a saved results file
+still verifies. The rows themselves are kept in the rejections file.
+
+    python -m core.results_manifest public-key
+    python -m core.results_manifest verify results.json [--public-key <base64>]
+
+After the commit the signed manifest is also kept in the results as
+"results_manifest", so a saved results file can be checked later. `verify`
+takes such a file (or one with "data", "manifest" and "signature" at the top
+level) and names every chunk whose hash doesn't match. Pass --public-key to
+check against the key registered for the runner rather than the one in the file.
+"""
+
+from __future__ import annotations
+
+import argparse
+import base64
+import binascii
+import hashlib
+import json
+import logging
+import os
+import sys
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any
+
+try:
+    from cryptography.exceptions import InvalidSignature
+    from cryptography.hazmat.primitives import serialization
+    from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PrivateKey, Ed25519PublicKey
+
+    HAS_CRYPTOGRAPHY = True
+except ImportError:
+    HAS_CRYPTOGRAPHY = False
+
+logger = logging.getLogger(__name__)
+
+MANIFEST_VERSION = 1
+SIGNING_KEY_ENV = "RESULTS_SIGNING_KEY"
+
+
+def canonical_json(value: Any) -> bytes:
+    return json.dumps(value, sort_keys=True, separators=(",", ":"), ensure_ascii=False).encode("utf-8")
+
+
+def chunk_digest(rows: dict[str, Any]) -> str:
+    return hashlib.sha256(canonical_json(rows)).hexdigest()
+
+
+def build_manifest(
+    job_id: str | None,
+    chunks: list[dict[str, Any]],
+    chunk_rows: int,
+    provenance: dict[str, Any] | None = None,
+    chunk_id: str | None = None,
+    set_aside: list[str] | None = None,
+) -> dict[str, Any]:
+    """Manifest of a job's accepted result chunks (SKU -> row each), in upload order.
+
+    chunk_id identifies a claimed work unit's results; set_aside names the rows the coordinator refused.
+    """
+    manifest: dict[str, Any] = {
+        "version": MANIFEST_VERSION,
+        "job_id": job_id,
+        "created_at": datetime.now(timezone.utc).isoformat(),
+        "chunk_rows": chunk_rows,
+        "total_rows": sum(len(chunk) for chunk in chunks),
+        "provenance": provenance or {},
+        "chunks": [
+            {"index": index, "rows": len(chunk), "first_sku": next(iter(chunk), None), "sha256": chunk_digest(chunk)}
+            for index, chunk in enumerate(chunks)
+        ],
+    }
+    if chunk_id is not None:
+        manifest["chunk_id"] = chunk_id
+    if set_aside:
+        manifest["set_aside"] = sorted(set_aside)
+    return manifest
+
+
+def _parse_signing_key(encoded: str | None) -> Any | None:
+    if not encoded:
+        return None
+    if not HAS_CRYPTOGRAPHY:
+        logger.warning(f"{SIGNING_KEY_ENV} is set but the cryptography package is not installed; results manifests are unsigned")
+        return None
+    try:
+        return Ed25519PrivateKey.from_private_bytes(base64.b64decode(encoded, validate=True))
+    except (binascii.Error, ValueError) as e:
+        logger.warning(f"Ignoring invalid {SIGNING_KEY_ENV}: {e}")
+        return None
+
+
+_signing_key = _parse_signing_key(os.environ.pop(SIGNING_KEY_ENV, None))
+
+
+def _load_signing_key() -> Any | None:
+    """The key read at import, replaced if RESULTS_SIGNING_KEY was set again since."""
+    global _signing_key
+    encoded = os.environ.pop(SIGNING_KEY_ENV, None)
+    if encoded is not None:
+        _signing_key = _parse_signing_key(encoded)
+    return _signing_key
+
+
+def public_key() -> str | None:
+    """This runner's public key (base64 of the raw 32 bytes), or None without a signing key."""
+    key = _load_signing_key()
+    if key is None:
+        return None
+    raw = key.public_key().public_bytes(encoding=serialization.Encoding.Raw, format=serialization.PublicFormat.Raw)
+    return base64.b64encode(raw).decode("ascii")
+
+
+def sign_manifest(manifest: dict[str, Any]) -> dict[str, Any]:
+    """The manifest with its signature and public key. Both are None when there is no signing key."""
+    key = _load_signing_key()
+    signature = base64.b64encode(key.sign(canonical_json(manifest))).decode("ascii") if key is not None else None
+    return {"manifest": manifest, "signature": signature, "public_key": public_key() if key is not None else None}
+
+
+def verify_signature(manifest: dict[str, Any], signature: str, public_key_b64: str) -> bool:
+    if not HAS_CRYPTOGRAPHY:
+        raise RuntimeError("Verifying signatures needs the cryptography package")
+    try:
+        key = Ed25519PublicKey.from_public_bytes(base64.b64decode(public_key_b64, validate=True))
+        key.verify(base64.b64decode(signature, validate=True), canonical_json(manifest))
+        return True
+    except (InvalidSignature, binascii.Error, ValueError):
+        return False
+
+
+def verify_data(manifest: dict[str, Any], data: dict[str, Any]) -> list[str]:
+    """Problems found re-hashing data (SKU -> row, upload order) against the manifest. Empty when it matches."""
+    problems: list[str] = []
+    rows = list(data.items())
+    if len(rows) != manifest.get("total_rows"):
+        problems.append(f"row count is {len(rows)}, manifest says {manifest.get('total_rows')}")
+    offset = 0
+    for chunk in manifest.get("chunks") or []:
+        chunk_data = dict(rows[offset : offset + chunk["rows"]])
+        offset += chunk["rows"]
+        if chunk_digest(chunk_data) != chunk["sha256"]:
+            problems.append(f"chunk {chunk['index']} (from SKU {chunk.get('first_sku')}) hash mismatch")
+    return problems
+
+
+def verify_results_file(path: Path, public_key_b64: str | None = None) -> list[str]:
+    """Problems with a results file's signature and chunk hashes. Empty when it verifies."""
+    content = json.loads(path.read_text())
+    signed = content.get("results_manifest") or content
+    manifest = signed.get("manifest")
+    if not isinstance(manifest, dict):
+        return ["no manifest in file"]
+    problems: list[str] = []
+    signature = signed.get("signature")
+    key = public_key_b64 or signed.get("public_key")
+    if not signature:
+        problems.append("manifest is not signed")
+    elif not key:
+        problems.append("no public key to check the signature with")
+    elif not verify_signature(manifest, signature, key):
+        problems.append("signature does not match the manifest")
+    return problems + verify_data(manifest, content.get("data") or {})
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Show this runner's results signing key or verify a results file")
+    sub = parser.add_subparsers(dest="command", required=True)
+    sub.add_parser("public-key", help="Print the public key HQ verifies this runner's manifests with")
+    verify = sub.add_parser("verify", help="Check a results file's signature and chunk hashes")
+    verify.add_argument("path", type=Path)
+    verify.add_argument("--public-key", help="Expected runner public key (defaults to the one in the file)")
+    args = parser.parse_args()
+
+    if args.command == "public-key":
+        key = public_key()
+        if key is None:
+            print(f"No signing key; set {SIGNING_KEY_ENV}", file=sys.stderr)
+            sys.exit(1)
+        print(key)
+        return
+    problems = verify_results_file(args.path, args.public_key)
+    for problem in problems:
+        print(problem, file=sys.stderr)
+    if problems:
+        sys.exit(1)
+    print(f"{args.path}: signature and chunk hashes verified")
+
+
+if __name__ == "__main__":
+    main()
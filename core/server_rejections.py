@@ -0,0 +1,152 @@
+"""
+Rows the coordinator refused as invalid.
+
+A 400 or 422 on an upload means the rows themselves are wrong. Retrying them
+only blocks the rest of the upload. The upload client isolates them instead.
+When the error body names the rejected SKUs, only those rows are pulled out
+and the remainder is uploaded. A rejection that names no rows can't be pinned
+on any of them, so the chunk fails and the upload stops there to be resumed.
+Set-aside rows and the server's reasons go to a JSON file per job under
+data/server_rejections/, so someone can fix the source data and export them:
+
+    python -m core.server_rejections show <job_id>
+    python -m core.server_rejections export <job_id> rejections.json
+
+Error bodies are expected to list rows as {"sku": ..., "reason": ...} under
+"rejected_rows", "rows" or "errors"; "message" and "error" are accepted in
+place of "reason".
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import logging
+import sys
+import threading
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any
+
+import httpx
+
+from core.settings_manager import PROJECT_ROOT
+
+logger = logging.getLogger(__name__)
+
+SERVER_REJECTIONS_DIR = PROJECT_ROOT / "data" / "server_rejections"
+
+# Status codes that mean "these rows are invalid", as opposed to a lost lease or missing session
+ROW_VALIDATION_STATUSES = {400, 422}
+
+
+def parse_row_rejections(response: httpx.Response) -> dict[str, str]:
+    """Per-SKU reasons from a validation error body. Empty when the body doesn't name rows."""
+    try:
+        body = response.json()
+    except (ValueError, TypeError):
+        return {}
+    if not isinstance(body, dict):
+        return {}
+    entries = next((body[key] for key in ("rejected_rows", "rows", "errors") if isinstance(body.get(key), list)), [])
+    reasons: dict[str, str] = {}
+    for entry in entries:
+        if not isinstance(entry, dict) or not entry.get("sku"):
+            continue
+        reason = entry.get("reason") or entry.get("message") or entry.get("error") or "rejected by server"
+        reasons[str(entry["sku"])] = str(reason)
+    return reasons
+
+
+def error_message(response: httpx.Response) -> str:
+    """The server's overall error message, for rows rejected without a reason of their own."""
+    try:
+        body = response.json()
+    except (ValueError, TypeError):
+        body = None
+    if isinstance(body, dict) and isinstance(body.get("error") or body.get("message"), str):
+        return body.get("error") or body["message"]
+    return f"HTTP {response.status_code}: {response.text[:200]}"
+
+
+class ServerRejections:
+    """Rejected rows per job, one JSON file each."""
+
+    def __init__(self, directory: Path | None = None) -> None:
+        self.directory = directory or SERVER_REJECTIONS_DIR
+        self._lock = threading.Lock()
+
+    def _path(self, job_id: str) -> Path:
+        return self.directory / f"{job_id}.json"
+
+    def get(self, job_id: str) -> list[dict[str, Any]]:
+        """Every row rejected for the job so far, with the server's reason."""
+        try:
+            data = json.loads(self._path(job_id).read_text())
+        except FileNotFoundError:
+            return []
+        except (OSError, json.JSONDecodeError) as e:
+            logger.warning(f"Ignoring unreadable server rejections file for job {job_id}: {e}")
+            return []
+        return data if isinstance(data, list) else []
+
+    def record(self, job_id: str, rows: dict[str, Any], reasons: dict[str, str], status_code: int) -> None:
+        """Keep rejected rows (SKU -> row) with their reasons."""
+        rejected_at = datetime.now(timezone.utc).isoformat()
+        entries = [
+            {"sku": sku, "reason": reasons.get(sku, "rejected by server"), "status_code": status_code, "rejected_at": rejected_at, "row": row}
+            for sku, row in rows.items()
+        ]
+        with self._lock:
+            data = self.get(job_id) + entries
+            try:
+                self.directory.mkdir(parents=True, exist_ok=True)
+                self._path(job_id).write_text(json.dumps(data, indent=2))
+            except OSError as e:
+                logger.warning(f"Could not persist server rejections for job {job_id}: {e}")
+        logger.warning(f"Server rejected {len(entries)} row(s) for job {job_id}; kept in {self._path(job_id)}")
+
+    def summary(self, job_id: str) -> dict[str, Any] | None:
+        """Rejected row count, reasons and file for a job, or None if nothing was rejected."""
+        entries = self.get(job_id)
+        if not entries:
+            return None
+        return {
+            "rows": len(entries),
+            "skus": [entry["sku"] for entry in entries],
+            "reasons": sorted({entry["reason"] for entry in entries}),
+            "path": str(self._path(job_id)),
+        }
+
+    def export(self, job_id: str, destination: Path) -> int:
+        """Write the job's rejected rows to destination. Returns the number of rows."""
+        entries = self.get(job_id)
+        destination.write_text(json.dumps(entries, indent=2))
+        return len(entries)
+
+
+server_rejections = ServerRejections()
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Show or export rows the coordinator rejected")
+    sub = parser.add_subparsers(dest="command", required=True)
+    show = sub.add_parser("show", help="Print a job's rejected rows as JSON")
+    show.add_argument("job_id")
+    export = sub.add_parser("export", help="Write a job's rejected rows to a file")
+    export.add_argument("job_id")
+    export.add_argument("destination", type=Path)
+    args = parser.parse_args()
+
+    if args.command == "show":
+        print(json.dumps(server_rejections.get(args.job_id), indent=2))
+        return
+    count = server_rejections.export(args.job_id, args.destination)
+    if not count:
+        print(f"No rejected rows for job {args.job_id}", file=sys.stderr)
+        sys.exit(1)
+    print(f"Exported {count} rejected row(s) to {args.destination}")
+
+
+if __name__ == "__main__":
+    main()
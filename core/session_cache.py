@@ -0,0 +1,69 @@
+"""
+In-memory cache of logged-in browser sessions.
+
+Every chunk starts a fresh browser, so without this each chunk logs into the
+supplier portal again - slow, and it looks suspicious on their side. After a
+successful login the browser's storage state (cookies and local storage) is
+kept here, keyed by scraper and a hash of the username it logged in as, and the
+next browser for that scraper and account starts from it. The login step then
+finds the success indicator and skips the form; if the session has expired it
+logs in once more and replaces the cached state.
+
+Sessions are never written to disk. Logins and sessions the login step found
+still logged in are counted per scraper so a job can report how much logging in
+it actually did.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import logging
+import threading
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+
+def account_key(username: str | None) -> str:
+    """Short hash identifying the account a session belongs to, so usernames aren't kept as keys."""
+    return hashlib.sha256((username or "").encode("utf-8")).hexdigest()[:16]
+
+
+class SessionCache:
+    """Thread-safe storage state per scraper and account for the life of the process."""
+
+    def __init__(self) -> None:
+        self._states: dict[tuple[str, str], dict[str, Any]] = {}
+        self._stats: dict[str, dict[str, float]] = {}
+        self._lock = threading.Lock()
+
+    def _scraper_stats(self, scraper: str) -> dict[str, float]:
+        return self._stats.setdefault(scraper, {"logins": 0, "login_seconds": 0.0, "sessions_reused": 0})
+
+    def restore(self, scraper: str, account: str = "") -> dict[str, Any] | None:
+        """The cached session to start a new browser from, if any."""
+        with self._lock:
+            return self._states.get((scraper, account))
+
+    def record_reuse(self, scraper: str) -> None:
+        """Count a restored session the login step found still logged in."""
+        with self._lock:
+            self._scraper_stats(scraper)["sessions_reused"] += 1
+
+    def record_login(self, scraper: str, seconds: float, state: dict[str, Any] | None, account: str = "") -> None:
+        """Count a completed login and cache its session."""
+        with self._lock:
+            stats = self._scraper_stats(scraper)
+            stats["logins"] += 1
+            stats["login_seconds"] += seconds
+            if state:
+                self._states[(scraper, account)] = state
+
+    def stats(self) -> dict[str, dict[str, float]]:
+        """Logins, time spent logging in and reused sessions so far, per scraper."""
+        with self._lock:
+            return {scraper: dict(stats) for scraper, stats in self._stats.items()}
+
+
+# Process-wide cache, so daemon chunks for the same scraper share one login
+session_cache = SessionCache()
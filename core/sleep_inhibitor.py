@@ -0,0 +1,177 @@
+"""
+Keep the machine awake while a job is running.
+
+Laptops left running unattended nightly scrapes go to sleep mid-job. While a
+job runs the runner holds a platform sleep-prevention assertion and drops it
+as soon as the job ends:
+
+- Windows: SetThreadExecutionState(ES_CONTINUOUS | ES_SYSTEM_REQUIRED), held
+  on a dedicated thread since the state belongs to the thread that set it
+- macOS: a `caffeinate -i -w <pid>` child
+- Linux: a `systemd-inhibit` lock around `tail --pid=<pid>`
+
+Each of these is released by the OS when the runner process exits, so a crash
+can't keep the machine awake forever. Set PREVENT_SLEEP_DURING_JOBS=false to
+turn it off.
+"""
+
+from __future__ import annotations
+
+import logging
+import os
+import shutil
+import signal
+import subprocess
+import sys
+import threading
+from collections.abc import Iterator
+from contextlib import contextmanager
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+ES_CONTINUOUS = 0x80000000
+ES_SYSTEM_REQUIRED = 0x00000001
+# How long a freshly spawned caffeinate/systemd-inhibit gets to fail (e.g. no logind) before it counts as holding
+SPAWN_CHECK_SECONDS = 0.5
+
+
+class _WindowsAssertion:
+    """
+    SetThreadExecutionState held on its own thread.
+
+    The execution state is per-thread, and jobs start and finish on whichever
+    worker thread asyncio.to_thread hands out, so one thread sets the state,
+    waits for the release and clears it.
+    """
+
+    def __init__(self, kernel32: Any = None) -> None:
+        if kernel32 is None:
+            import ctypes
+
+            kernel32 = ctypes.windll.kernel32  # type: ignore[attr-defined]
+        self._kernel32 = kernel32
+        self._started = threading.Event()
+        self._stop = threading.Event()
+        self._ok = False
+        self._thread = threading.Thread(target=self._hold, name="sleep-inhibitor", daemon=True)
+        self._thread.start()
+        self._started.wait()
+        if not self._ok:
+            raise OSError("SetThreadExecutionState failed")
+
+    def _hold(self) -> None:
+        self._ok = bool(self._kernel32.SetThreadExecutionState(ES_CONTINUOUS | ES_SYSTEM_REQUIRED))
+        self._started.set()
+        if not self._ok:
+            return
+        self._stop.wait()
+        self._kernel32.SetThreadExecutionState(ES_CONTINUOUS)
+
+    def stop(self) -> None:
+        self._stop.set()
+        self._thread.join(timeout=5)
+
+
+def _start_assertion(reason: str) -> Any:
+    """Take the platform assertion; returns a handle for _stop_assertion, or None if unsupported."""
+    if sys.platform == "win32":
+        return _WindowsAssertion()
+
+    pid = str(os.getpid())
+    if sys.platform == "darwin":
+        command = ["caffeinate", "-i", "-w", pid]
+    elif shutil.which("systemd-inhibit"):
+        command = [
+            "systemd-inhibit",
+            "--what=sleep:idle",
+            "--who=BayStateScraper",
+            f"--why={reason}",
+            "--mode=block",
+            "tail",
+            f"--pid={pid}",
+            "-f",
+            "/dev/null",
+        ]
+    else:
+        return None
+    # Own session so release can stop systemd-inhibit and its tail child together
+    process = subprocess.Popen(command, stdin=subprocess.DEVNULL, stdout=subprocess.DEVNULL, stderr=subprocess.DEVNULL, start_new_session=True)
+    try:
+        returncode = process.wait(timeout=SPAWN_CHECK_SECONDS)
+    except subprocess.TimeoutExpired:
+        return process
+    raise OSError(f"{command[0]} exited with status {returncode}")
+
+
+def _stop_assertion(handle: Any) -> None:
+    if isinstance(handle, _WindowsAssertion):
+        handle.stop()
+        return
+    try:
+        os.killpg(handle.pid, signal.SIGTERM)
+    except ProcessLookupError:
+        return
+    try:
+        handle.wait(timeout=5)
+    except subprocess.TimeoutExpired:
+        handle.kill()
+
+
+class SleepInhibitor:
+    """Reference-counted sleep-prevention assertion, held while any job runs."""
+
+    def __init__(self, enabled: bool | None = None) -> None:
+        self.enabled = (
+            enabled if enabled is not None else os.environ.get("PREVENT_SLEEP_DURING_JOBS", "true").lower() not in ("0", "false", "no")
+        )
+        self._holders = 0
+        self._handle: Any = None
+        self._lock = threading.Lock()
+
+    @property
+    def held(self) -> bool:
+        """Whether a platform assertion is currently in place."""
+        with self._lock:
+            return self._handle is not None
+
+    def acquire(self, reason: str) -> None:
+        with self._lock:
+            self._holders += 1
+            if not self.enabled or self._holders > 1:
+                return
+            try:
+                self._handle = _start_assertion(reason)
+            except Exception as e:
+                logger.warning(f"[Runner] Could not prevent sleep during job: {e}")
+                return
+            if self._handle is None:
+                logger.debug("Sleep prevention is not supported on this platform")
+            else:
+                logger.info(f"[Runner] Preventing sleep while running {reason}")
+
+    def release(self) -> None:
+        with self._lock:
+            self._holders = max(0, self._holders - 1)
+            if self._holders or self._handle is None:
+                return
+            handle, self._handle = self._handle, None
+            try:
+                _stop_assertion(handle)
+            except Exception as e:
+                logger.warning(f"[Runner] Could not release sleep prevention: {e}")
+                return
+        logger.info("[Runner] Sleep prevention released")
+
+    @contextmanager
+    def hold(self, reason: str) -> Iterator[None]:
+        """Hold the assertion for the duration of the block, releasing it on errors and cancellation."""
+        self.acquire(reason)
+        try:
+            yield
+        finally:
+            self.release()
+
+
+# Process-wide inhibitor shared by the daemon and sidecar server
+sleep_inhibitor = SleepInhibitor()
@@ -0,0 +1,177 @@
+"""
+Version information for support triage.
+
+Collects the sidecar version, Python and Playwright versions, installed browser
+revisions and OS/arch in one dict. Each field is read independently, so a
+missing component shows up under "errors" instead of failing the whole call.
+The same dict goes into the heartbeat and the sidecar's /version endpoint, so
+server-side dashboards can break the runner fleet down by version.
+
+The browsers directory is resolved in one place (resolve_browsers_dir) for the
+version info, preflight and browser launch, in this order:
+
+1. BROWSERS_DIR_OVERRIDE, set from the desktop app's browsers_dir_override setting
+2. PLAYWRIGHT_BROWSERS_PATH, when HONOR_ENV_BROWSERS_PATH is true
+3. The platform default ms-playwright cache, unless it has no Chromium install
+   and PLAYWRIGHT_BROWSERS_PATH is set, in which case that path is used
+
+Power users export PLAYWRIGHT_BROWSERS_PATH for other projects, so it does not
+win over our own install by default. Installs that only ever lived under that
+path keep working, though: with nothing in the default location there is no
+one else's browsers to get wrong.
+
+An installer that downloaded the browsers from a mirror (PLAYWRIGHT_DOWNLOAD_HOST)
+records the host in a .download-host file in the browsers directory, reported
+as browser_download_host so a misconfigured mirror is easy to spot.
+
+App version, commit and webview details are added by the desktop app, which
+knows its own build metadata.
+"""
+
+from __future__ import annotations
+
+import logging
+import os
+import platform
+import sys
+from collections.abc import Callable
+from importlib import metadata
+from pathlib import Path
+from typing import Any, NamedTuple
+
+from core.health import read_version
+
+logger = logging.getLogger(__name__)
+
+
+BROWSERS_DIR_OVERRIDE_ENV = "BROWSERS_DIR_OVERRIDE"
+HONOR_ENV_BROWSERS_PATH_ENV = "HONOR_ENV_BROWSERS_PATH"
+# Written next to the browsers by installers that used PLAYWRIGHT_DOWNLOAD_HOST
+DOWNLOAD_HOST_MARKER = ".download-host"
+
+SOURCE_OVERRIDE = "override"
+SOURCE_ENV = "env"
+SOURCE_ENV_FALLBACK = "env_fallback"
+SOURCE_DEFAULT = "default"
+
+
+class BrowsersDir(NamedTuple):
+    """The browsers directory to use and which setting it came from."""
+
+    path: Path | None
+    source: str
+
+
+_logged_resolution: BrowsersDir | None = None
+
+
+def default_browsers_path() -> Path | None:
+    """The platform's default ms-playwright cache, or None if it can't be determined."""
+    if sys.platform == "win32":
+        local_app_data = os.environ.get("LOCALAPPDATA")
+        return Path(local_app_data) / "ms-playwright" if local_app_data else None
+    if sys.platform == "darwin":
+        return Path.home() / "Library" / "Caches" / "ms-playwright"
+    return Path(os.environ.get("XDG_CACHE_HOME", Path.home() / ".cache")) / "ms-playwright"
+
+
+def _honor_env_browsers_path() -> bool:
+    return os.environ.get(HONOR_ENV_BROWSERS_PATH_ENV, "false").lower() in ("1", "true", "yes")
+
+
+def resolve_browsers_dir() -> BrowsersDir:
+    """Where browsers are installed and looked up. A None path means inside the playwright package."""
+    global _logged_resolution
+
+    override = os.environ.get(BROWSERS_DIR_OVERRIDE_ENV)
+    env_path = os.environ.get("PLAYWRIGHT_BROWSERS_PATH")
+    if override:
+        resolved = BrowsersDir(Path(override), SOURCE_OVERRIDE)
+    elif env_path and _honor_env_browsers_path():
+        # "0" keeps browsers inside the playwright package; nothing to inspect
+        resolved = BrowsersDir(None if env_path == "0" else Path(env_path), SOURCE_ENV)
+    else:
+        default = default_browsers_path()
+        if env_path and not (default is not None and has_chromium(default)):
+            resolved = BrowsersDir(None if env_path == "0" else Path(env_path), SOURCE_ENV_FALLBACK)
+        else:
+            resolved = BrowsersDir(default, SOURCE_DEFAULT)
+
+    if resolved != _logged_resolution:
+        _logged_resolution = resolved
+        logger.info(f"Browsers directory: {resolved.path or 'bundled with playwright'} (from {resolved.source})")
+    return resolved
+
+
+def has_chromium(browsers_path: Path) -> bool:
+    return browsers_path.is_dir() and any(p.name.startswith("chromium") for p in browsers_path.iterdir())
+
+
+def browsers_dir_conflict() -> dict[str, str] | None:
+    """
+    The ignored PLAYWRIGHT_BROWSERS_PATH and the directory actually used, when
+    both hold a Chromium install. None when there's nothing to warn about.
+    """
+    env_path = os.environ.get("PLAYWRIGHT_BROWSERS_PATH")
+    resolved = resolve_browsers_dir()
+    if not env_path or env_path == "0" or resolved.source in (SOURCE_ENV, SOURCE_ENV_FALLBACK) or resolved.path is None:
+        return None
+    if Path(env_path).resolve() == resolved.path.resolve():
+        return None
+    if not (has_chromium(Path(env_path)) and has_chromium(resolved.path)):
+        return None
+    return {"env_path": env_path, "resolved_path": str(resolved.path), "source": resolved.source}
+
+
+def _installed_browsers() -> list[str]:
+    path = resolve_browsers_dir().path
+    if path is None or not path.is_dir():
+        return []
+    return sorted(p.name for p in path.iterdir() if p.is_dir() and not p.name.startswith("."))
+
+
+def _browser_download_host() -> str | None:
+    path = resolve_browsers_dir().path
+    if path is None or not (path / DOWNLOAD_HOST_MARKER).is_file():
+        return None
+    return (path / DOWNLOAD_HOST_MARKER).read_text().strip() or None
+
+
+def _pinned_revisions() -> list[str]:
+    from utils.scraping.playwright_browser import BROWSER_REVISIONS_DIR_ENV
+
+    root = os.environ.get(BROWSER_REVISIONS_DIR_ENV)
+    if not root or not Path(root).is_dir():
+        return []
+    return sorted(p.name for p in Path(root).iterdir() if p.is_dir())
+
+
+FIELDS: dict[str, Callable[[], Any]] = {
+    "sidecar_version": read_version,
+    "python_version": platform.python_version,
+    "playwright_version": lambda: metadata.version("playwright"),
+    "browsers": _installed_browsers,
+    "browsers_dir": lambda: str(resolve_browsers_dir().path or ""),
+    "browsers_dir_source": lambda: resolve_browsers_dir().source,
+    "browser_revisions": _pinned_revisions,
+    "browser_download_host": _browser_download_host,
+    "os": platform.system,
+    "os_release": platform.release,
+    "arch": platform.machine,
+}
+
+
+def collect_version_info() -> dict[str, Any]:
+    """Every version field that could be read, plus an "errors" entry for the rest."""
+    info: dict[str, Any] = {}
+    errors: dict[str, str] = {}
+    for name, read in FIELDS.items():
+        try:
+            info[name] = read()
+        except Exception as e:
+            logger.debug(f"Could not read {name}: {e}")
+            info[name] = None
+            errors[name] = f"{type(e).__name__}: {e}"
+    if errors:
+        info["errors"] = errors
+    return info
@@ -10,6 +10,8 @@ Key behaviors:
 - Sends heartbeat when idle so coordinator knows runner is alive
 - Fetches credentials on-demand from coordinator (never stored locally)
 - Recycles browser after MAX_JOBS_BEFORE_RESTART to prevent memory leaks
+- Releases chunks that failed for local reasons (no browser, full disk) back to
+  the queue for another runner, then pauses claiming for a while
 - Graceful shutdown on SIGTERM/SIGINT
 
 Usage:
@@ -24,6 +26,24 @@ Environment Variables:
     POLL_INTERVAL: Seconds between polls when idle (default: 30)
     MAX_JOBS_BEFORE_RESTART: Recycle after N jobs to prevent leaks (default: 100)
     ENVIRONMENT: Set to 'dev' to use .env.development instead of .env
+    HEALTH_API_UNREACHABLE_MINUTES: Report failed health after this long without the API (default: 10)
+    HEALTH_MIN_FREE_DISK_MB: Report failed health, stop claiming and pause running jobs below this much free disk (default: 500)
+    HEALTH_WARN_FREE_DISK_MB: Report degraded health and warn running jobs below this much free disk (default: 2000)
+    DISK_CHECK_INTERVAL_SECONDS: How often a running job re-checks free disk space (default: 60)
+    DISK_PAUSE_MAX_SECONDS: Release the chunk after pausing this long for disk space (default: 1800)
+    METRICS_PUSH_ENABLED: Include a metrics snapshot in heartbeats, for runners behind NAT (default: off)
+    METRICS_PUSH_INTERVAL_SECONDS: Minimum seconds between metrics snapshots (default: 300)
+    METRICS_PUSH_MAX_SERIES: Cap on series per snapshot; extra per-site series are dropped (default: 200)
+    PAUSE_ON_BATTERY: Don't claim new work while on battery below the threshold (default: off)
+    PAUSE_ON_BATTERY_BELOW_PERCENT: Battery threshold for PAUSE_ON_BATTERY (default: 50)
+    PREVENT_SLEEP_DURING_JOBS: Keep the machine awake while a chunk is running (default: true)
+    RUNNER_LOCATION_TAG: Store this runner belongs to, sent with heartbeats and uploads (optional)
+    RUNNER_LABELS: Comma-separated labels sent with heartbeats and uploads, at most 10 (optional)
+    RUNNER_FAILOVER_PRIORITY: Failover rank sent with heartbeats, 0 for the primary (optional)
+    REDACTION_HASH_KEY: Secret for redact_mode: hash (optional; a per-runner key is created in data/ otherwise)
+    LOCAL_FAILURE_COOLDOWN_SECONDS: Pause claiming after releasing a chunk that failed locally (default: 600)
+    DEBUG_API_TRAFFIC: Stream every coordinator request (metadata only) as an api.request event (default: off)
+    RESULTS_SIGNING_KEY: Base64 Ed25519 private key for signing results manifests, read once and removed from the environment (optional; unsigned without it)
 """
 
 from __future__ import annotations
@@ -36,6 +56,7 @@ import signal
 import sys
 import time
 import asyncio
+from collections import Counter
 from datetime import datetime
 from pathlib import Path
 from typing import Any
@@ -73,15 +94,21 @@ if env_file.exists():
     load_dotenv(env_file, override=True)
 
 
-from core.api_client import ClaimedChunk, ScraperAPIClient, JobConfig
+from core.api_client import ClaimedChunk, IncompatibleServerError, ScraperAPIClient, JobConfig
+from core.health import read_version, register_state_dump_signal, runner_health
+from core.instance import AlreadyRunningError, InstanceLock, load_instance_id
+from core.job_pause import job_pause
 from core.realtime_manager import RealtimeManager
+from core.sleep_inhibitor import sleep_inhibitor
 from utils.logger import setup_logging
+from utils.structured_logging import LogBuffer, scrub_log_message
 
 
 # Configuration
 POLL_INTERVAL = int(os.environ.get("POLL_INTERVAL", "30"))
 MAX_JOBS_BEFORE_RESTART = int(os.environ.get("MAX_JOBS_BEFORE_RESTART", "100"))
 HEARTBEAT_INTERVAL = 60  # Send heartbeat every 60 seconds when idle
+LOCAL_FAILURE_COOLDOWN_SECONDS = int(os.environ.get("LOCAL_FAILURE_COOLDOWN_SECONDS", "600"))
 
 # Setup logging
 setup_logging(debug_mode=False)
@@ -97,14 +124,23 @@ def signal_handler(signum, frame):
     sig_name = signal.Signals(signum).name
     logger.info(f"Received {sig_name}, initiating graceful shutdown...")
     _shutdown_requested = True
+    # A paused job would otherwise hold its chunk until someone resumes it
+    job_pause.request_stop()
 
 
-def _create_log_entry(level: str, message: str) -> dict[str, str]:
-    return {
+def _create_log_entry(level: str, message: str) -> dict[str, Any]:
+    hits: Counter[str] = Counter()
+    message, cut = scrub_log_message(message, hits)
+    entry: dict[str, Any] = {
         "level": level,
         "message": message,
         "timestamp": datetime.utcnow().isoformat() + "Z",
     }
+    if cut:
+        entry["truncated_chars"] = cut
+    if hits:
+        entry["redactions"] = dict(hits)
+    return entry
 
 
 def run_job(
@@ -139,6 +175,8 @@ def run_claimed_chunk(
     client: ScraperAPIClient,
     log_buffer: list[dict[str, Any]] | None = None,
 ) -> dict[str, Any]:
+    from runner.golden_check import job_config_for_chunk
+
     job_config = client.get_job_config(chunk.job_id)
     if not job_config:
         raise RuntimeError(f"Failed to fetch job config for chunk job {chunk.job_id}")
@@ -146,6 +184,7 @@ def run_claimed_chunk(
     job_config.skus = chunk.skus
     job_config.test_mode = chunk.test_mode
     job_config.max_workers = chunk.max_workers
+    job_config.job_config = job_config_for_chunk(job_config.job_config, chunk.chunk_index)
 
     if chunk.scrapers:
         job_config.scrapers = [
@@ -170,12 +209,9 @@ async def main_async():
 
     # Initialize API client
     client = ScraperAPIClient()
+    client.instance_id = load_instance_id()
 
-    # Read version
-    version = "unknown"
-    version_file = PROJECT_ROOT / "VERSION"
-    if version_file.exists():
-        version = version_file.read_text().strip()
+    version = read_version()
 
     if not client.api_url or not client.api_key:
         logger.error("Missing SCRAPER_API_URL or SCRAPER_API_KEY. Cannot start daemon.")
@@ -186,11 +222,19 @@ async def main_async():
     logger.info("=" * 60)
     logger.info(f"Environment: {args.env.upper()}")
     logger.info(f"Runner Name: {client.runner_name}")
+    logger.info(f"Instance ID: {client.instance_id}")
     logger.info(f"API URL: {client.api_url}")
     logger.info(f"Platform: {platform.system()} {platform.release()}")
     logger.info(f"Poll Interval: {POLL_INTERVAL}s")
     logger.info(f"Max Jobs Before Restart: {MAX_JOBS_BEFORE_RESTART}")
     logger.info("=" * 60)
+    runner_health.check_power_monitoring()
+
+    try:
+        await asyncio.to_thread(client.negotiate_capabilities)
+    except IncompatibleServerError as e:
+        logger.error(f"{e} Cannot start daemon.")
+        sys.exit(1)
 
     logger.info("Daemon API handler disabled; per-job log batches enabled")
 
@@ -219,6 +263,9 @@ async def main_async():
 
     chunks_completed = 0
     last_heartbeat = 0
+    deferring: str | None = None
+    # Set after releasing a chunk for a local failure, so we don't claim it straight back
+    local_failure: tuple[str, float] | None = None
 
     logger.info("[Daemon] Entering main polling loop")
 
@@ -228,23 +275,50 @@ async def main_async():
                 logger.info(f"Completed {chunks_completed} chunks. Exiting for container restart (memory hygiene).")
                 break
 
+            defer_reason = runner_health.defer_work_reason()
+            if local_failure and time.time() < local_failure[1]:
+                defer_reason = defer_reason or f"local_failure: {local_failure[0]}"
+            if defer_reason != deferring:
+                if defer_reason:
+                    logger.info(f"[Daemon] Deferring new work ({defer_reason})")
+                else:
+                    logger.info("[Daemon] Resuming work")
+                deferring = defer_reason
+            if defer_reason:
+                now = time.time()
+                if now - last_heartbeat >= HEARTBEAT_INTERVAL:
+                    await asyncio.to_thread(client.heartbeat, status="idle")
+                    last_heartbeat = now
+                await asyncio.sleep(POLL_INTERVAL)
+                continue
+
             logger.info("[Daemon] Claiming next work unit...")
             chunk = await asyncio.to_thread(client.claim_chunk, runner_name=client.runner_name)
             logger.info(f"[Daemon] Claim result: {chunk}")
+            if chunk is None:
+                runner_health.set_queue_depth(0)
+            elif chunk.pending_chunks is not None:
+                runner_health.set_queue_depth(chunk.pending_chunks)
 
             if chunk:
                 logger.info(f"[Chunk {chunk.chunk_id}] Claimed - job={chunk.job_id}, skus={len(chunk.skus)}")
 
+                runner_health.set_current_job(chunk.job_id)
+                sleep_inhibitor.acquire(f"chunk {chunk.chunk_id}")
                 try:
                     await asyncio.to_thread(client.heartbeat, current_job_id=chunk.job_id, lease_token=chunk.lease_token, status="busy")
                     if rm and rm.is_connected:
                         await rm.broadcast_job_progress(chunk.job_id, "started", 0, "Chunk processing started")
 
-                    chunk_logs: list[dict[str, Any]] = []
+                    chunk_logs: list[dict[str, Any]] = LogBuffer()
                     chunk_logs.append(_create_log_entry("info", f"Daemon claimed chunk {chunk.chunk_id} for job {chunk.job_id}"))
                     start_time = time.time()
                     results = await asyncio.to_thread(run_claimed_chunk, chunk, client, chunk_logs)
                     elapsed = time.time() - start_time
+                    from runner.local_failures import check_browser_launches, check_disk_space
+
+                    check_browser_launches(results)
+                    check_disk_space(results)
                     chunk_logs.append(_create_log_entry("info", f"Daemon completed chunk in {elapsed:.1f}s"))
 
                     chunk_results = {
@@ -255,6 +329,43 @@ async def main_async():
                         "telemetry": results.get("telemetry", {}),
                         "logs": results.get("logs", []),
                     }
+                    if results.get("deferred_scrapers"):
+                        chunk_results["deferred_scrapers"] = results["deferred_scrapers"]
+                    if results.get("rejected"):
+                        chunk_results["rejected"] = results["rejected"]
+                    if results.get("selector_drift"):
+                        chunk_results["selector_drift"] = results["selector_drift"]
+                    if results.get("provenance"):
+                        chunk_results["provenance"] = results["provenance"]
+                    if results.get("log_redactions"):
+                        chunk_results["log_redactions"] = results["log_redactions"]
+                    if results.get("block_cooldowns"):
+                        chunk_results["block_cooldowns"] = results["block_cooldowns"]
+                    if results.get("logins"):
+                        chunk_results["logins"] = results["logins"]
+                    if results.get("result_warnings"):
+                        chunk_results["result_warnings"] = results["result_warnings"]
+                    if results.get("paused_seconds"):
+                        chunk_results["paused_seconds"] = results["paused_seconds"]
+                    if results.get("duplicates_merged"):
+                        chunk_results["duplicates_merged"] = results["duplicates_merged"]
+                    if results.get("conflicts_flagged"):
+                        chunk_results["conflicts_flagged"] = results["conflicts_flagged"]
+                    if results.get("unmapped_availability_strings"):
+                        chunk_results["unmapped_availability_strings"] = results["unmapped_availability_strings"]
+                    if results.get("output_fallbacks"):
+                        chunk_results["output_fallbacks"] = results["output_fallbacks"]
+                    if results.get("portal_changes"):
+                        chunk_results["portal_changes"] = results["portal_changes"]
+                    if results.get("log_truncation"):
+                        chunk_results["log_truncation"] = results["log_truncation"]
+                    if results.get("coverage"):
+                        chunk_results["coverage"] = results["coverage"]
+                        chunk_results["outcome"] = results["outcome"]
+                    if results.get("disk_pauses"):
+                        chunk_results["disk_pauses"] = results["disk_pauses"]
+                    if results.get("browser_launch_failures"):
+                        chunk_results["browser_launch_failures"] = results["browser_launch_failures"]
 
                     await asyncio.to_thread(
                         client.submit_chunk_results,
@@ -277,16 +388,26 @@ async def main_async():
                         _create_log_entry("error", f"Daemon failed chunk {chunk.chunk_id}: {type(e).__name__} - {e}"),
                     ]
                     logger.exception(f"[Chunk {chunk.chunk_id}] Failed with error")
-                    await asyncio.to_thread(
-                        client.submit_chunk_results,
-                        chunk.chunk_id,
-                        "failed",
-                        error_message=str(e),
-                    )
+                    from runner.local_failures import local_failure_reason
+
+                    local_reason = local_failure_reason(e)
+                    if local_reason and await asyncio.to_thread(client.release_chunk, chunk.chunk_id, local_reason, lease_token=chunk.lease_token):
+                        local_failure = (local_reason, time.time() + LOCAL_FAILURE_COOLDOWN_SECONDS)
+                        failure_logs.append(_create_log_entry("warning", f"Chunk {chunk.chunk_id} released back to queue ({local_reason})"))
+                    else:
+                        await asyncio.to_thread(
+                            client.submit_chunk_results,
+                            chunk.chunk_id,
+                            "failed",
+                            error_message=str(e),
+                        )
                     try:
                         await asyncio.to_thread(client.post_logs, chunk.job_id, failure_logs)
                     except Exception as log_error:
                         logger.warning(f"[Chunk {chunk.chunk_id}] Failed to send error logs: {log_error}")
+                finally:
+                    sleep_inhibitor.release()
+                    runner_health.set_current_job(None)
 
             else:
                 now = time.time()
@@ -312,11 +433,21 @@ async def main_async():
 def main():
     signal.signal(signal.SIGTERM, signal_handler)
     signal.signal(signal.SIGINT, signal_handler)
+    register_state_dump_signal()
+
+    lock = InstanceLock("daemon")
+    try:
+        lock.acquire()
+    except AlreadyRunningError as e:
+        logger.error(f"{e}. Refusing to start a second daemon for this user.")
+        sys.exit(1)
 
     try:
         asyncio.run(main_async())
     except KeyboardInterrupt:
         pass
+    finally:
+        lock.release()
 
 
 if __name__ == "__main__":
@@ -3,30 +3,182 @@ from __future__ import annotations
 import asyncio
 import logging
 import os
-from datetime import datetime, timezone
+import sys
+import threading
+from collections import Counter
+from dataclasses import dataclass
+from pathlib import Path
+from datetime import datetime, timedelta, timezone
 from typing import Any, Callable, Dict, List, Optional, Tuple
 
+from pydantic import ValidationError
+
 from core.api_client import JobConfig
+from core.block_cooldown import BLOCKED_CATEGORIES, block_cooldowns
+from core.disk_guard import DiskGuard
 from core.events import ScraperEvent, create_emitter, event_bus
+from core.failure_classifier import FailureClassifier
+from core.health import read_version
+from core.models import normalize_availability
+from core.job_pause import job_pause
+from core.pacing import pacing_control
+from core.portal_fingerprint import portal_fingerprints
+from core.session_cache import session_cache
 from core.settings_manager import settings
+from core.version_info import browsers_dir_conflict, resolve_browsers_dir
 from scrapers.ai_discovery import AIDiscoveryScraper
 from scrapers.executor.workflow_executor import WorkflowExecutor
 from scrapers.parser import ScraperConfigParser
 from scrapers.result_collector import ResultCollector
+from utils.structured_logging import LogBuffer, log_scrubber, scrub_log_message
+
+from runner.browser_launch import classify_launch_failure
+from runner.golden_check import check_golden_sample
+from runner.dedup import DedupResult, dedupe_records
+from runner.preflight import PreflightFailed, preflight_skipped, run_preflight
+from runner.result_checks import OUTCOME_SUCCESS, check_results, compute_coverage, worst_outcome
+from runner.run_options import RunOptions, merge_options
 
 logger = logging.getLogger(__name__)
 
 
+# Debug runs show the browser, so keep them to a handful of SKUs to avoid
+# accidentally walking a full catalog in headful mode.
+DEBUG_RUN_MAX_SKUS = 10
+
+# Distinct unrecognised availability strings reported per scraper
+MAX_UNMAPPED_AVAILABILITY_STRINGS = 50
+
+_failure_classifier = FailureClassifier()
+
+
 class ConfigurationError(Exception):
     pass
 
 
+@dataclass
+class DebugRunOptions:
+    """Per-run overrides for interactive (headful) debug runs.
+
+    Debug runs are tagged in the results payload and are never submitted
+    back to the coordinator.
+    """
+
+    headful: bool = True
+    slow_mo_ms: int = 0
+    pause_on_error: bool = False
+    max_skus: int = DEBUG_RUN_MAX_SKUS
+
+
 def create_log_entry(level: str, message: str) -> Dict[str, Any]:
-    return {
+    hits: Counter[str] = Counter()
+    message, cut = scrub_log_message(message, hits)
+    entry: Dict[str, Any] = {
         "level": level,
         "message": message,
         "timestamp": datetime.now(timezone.utc).isoformat().replace("+00:00", "Z"),
     }
+    if cut:
+        entry["truncated_chars"] = cut
+    if hits:
+        entry["redactions"] = dict(hits)
+    return entry
+
+
+def _log_redactions(log_buffer: List[Dict[str, Any]]) -> Dict[str, int]:
+    """Redactions made per rule in the job's log entries."""
+    if isinstance(log_buffer, LogBuffer):
+        return dict(log_buffer.redactions)
+    counts: Counter[str] = Counter()
+    for entry in log_buffer:
+        counts.update(entry.get("redactions") or {})
+    return dict(counts)
+
+
+def _logins_since(before: Dict[str, Dict[str, float]]) -> Dict[str, Dict[str, float]]:
+    """Logins, login time and reused sessions per scraper since the `before` snapshot."""
+    logins: Dict[str, Dict[str, float]] = {}
+    for scraper, stats in session_cache.stats().items():
+        previous = before.get(scraper, {})
+        delta = {key: value - previous.get(key, 0) for key, value in stats.items()}
+        if delta["logins"] or delta["sessions_reused"]:
+            delta["login_seconds"] = round(delta["login_seconds"], 2)
+            logins[scraper] = delta
+    return logins
+
+
+def _record_log_truncation(results: Dict[str, Any], log_buffer: List[Dict[str, Any]]) -> None:
+    """Report log entries the buffer dropped or truncated to stay within its caps."""
+    if isinstance(log_buffer, LogBuffer):
+        stats = log_buffer.stats()
+        if stats["dropped_lines"] or stats["truncated_lines"]:
+            results["log_truncation"] = stats
+
+
+def _usable_output_dir(scraper_name: str, output_dir: str) -> Optional[Path]:
+    """The scraper's output directory if it exists and is writable, else None (the default directory is used)."""
+    path = Path(output_dir)
+    if path.is_dir() and os.access(path, os.W_OK):
+        return path
+    logger.warning(f"[Runner] {scraper_name}: output directory {output_dir} is missing or not writable, using the default directory")
+    return None
+
+
+def _record_unmapped_availability(results: Dict[str, Any], scraper_name: str, raw: str) -> None:
+    """Remember an availability string no mapping or heuristic understood, so the mappings can be extended."""
+    unmapped = results.setdefault("unmapped_availability_strings", {}).setdefault(scraper_name, [])
+    if raw not in unmapped and len(unmapped) < MAX_UNMAPPED_AVAILABILITY_STRINGS:
+        unmapped.append(raw)
+
+
+def _apply_dedup(results: Dict[str, Any], scraper_name: str, records: Dict[str, Dict[str, Any]], dedup: DedupResult) -> None:
+    """Drop merged duplicates from the upload and keep conflicting values for review."""
+    for sku in records.keys() - dedup.records.keys():
+        del results["data"][sku][scraper_name]
+        if not results["data"][sku]:
+            del results["data"][sku]
+    results["duplicates_merged"] = results.get("duplicates_merged", 0) + dedup.duplicates_merged
+    for conflict in dedup.conflicts:
+        results["conflicts_flagged"] = results.get("conflicts_flagged", 0) + 1
+        resolution = "held for review" if conflict["kept"] is None else f"kept {conflict['kept']}"
+        results.setdefault("rejected", []).append(
+            {
+                "sku": conflict["sku"],
+                "scraper": scraper_name,
+                "field": "record",
+                "value": conflict["values"],
+                "reason": f"conflicting duplicate of {conflict['duplicate_sku']}, {resolution}",
+            }
+        )
+
+
+def _record_block_outcome(results: Dict[str, Any], scraper_name: str, clean: bool) -> None:
+    """Start or escalate a block cooldown if the scraper was blocked, or reset it after a clean run."""
+    failures = [f for f in results.get("failed_skus") or [] if f.get("scraper") == scraper_name]
+    if any(f.get("category") in BLOCKED_CATEGORIES for f in failures):
+        until = block_cooldowns.record_blocked(scraper_name)
+        results.setdefault("block_cooldowns", []).append({"scraper": scraper_name, "until": until.isoformat()})
+        return
+    aborted = any(d.get("scraper") == scraper_name and d.get("aborted") for d in results.get("selector_drift") or [])
+    if clean and not failures and not aborted:
+        block_cooldowns.record_success(scraper_name)
+
+
+def _run_window_overrun(config: Any, now: datetime | None = None) -> datetime | None:
+    """When the scraper's run windows reopen, if they closed more than its grace period ago."""
+    now = now or datetime.now(timezone.utc)
+    opens_at = config.run_window_opens_at(now)
+    if opens_at is None or config.run_window_opens_at(now - timedelta(minutes=config.run_window_grace_minutes)) is None:
+        return None
+    return opens_at
+
+
+def _record_failure(results: Dict[str, Any], scraper_name: str, sku: str, error: Exception) -> None:
+    """Add a failed SKU to the results, categorized by FailureClassifier."""
+    category = _failure_classifier.classify_exception(error, {}).failure_type.value
+    results.setdefault("failed_skus", []).append(
+        {"scraper": scraper_name, "sku": sku, "category": category, "error": f"{type(error).__name__}: {error}"}
+    )
 
 
 def _normalize_selectors_payload(raw_selectors: Any) -> list[dict[str, Any]]:
@@ -178,11 +330,41 @@ def _build_telemetry_from_events(events: list[ScraperEvent]) -> Dict[str, Any]:
     }
 
 
+def _read_line_into(loop: asyncio.AbstractEventLoop, future: asyncio.Future[str]) -> None:
+    try:
+        line = sys.stdin.readline()
+    except (OSError, ValueError):
+        # stdin unavailable (detached process) - nothing to wait on
+        line = ""
+    try:
+        loop.call_soon_threadsafe(lambda: future.done() or future.set_result(line))
+    except RuntimeError:
+        # The run ended (loop closed) before a line arrived
+        pass
+
+
+async def _wait_at_breakpoint(scraper_name: str, sku: str, error: Exception) -> None:
+    """Hold a debug run at a failed SKU until the operator continues.
+
+    Reads one line from stdin in a daemon thread so the event loop (and the
+    visible browser) stay responsive. The thread isn't part of the loop's
+    executor, so cancelling the job or Ctrl-C returns at once instead of
+    waiting for a line; closing stdin also releases the breakpoint.
+    """
+    logger.warning(f"[Runner] Paused at {scraper_name}/{sku} after {type(error).__name__}: {error}. Press Enter to continue.")
+    loop = asyncio.get_running_loop()
+    line: asyncio.Future[str] = loop.create_future()
+    threading.Thread(target=_read_line_into, args=(loop, line), name="breakpoint-stdin", daemon=True).start()
+    await line
+    logger.info(f"[Runner] Resuming after breakpoint at {scraper_name}/{sku}")
+
+
 def run_job(
     job_config: JobConfig,
     runner_name: Optional[str] = None,
     log_buffer: Optional[List[Dict[str, Any]]] = None,
     progress_callback: Optional[Callable[[str, str, dict[str, Any]], bool]] = None,
+    debug_options: Optional[DebugRunOptions] = None,
 ) -> Dict[str, Any]:
     """Execute a scrape job.
 
@@ -190,9 +372,12 @@ def run_job(
         job_config: The job configuration
         runner_name: Optional name of the runner
         log_buffer: Optional list to collect log entries
-        progress_callback: Optional callback function called after each SKU is processed.
+        progress_callback: Optional callback function called with each SKU's upload record
+                          once the scraper's results are deduplicated.
                           Signature: callback(sku: str, scraper_name: str, data: dict) -> bool
                           Should return True if progress was saved successfully.
+        debug_options: Optional headful debug run overrides. Caps the SKU count
+                       and tags the results with ``debug_run: True``.
 
     Returns:
         Dictionary with job results
@@ -204,14 +389,17 @@ def run_job(
     parser = ScraperConfigParser()
     collector = ResultCollector(test_mode=job_config.test_mode)
 
+    logins_before = session_cache.stats()
     results: Dict[str, Any] = {
         "skus_processed": 0,
         "scrapers_run": [],
         "data": {},
     }
+    if debug_options is not None:
+        results["debug_run"] = True
 
     if log_buffer is None:
-        log_buffer = []
+        log_buffer = LogBuffer()
 
     log_buffer.append(create_log_entry("info", f"Job {job_id} started"))
     log_buffer.append(create_log_entry("info", f"Processing {len(job_config.skus)} SKUs with {len(job_config.scrapers)} scrapers"))
@@ -230,6 +418,11 @@ def run_job(
         log_buffer.append(create_log_entry("info", f"Test mode: using {len(skus)} test SKUs from job payload"))
         logger.info(f"[Runner] Test mode: using {len(skus)} test SKUs from job payload")
 
+    if debug_options is not None and len(skus) > debug_options.max_skus:
+        log_buffer.append(create_log_entry("warning", f"Debug run: limiting {len(skus)} SKUs to the first {debug_options.max_skus}"))
+        logger.warning(f"[Runner] Debug run: limiting {len(skus)} SKUs to the first {debug_options.max_skus}")
+        skus = skus[: debug_options.max_skus]
+
     if not skus:
         log_buffer.append(create_log_entry("warning", "No SKUs to process"))
         logger.warning("[Runner] No SKUs to process")
@@ -241,26 +434,58 @@ def run_job(
     if is_discovery_job:
         return _run_discovery_job(job_config, skus, results, log_buffer)
 
+    try:
+        run_options = RunOptions.from_job_config(job_config.job_config)
+    except ValidationError as e:
+        log_buffer.append(create_log_entry("error", f"Invalid run options: {e}"))
+        raise ConfigurationError(f"[Runner] Invalid run options: {e}") from e
+    global_options = {
+        "headless": settings.browser_settings["headless"],
+        "timeout": settings.browser_settings["timeout"],
+        "browser_revision": None,
+    }
+    headless_by_scraper: dict[str, bool] = {}
+
     configs: list[Any] = []
     config_errors: list[tuple[str, str]] = []
 
     for scraper_cfg in job_config.scrapers:
         try:
             options = scraper_cfg.options or {}
+            effective = merge_options(
+                global_options,
+                {key: options.get(key) for key in global_options},
+                run_options.model_dump(include=set(global_options)),
+            )
             config_dict = {
                 "name": scraper_cfg.name,
                 "base_url": scraper_cfg.base_url,
                 "search_url_template": scraper_cfg.search_url_template,
                 "selectors": _normalize_selectors_payload(scraper_cfg.selectors),
                 "workflows": options.get("workflows", []),
-                "timeout": options.get("timeout", 30),
+                "timeout": effective["timeout"],
                 "test_skus": scraper_cfg.test_skus if scraper_cfg.test_skus is not None else [],
                 "retries": getattr(scraper_cfg, "retries", 0),
                 "validation": getattr(scraper_cfg, "validation", None),
+                "browser_revision": effective["browser_revision"],
+                "maintenance_windows": options.get("maintenance_windows"),
+                "run_windows": options.get("run_windows"),
+                "run_window_grace_minutes": options.get("run_window_grace_minutes", 15),
+                "adaptive_pacing": bool(options.get("adaptive_pacing", False)),
+                "golden": options.get("golden"),
+                "redact_fields": options.get("redact_fields"),
+                "redact_mode": options.get("redact_mode", "strip"),
+                "log_redaction_patterns": options.get("log_redaction_patterns"),
+                "output_dir": options.get("output_dir"),
+                "availability_mappings": options.get("availability_mappings"),
+                "dedup_policy": options.get("dedup_policy"),
+                "result_checks": options.get("result_checks"),
+                "min_coverage_percent": options.get("min_coverage_percent"),
             }
 
-            config = parser.load_from_dict(config_dict)
+            config = parser.load_from_dict(config_dict).with_source(scraper_cfg.source())
             configs.append(config)
+            headless_by_scraper[config.name] = bool(effective["headless"])
             log_buffer.append(create_log_entry("info", f"Loaded scraper config: {config.name}"))
             logger.info(f"[Runner] Loaded scraper config: {config.name}")
         except Exception as e:
@@ -282,139 +507,398 @@ def run_job(
         raise ConfigurationError(f"[Runner] {error_msg}")
 
     for config in configs:
-        log_buffer.append(create_log_entry("info", f"Starting scraper: {config.name}"))
-        logger.info(f"[Runner] Running scraper: {config.name}")
-        results["scrapers_run"].append(config.name)
+        log_scrubber.set_scraper_rules(config.name, config.log_redaction_patterns or {})
 
-        executor = None
-        try:
-            headless = settings.browser_settings["headless"]
-            if not headless:
-                logger.warning("[Runner] Running in VISIBLE mode (HEADLESS=false) - browser will be visible for debugging")
-                log_buffer.append(create_log_entry("warning", "Running in VISIBLE mode - browser will be visible"))
-
-            executor = WorkflowExecutor(
-                config,
-                headless=headless,
-                timeout=30,
-                worker_id="API",
-                debug_mode=False,
-                job_id=job_id,
-                event_emitter=emitter,
+    # Scraper rules apply to every log line, so they must not outlive the job
+    try:
+        results["provenance"] = {
+            "sidecar_version": read_version(),
+            "started_at": datetime.now(timezone.utc).isoformat(),
+            "scrapers": {config.name: {"config_hash": config.config_hash(), "browser_revision": config.browser_revision} for config in configs},
+        }
+        if run_options.model_dump(exclude_none=True):
+            results["provenance"]["run_options"] = run_options.model_dump(exclude_none=True)
+        browsers_dir = resolve_browsers_dir()
+        results["provenance"]["browsers_dir"] = {"path": str(browsers_dir.path) if browsers_dir.path else None, "source": browsers_dir.source}
+        browsers_conflict = browsers_dir_conflict()
+        if browsers_conflict:
+            message = (
+                f"PLAYWRIGHT_BROWSERS_PATH ({browsers_conflict['env_path']}) is ignored in favor of "
+                f"{browsers_conflict['resolved_path']} ({browsers_conflict['source']}); both contain Chromium"
             )
+            log_buffer.append(create_log_entry("warning", message))
+            emitter.warning(message, browsers_dir_conflict=browsers_conflict)
 
-            # Run all async operations in a single event loop to properly manage
-            # Playwright browser subprocess lifecycle
-            async def run_all_scrapes() -> List[Tuple[str, Any]]:
-                if executor is None:
-                    return []
-                scrape_results = []
-                try:
-                    await executor.initialize()
-                    for sku in skus:
-                        try:
-                            result = await executor.execute_workflow(
-                                context={"sku": sku, "test_mode": job_config.test_mode},
-                                quit_browser=False,
-                            )
-                            scrape_results.append((sku, result))
-                        except Exception as e:
-                            log_buffer.append(create_log_entry("error", f"{config.name}/{sku}: {type(e).__name__} - {e}"))
-                            logger.error(f"[Runner] {config.name}/{sku}: Error - {e}")
-                            scrape_results.append((sku, None))
-                finally:
-                    # Ensure browser is properly quit inside the async context
-                    if executor.browser:
-                        try:
-                            await executor.browser.quit()
-                        except Exception as e:
-                            logger.debug(f"Browser quit error: {e}")
-                return scrape_results
-
-            scrape_results = asyncio.run(run_all_scrapes())
-
-            # Process results after async loop completes
-            for sku, result in scrape_results:
-                if result is None:
+        if preflight_skipped(job_config):
+            log_buffer.append(create_log_entry("warning", "Preflight checks skipped"))
+            logger.warning("[Runner] Preflight checks skipped (skip_preflight)")
+        else:
+            try:
+                run_preflight(job_config, configs)
+            except PreflightFailed as e:
+                log_buffer.append(create_log_entry("error", str(e)))
+                raise
+
+        # pause_on_error reads stdin itself, so pacing and pause commands are only accepted outside debug runs
+        if debug_options is None:
+            pacing_control.start_listener()
+
+        disk_guard = DiskGuard()
+
+        def disk_notice(level: str, message: str, free_mb: float | None) -> None:
+            log_buffer.append(create_log_entry(level, message))
+            logger.log(logging.INFO if level == "info" else logging.WARNING, f"[Runner] {message}")
+            if level != "info":
+                emitter.warning(message, free_disk_mb=round(free_mb) if free_mb is not None else None)
+
+        ignore_maintenance = bool((job_config.job_config or {}).get("ignore_maintenance_windows"))
+        ignore_run_windows = bool((job_config.job_config or {}).get("ignore_run_windows"))
+        # Golden samples guard full jobs; test and debug runs are already small and supervised
+        run_golden = not job_config.test_mode and debug_options is None and not (job_config.job_config or {}).get("skip_golden_check")
+        # Set by the desktop app when someone can answer login challenges (see core.auth_challenge)
+        interactive_auth = bool((job_config.job_config or {}).get("interactive_auth")) and debug_options is None
+
+        for config in configs:
+            window_end = config.active_maintenance_window_end()
+            if window_end is not None:
+                if not ignore_maintenance:
+                    message = f"{config.name}: deferred due to maintenance window (ends {window_end.isoformat()})"
+                    log_buffer.append(create_log_entry("warning", message))
+                    logger.warning(f"[Runner] {message}")
+                    results.setdefault("deferred_scrapers", []).append(
+                        {
+                            "scraper": config.name,
+                            "reason": "maintenance_window",
+                            "resume_after": window_end.astimezone(timezone.utc).isoformat(),
+                        }
+                    )
                     continue
-
-                results["skus_processed"] += 1
-
-                if result.get("success"):
-                    extracted_data = result.get("results", {})
-
-                    if extracted_data.get("product_name") and not extracted_data.get("Name"):
-                        extracted_data["Name"] = extracted_data.pop("product_name")
-                    if extracted_data.get("price") and not extracted_data.get("Price"):
-                        extracted_data["Price"] = extracted_data.pop("price")
-                    if extracted_data.get("brand") and not extracted_data.get("Brand"):
-                        extracted_data["Brand"] = extracted_data.pop("brand")
-                    if extracted_data.get("description") and not extracted_data.get("Description"):
-                        extracted_data["Description"] = extracted_data.pop("description")
-                    if extracted_data.get("image_url") and not extracted_data.get("Images"):
-                        extracted_data["Images"] = [extracted_data.pop("image_url")]
-                    if extracted_data.get("availability") and not extracted_data.get("Availability"):
-                        extracted_data["Availability"] = extracted_data.pop("availability")
-                    has_data = any(extracted_data.get(field) for field in ["Name", "Brand", "Weight"])
-
-                    if has_data:
-                        if sku not in results["data"]:
-                            results["data"][sku] = {}
-
-                        # Handle both "Images" and "Image URLs" field names
-                        # (scraper configs use "Image URLs" as the selector name)
-                        images = extracted_data.get("Images") or extracted_data.get("Image URLs") or extracted_data.get("Image_URLs") or []
-
-                        # Capture the product page URL from the browser if not
-                        # explicitly extracted by a "URL" selector
-                        page_url = extracted_data.get("URL")
-                        if not page_url and executor and executor.browser:
-                            try:
-                                page_url = executor.browser.current_url
-                            except Exception:
-                                pass
-
-                        results["data"][sku][config.name] = {
-                            # Note: Price is NOT scraped - we use our own pricing
-                            "title": extracted_data.get("Name"),
-                            "brand": extracted_data.get("Brand"),
-                            "weight": extracted_data.get("Weight"),
-                            "description": extracted_data.get("Description"),
-                            "images": extracted_data.get("Image URLs", []) or extracted_data.get("Images", []),
-                            "availability": extracted_data.get("Availability"),
-                            "url": page_url,
-                            "scraped_at": datetime.now().isoformat(),
+                message = f"{config.name}: maintenance window active until {window_end.isoformat()}, running anyway (override)"
+                log_buffer.append(create_log_entry("warning", message))
+                logger.warning(f"[Runner] {message}")
+
+            opens_at = config.run_window_opens_at()
+            if opens_at is not None:
+                if not ignore_run_windows:
+                    message = f"{config.name}: deferred, outside its run windows (next opens {opens_at.isoformat()})"
+                    log_buffer.append(create_log_entry("warning", message))
+                    logger.warning(f"[Runner] {message}")
+                    results.setdefault("deferred_scrapers", []).append(
+                        {
+                            "scraper": config.name,
+                            "reason": "outside_run_window",
+                            "resume_after": opens_at.astimezone(timezone.utc).isoformat(),
                         }
+                    )
+                    continue
+                message = f"{config.name}: outside its run windows (next opens {opens_at.isoformat()}), running anyway (override)"
+                log_buffer.append(create_log_entry("warning", message))
+                logger.warning(f"[Runner] {message}")
+
+            cooldown_end = block_cooldowns.cooldown_until(config.name)
+            if cooldown_end is not None:
+                message = f"{config.name}: deferred, in block cooldown until {cooldown_end.isoformat()}"
+                log_buffer.append(create_log_entry("warning", message))
+                logger.warning(f"[Runner] {message}")
+                results.setdefault("deferred_scrapers", []).append(
+                    {"scraper": config.name, "reason": "block_cooldown", "resume_after": cooldown_end.isoformat()}
+                )
+                continue
 
-                        collector.add_result(sku, config.name, extracted_data)
-
-                        # Call progress callback if provided (for incremental saving)
-                        if progress_callback:
+            if config.redact_fields:
+                collector.redacted_fields[config.name] = config.redacted_source_fields()
+            if config.output_dir:
+                output_dir = _usable_output_dir(config.name, config.output_dir)
+                if output_dir is not None:
+                    collector.output_dirs[config.name] = output_dir
+                else:
+                    collector.output_fallbacks[config.name] = f"{config.output_dir} is not a writable directory"
+            log_buffer.append(create_log_entry("info", f"Starting scraper: {config.name}"))
+            logger.info(f"[Runner] Running scraper: {config.name}")
+            results["scrapers_run"].append(config.name)
+
+            executor = None
+            initialized = True
+            # SKUs left unscraped because the run window closed mid-job
+            held_back: list[str] = []
+            try:
+                headless = headless_by_scraper.get(config.name, settings.browser_settings["headless"])
+                if debug_options is not None and debug_options.headful:
+                    headless = False
+                if not headless:
+                    logger.warning("[Runner] Running in VISIBLE mode (HEADLESS=false) - browser will be visible for debugging")
+                    log_buffer.append(create_log_entry("warning", "Running in VISIBLE mode - browser will be visible"))
+
+                executor = WorkflowExecutor(
+                    config,
+                    headless=headless,
+                    timeout=config.timeout,
+                    worker_id="API",
+                    debug_mode=False,
+                    job_id=job_id,
+                    event_emitter=emitter,
+                    slow_mo_ms=debug_options.slow_mo_ms if debug_options is not None else 0,
+                )
+
+                # Run all async operations in a single event loop to properly manage
+                # Playwright browser subprocess lifecycle
+                async def run_all_scrapes() -> List[Tuple[str, Any]]:
+                    if executor is None:
+                        return []
+                    scrape_results = []
+                    try:
+                        await executor.initialize()
+                        if run_golden and config.golden is not None:
+                            check = await check_golden_sample(executor, test_mode=job_config.test_mode)
+                            if check is not None and check.drift_suspected:
+                                aborted = config.golden.abort_on_drift
+                                message = (
+                                    f"{config.name}: selector drift suspected ({len(check.missing)}/{check.expected_fields} golden fields empty or malformed)"
+                                    + (", skipping remaining SKUs" if aborted else "")
+                                )
+                                log_buffer.append(create_log_entry("error" if aborted else "warning", message))
+                                emitter.selector_drift(config.name, check.missing_ratio, check.missing, aborted=aborted)
+                                results.setdefault("selector_drift", []).append({**check.to_dict(), "aborted": aborted})
+                                if aborted:
+                                    return scrape_results
+                        for index, sku in enumerate(skus):
+                            paused_for = await job_pause.wait_while_paused(f"{config.name}/{sku}")
+                            if paused_for:
+                                results["paused_seconds"] = round(results.get("paused_seconds", 0) + paused_for, 1)
+                            if job_pause.paused:
+                                # Only a stop request ends the wait without a resume
+                                held_back.extend(skus[index:])
+                                message = f"{config.name}: stopped while paused, holding back {len(skus) - index} SKU(s)"
+                                log_buffer.append(create_log_entry("warning", message))
+                                logger.warning(f"[Runner] {message}")
+                                results.setdefault("deferred_scrapers", []).append(
+                                    {
+                                        "scraper": config.name,
+                                        "reason": "stopped_while_paused",
+                                        "resume_after": datetime.now(timezone.utc).isoformat(),
+                                        "skus": skus[index:],
+                                    }
+                                )
+                                break
+                            if paused_for:
+                                log_buffer.append(create_log_entry("info", f"Job resumed at {config.name}/{sku} after {paused_for:.0f}s paused"))
+                            disk_paused_for = await disk_guard.guard(f"{config.name}/{sku}", disk_notice)
+                            if disk_paused_for:
+                                results["paused_seconds"] = round(results.get("paused_seconds", 0) + disk_paused_for, 1)
+                                results.setdefault("disk_pauses", []).append(
+                                    {"scraper": config.name, "sku": sku, "paused_seconds": round(disk_paused_for, 1), "gave_up": disk_guard.gave_up}
+                                )
+                            if disk_guard.gave_up:
+                                held_back.extend(skus[index:])
+                                message = f"{config.name}: disk space critically low, holding back {len(skus) - index} SKU(s)"
+                                log_buffer.append(create_log_entry("error", message))
+                                logger.error(f"[Runner] {message}")
+                                results.setdefault("deferred_scrapers", []).append(
+                                    {
+                                        "scraper": config.name,
+                                        "reason": "disk_full",
+                                        "resume_after": datetime.now(timezone.utc).isoformat(),
+                                        "skus": skus[index:],
+                                    }
+                                )
+                                break
+                            reopens_at = None if ignore_run_windows else _run_window_overrun(config)
+                            if reopens_at is not None:
+                                held_back.extend(skus[index:])
+                                message = f"{config.name}: run window closed, holding back {len(held_back)} SKU(s) until {reopens_at.isoformat()}"
+                                log_buffer.append(create_log_entry("warning", message))
+                                logger.warning(f"[Runner] {message}")
+                                results.setdefault("deferred_scrapers", []).append(
+                                    {
+                                        "scraper": config.name,
+                                        "reason": "run_window_closed",
+                                        "resume_after": reopens_at.astimezone(timezone.utc).isoformat(),
+                                        "skus": list(held_back),
+                                    }
+                                )
+                                break
                             try:
-                                progress_callback(sku, config.name, results["data"][sku][config.name])
+                                result = await executor.execute_workflow(
+                                    context={"sku": sku, "test_mode": job_config.test_mode, "interactive_auth": interactive_auth},
+                                    quit_browser=False,
+                                )
+                                scrape_results.append((sku, result))
                             except Exception as e:
-                                logger.warning(f"[Runner] Progress callback failed for {config.name}/{sku}: {e}")
-
-                        log_buffer.append(create_log_entry("info", f"{config.name}/{sku}: Found data"))
-                        emitter.info(f"{config.name}/{sku}: Found data", data=results["data"][sku][config.name])
-                        logger.info(f"[Runner] {config.name}/{sku}: Found data")
+                                log_buffer.append(create_log_entry("error", f"{config.name}/{sku}: {type(e).__name__} - {e}"))
+                                logger.error(f"[Runner] {config.name}/{sku}: Error - {e}")
+                                _record_failure(results, config.name, sku, e)
+                                scrape_results.append((sku, None))
+                                if debug_options is not None and debug_options.pause_on_error:
+                                    await _wait_at_breakpoint(config.name, sku, e)
+                    finally:
+                        # Ensure browser is properly quit inside the async context
+                        if executor.browser:
+                            try:
+                                await executor.browser.quit()
+                            except Exception as e:
+                                logger.debug(f"Browser quit error: {e}")
+                    return scrape_results
+
+                scrape_results = asyncio.run(run_all_scrapes())
+
+                # Process results after async loop completes
+                for sku, result in scrape_results:
+                    if result is None:
+                        continue
+
+                    results["skus_processed"] += 1
+
+                    if result.get("success"):
+                        extracted_data = result.get("results", {})
+
+                        if extracted_data.get("product_name") and not extracted_data.get("Name"):
+                            extracted_data["Name"] = extracted_data.pop("product_name")
+                        if extracted_data.get("price") and not extracted_data.get("Price"):
+                            extracted_data["Price"] = extracted_data.pop("price")
+                        if extracted_data.get("brand") and not extracted_data.get("Brand"):
+                            extracted_data["Brand"] = extracted_data.pop("brand")
+                        if extracted_data.get("description") and not extracted_data.get("Description"):
+                            extracted_data["Description"] = extracted_data.pop("description")
+                        if extracted_data.get("image_url") and not extracted_data.get("Images"):
+                            extracted_data["Images"] = [extracted_data.pop("image_url")]
+                        if extracted_data.get("availability") and not extracted_data.get("Availability"):
+                            extracted_data["Availability"] = extracted_data.pop("availability")
+                        has_data = any(extracted_data.get(field) for field in ["Name", "Brand", "Weight"])
+
+                        if has_data:
+                            if sku not in results["data"]:
+                                results["data"][sku] = {}
+
+                            # Handle both "Images" and "Image URLs" field names
+                            # (scraper configs use "Image URLs" as the selector name)
+                            images = extracted_data.get("Images") or extracted_data.get("Image URLs") or extracted_data.get("Image_URLs") or []
+
+                            # Capture the product page URL from the browser if not
+                            # explicitly extracted by a "URL" selector
+                            page_url = extracted_data.get("URL")
+                            if not page_url and executor and executor.browser:
+                                try:
+                                    page_url = executor.browser.current_url
+                                except Exception:
+                                    pass
+
+                            results["data"][sku][config.name] = {
+                                # Note: Price is NOT scraped - we use our own pricing
+                                "title": extracted_data.get("Name"),
+                                "brand": extracted_data.get("Brand"),
+                                "weight": extracted_data.get("Weight"),
+                                "description": extracted_data.get("Description"),
+                                "images": extracted_data.get("Image URLs", []) or extracted_data.get("Images", []),
+                                "availability": extracted_data.get("Availability"),
+                                "url": page_url,
+                                "scraped_at": datetime.now().isoformat(),
+                            }
+
+                            collector.add_result(sku, config.name, extracted_data)
+                            collected = collector.results.get(config.name, {}).get(sku)
+                            if collected and collected["data"].get("ScrapedPrice"):
+                                # Reference only, as integer cents + currency
+                                results["data"][sku][config.name]["scraped_price"] = collected["data"]["ScrapedPrice"]
+                            raw_availability = extracted_data.get("Availability")
+                            availability = normalize_availability(raw_availability, config.availability_rules())
+                            if availability is not None:
+                                results["data"][sku][config.name]["availability_status"] = availability.model_dump(
+                                    mode="json", include={"status", "quantity", "restock_date"}
+                                )
+                            elif raw_availability and str(raw_availability).strip():
+                                _record_unmapped_availability(results, config.name, str(raw_availability).strip())
+                            # The full record stays in the collector's local results
+                            results["data"][sku][config.name] = config.redact_upload_record(results["data"][sku][config.name])
+
+                            log_buffer.append(create_log_entry("info", f"{config.name}/{sku}: Found data"))
+                            emitter.info(f"{config.name}/{sku}: Found data", data=results["data"][sku][config.name])
+                            logger.info(f"[Runner] {config.name}/{sku}: Found data")
+                        else:
+                            log_buffer.append(create_log_entry("info", f"{config.name}/{sku}: No data found"))
+                            logger.info(f"[Runner] {config.name}/{sku}: No data found")
                     else:
-                        log_buffer.append(create_log_entry("info", f"{config.name}/{sku}: No data found"))
-                        logger.info(f"[Runner] {config.name}/{sku}: No data found")
-                else:
-                    log_buffer.append(create_log_entry("warning", f"{config.name}/{sku}: Workflow failed"))
-                    logger.warning(f"[Runner] {config.name}/{sku}: Workflow failed")
-
-        except Exception as e:
-            log_buffer.append(create_log_entry("error", f"Failed to initialize {config.name}: {e}"))
-            logger.error(f"[Runner] Failed to initialize {config.name}: {e}")
-
-    log_buffer.append(create_log_entry("info", f"Job complete. Processed {results['skus_processed']} SKUs"))
-    logger.info(f"[Runner] Job complete. Processed {results['skus_processed']} SKUs")
-    captured_events = event_bus.get_events(job_id=job_id, limit=2000)
-    results["logs"] = log_buffer
-    results["telemetry"] = _build_telemetry_from_events(captured_events)
-    return results
+                        log_buffer.append(create_log_entry("warning", f"{config.name}/{sku}: Workflow failed"))
+                        logger.warning(f"[Runner] {config.name}/{sku}: Workflow failed")
+                        results.setdefault("failed_skus", []).append(
+                            {"scraper": config.name, "sku": sku, "category": "workflow_failed", "error": result.get("error")}
+                        )
+
+            except Exception as e:
+                log_buffer.append(create_log_entry("error", f"Failed to initialize {config.name}: {e}"))
+                logger.error(f"[Runner] Failed to initialize {config.name}: {e}")
+                initialized = False
+                launch_failure = classify_launch_failure(e)
+                if launch_failure is not None:
+                    message = f"{config.name}: browser failed to launch ({launch_failure['category']}), try `{launch_failure['remedy']}`"
+                    if launch_failure["packages"]:
+                        message += f" or install {' '.join(launch_failure['packages'])}"
+                    log_buffer.append(create_log_entry("error", message))
+                    emitter.error(message, browser_launch=launch_failure)
+                    results.setdefault("browser_launch_failures", []).append(
+                        {"scraper": config.name, **launch_failure, "error": scrub_log_message(f"{type(e).__name__}: {e}")[0]}
+                    )
+
+            _record_block_outcome(results, config.name, clean=initialized)
+            portal_change = portal_fingerprints.pending_change(config.name) if config.requires_login() else None
+            if portal_change:
+                message = f"{config.name}: login page changed since {portal_change['detected_at']}, check the login selectors"
+                log_buffer.append(create_log_entry("warning", message))
+                results.setdefault("portal_changes", []).append(
+                    {"scraper": config.name, **{k: v for k, v in portal_change.items() if k != "fingerprint"}}
+                )
+
+            records = {sku: scrapers[config.name] for sku, scrapers in results["data"].items() if config.name in scrapers}
+            found = set(records)
+            dedup = dedupe_records(records, config.dedup_policy or "latest")
+            quarantined = {sku for conflict in dedup.conflicts if conflict["kept"] is None for sku in (conflict["sku"], conflict["duplicate_sku"])}
+            if dedup.duplicates_merged:
+                _apply_dedup(results, config.name, records, dedup)
+                message = f"{config.name}: merged {dedup.duplicates_merged} duplicate product(s), {len(dedup.conflicts)} with conflicting values"
+                log_buffer.append(create_log_entry("warning" if dedup.conflicts else "info", message))
+                logger.info(f"[Runner] {message}")
+                records = dedup.records
+            # Streamed once this scraper is done and deduplicated, so merged duplicates and flagged conflicts never reach the server
+            if progress_callback:
+                for sku, record in records.items():
+                    try:
+                        progress_callback(sku, config.name, record)
+                    except Exception as e:
+                        logger.warning(f"[Runner] Progress callback failed for {config.name}/{sku}: {e}")
+            for finding in check_results(config.name, records, config.result_checks):
+                log_buffer.append(create_log_entry("warning", f"{config.name}: result check {finding['check']} - {finding['message']}"))
+                logger.warning(f"[Runner] {config.name}: result check {finding['check']} - {finding['message']}")
+                results.setdefault("result_warnings", []).append(finding)
+            coverage = compute_coverage(set(skus) - set(held_back), found, quarantined, config.min_coverage_percent)
+            results.setdefault("coverage", {})[config.name] = coverage
+            if coverage["outcome"] != OUTCOME_SUCCESS:
+                message = f"{config.name}: {coverage['found']}/{coverage['attempted']} SKUs found ({coverage['percent']}%), outcome {coverage['outcome']}"
+                log_buffer.append(create_log_entry("warning", message))
+                logger.warning(f"[Runner] {message}")
+
+        if "coverage" in results:
+            results["outcome"] = worst_outcome([coverage["outcome"] for coverage in results["coverage"].values()])
+
+        log_buffer.append(create_log_entry("info", f"Job complete. Processed {results['skus_processed']} SKUs"))
+        logger.info(f"[Runner] Job complete. Processed {results['skus_processed']} SKUs")
+        for scraper_name, error in collector.output_fallbacks.items():
+            log_buffer.append(create_log_entry("warning", f"{scraper_name}: output directory unavailable ({error}), results kept in the default directory"))
+            results.setdefault("output_fallbacks", []).append({"scraper": scraper_name, "error": error})
+        if collector.rejected:
+            results["rejected"] = collector.rejected + results.get("rejected", [])
+        redactions = _log_redactions(log_buffer)
+        if redactions:
+            results["log_redactions"] = redactions
+        logins = _logins_since(logins_before)
+        if logins:
+            results["logins"] = logins
+        captured_events = event_bus.get_events(job_id=job_id, limit=2000)
+        _record_log_truncation(results, log_buffer)
+        results["logs"] = log_buffer
+        results["telemetry"] = _build_telemetry_from_events(captured_events)
+        return results
+    finally:
+        for config in configs:
+            log_scrubber.set_scraper_rules(config.name, {})
 
 
 def _run_discovery_job(
@@ -541,6 +1025,7 @@ def _run_discovery_job(
 
     log_buffer.append(create_log_entry("info", f"Discovery job complete. Processed {results['skus_processed']} SKUs"))
     logger.info(f"[Runner] Discovery job complete. Processed {results['skus_processed']} SKUs")
+    _record_log_truncation(results, log_buffer)
     results["logs"] = log_buffer
     results["telemetry"] = {"steps": [], "selectors": [], "extractions": []}
     return results
@@ -548,6 +1033,9 @@ def _run_discovery_job(
 
 __all__ = [
     "ConfigurationError",
+    "DEBUG_RUN_MAX_SKUS",
+    "DebugRunOptions",
+    "PreflightFailed",
     "create_emitter",
     "create_log_entry",
     "run_job",
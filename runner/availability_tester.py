@@ -0,0 +1,77 @@
+"""
+Availability mapping tester for scraper authors.
+
+Runs sample availability strings through a scraper's `availability_mappings`
+and the built-in heuristics, without opening a browser, and reports what each
+one normalizes to. Use it to check new mapping rules against the strings listed
+in a job's `unmapped_availability_strings`.
+
+Usage:
+    python -m runner.availability_tester --scraper phillips "In Stock" "Backordered til 3/15"
+
+The result is printed to stdout as a single JSON object.
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import sys
+from datetime import date
+from typing import Any
+
+from core.models import normalize_availability
+from runner.selector_tester import load_scraper_config
+from scrapers.models.config import ScraperConfig
+
+
+def test_availability_mapping(config: ScraperConfig, samples: list[str], today: date | None = None) -> dict[str, Any]:
+    """
+    Normalize each sample with the scraper's mapping rules.
+
+    Returns:
+        Dictionary with one entry per sample (status, quantity, restock_date and
+        the index of the matching rule, or "heuristic") and the samples that
+        nothing matched
+    """
+    rules = config.availability_rules()
+    results = []
+    unmapped = []
+    for sample in samples:
+        parsed = normalize_availability(sample, rules, today=today)
+        if parsed is None:
+            unmapped.append(sample)
+            results.append({"raw": sample, "status": None})
+            continue
+        results.append(
+            {
+                **parsed.model_dump(mode="json", include={"raw", "status", "quantity", "restock_date"}),
+                "matched_by": "heuristic" if parsed.matched_rule is None else f"rule {parsed.matched_rule}",
+            }
+        )
+    return {"success": True, "scraper": config.name, "results": results, "unmapped": unmapped}
+
+
+# Not a pytest test despite the name
+test_availability_mapping.__test__ = False  # type: ignore[attr-defined]
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Test availability mapping rules against sample strings")
+    parser.add_argument("--scraper", required=True, help="Scraper name or path to its YAML config")
+    parser.add_argument("samples", nargs="+", help="Raw availability strings as shown on the supplier site")
+    args = parser.parse_args()
+
+    try:
+        config = load_scraper_config(args.scraper)
+    except Exception as e:
+        result: dict[str, Any] = {"success": False, "error": str(e)}
+    else:
+        result = test_availability_mapping(config, args.samples)
+
+    print(json.dumps(result))
+    sys.exit(0 if result["success"] else 1)
+
+
+if __name__ == "__main__":
+    main()
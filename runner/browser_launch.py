@@ -0,0 +1,65 @@
+"""
+Recognise browser launch failures.
+
+"Failed to launch" says nothing about the site being scraped. It nearly always
+means Chromium is missing or damaged, or (on Linux, often after an OS upgrade)
+a shared library it needs is missing. These failures get their own entry in
+the results, so they aren't mistaken for scraper failures:
+
+    {"scraper": "phillips", "category": "missing_libraries", "error": "...",
+     "missing_libraries": ["libnss3.so"], "packages": ["libnss3"],
+     "remedy": "playwright install-deps chromium"}
+
+Categories are browser_missing, missing_libraries and launch_failed (any other
+launch error). `packages` is only filled in when Playwright's output names the
+apt packages to install.
+"""
+
+from __future__ import annotations
+
+import re
+from typing import Any
+
+LAUNCH_BROWSER_MISSING = "browser_missing"
+LAUNCH_MISSING_LIBRARIES = "missing_libraries"
+LAUNCH_FAILED = "launch_failed"
+
+# Playwright's wording when the browser binary is not installed
+BROWSER_MISSING_PATTERN = re.compile(r"Executable doesn't exist at|Looks like Playwright was just installed or updated", re.IGNORECASE)
+
+LAUNCH_FAILURE_PATTERN = re.compile(r"browserType\.launch|Failed to launch|Browser closed unexpectedly|Failed to initialize browser", re.IGNORECASE)
+MISSING_DEPENDENCIES_PATTERN = re.compile(r"missing dependencies to run browsers|error while loading shared libraries|Missing libraries", re.IGNORECASE)
+SHARED_LIBRARY_PATTERN = re.compile(r"\b(lib[\w+-]+(?:\.[\w+-]+)*\.so(?:\.\d+)*)")
+APT_PACKAGE_PATTERN = re.compile(r"^[a-z0-9][a-z0-9.+-]+$")
+
+REMEDY_INSTALL_BROWSER = "playwright install chromium"
+REMEDY_INSTALL_DEPS = "playwright install-deps chromium"
+
+
+def _apt_packages(text: str) -> list[str]:
+    """Packages from the `apt-get install ...` command Playwright suggests."""
+    _, found, rest = text.partition("apt-get install")
+    if not found:
+        return []
+    packages: list[str] = []
+    for token in rest.split():
+        token = token.strip("\\║│")
+        if not token:
+            continue
+        if not APT_PACKAGE_PATTERN.match(token):
+            break
+        packages.append(token)
+    return packages
+
+
+def classify_launch_failure(error: BaseException | str) -> dict[str, Any] | None:
+    """Category, libraries and remedy for a browser launch failure, or None if the error is something else."""
+    text = str(error)
+    if BROWSER_MISSING_PATTERN.search(text):
+        return {"category": LAUNCH_BROWSER_MISSING, "missing_libraries": [], "packages": [], "remedy": REMEDY_INSTALL_BROWSER}
+    if MISSING_DEPENDENCIES_PATTERN.search(text):
+        libraries = sorted(set(SHARED_LIBRARY_PATTERN.findall(text)))
+        return {"category": LAUNCH_MISSING_LIBRARIES, "missing_libraries": libraries, "packages": _apt_packages(text), "remedy": REMEDY_INSTALL_DEPS}
+    if LAUNCH_FAILURE_PATTERN.search(text):
+        return {"category": LAUNCH_FAILED, "missing_libraries": [], "packages": [], "remedy": REMEDY_INSTALL_BROWSER}
+    return None
@@ -5,6 +5,8 @@ import logging
 from core.api_client import JobConfig, ScraperAPIClient
 
 from runner import run_job
+from runner.golden_check import job_config_for_chunk
+from scrapers.models.config import source_config_hash
 
 logger = logging.getLogger(__name__)
 
@@ -23,6 +25,7 @@ def run_chunk_worker_mode(client: ScraperAPIClient, job_id: str, runner_name: st
 
     logger.info(f"[Chunk Worker] Loaded job config: {len(base_job_config.skus)} SKUs, {len(base_job_config.scrapers)} scrapers")
     base_scrapers_by_name = {scraper.name: scraper for scraper in base_job_config.scrapers}
+    config_hashes = {scraper.name: source_config_hash(scraper.source()) for scraper in base_job_config.scrapers}
 
     while True:
         chunk = client.claim_chunk(job_id=job_id, runner_name=runner_name)
@@ -58,7 +61,7 @@ def run_chunk_worker_mode(client: ScraperAPIClient, job_id: str, runner_name: st
 
             # Submit progress to API (fire and forget - don't block on failure)
             try:
-                client.submit_chunk_progress(chunk_id, sku, scraper_name, data)
+                client.submit_chunk_progress(chunk_id, sku, scraper_name, data, config_hash=config_hashes.get(scraper_name))
                 logger.debug(f"[Chunk Worker] Saved progress for {scraper_name}/{sku}")
                 return True
             except Exception as e:
@@ -88,7 +91,7 @@ def run_chunk_worker_mode(client: ScraperAPIClient, job_id: str, runner_name: st
                 test_mode=base_job_config.test_mode,
                 max_workers=base_job_config.max_workers,
                 job_type=base_job_config.job_type,
-                job_config=base_job_config.job_config,
+                job_config=job_config_for_chunk(base_job_config.job_config, chunk_index),
                 ai_credentials=base_job_config.ai_credentials,
                 lease_token=chunk.lease_token or base_job_config.lease_token,
                 lease_expires_at=chunk.lease_expires_at or base_job_config.lease_expires_at,
@@ -119,6 +122,43 @@ def run_chunk_worker_mode(client: ScraperAPIClient, job_id: str, runner_name: st
                 "skus_failed": skus_failed,
                 "data": partial_results,
             }
+            if results.get("deferred_scrapers"):
+                chunk_results["deferred_scrapers"] = results["deferred_scrapers"]
+            if results.get("rejected"):
+                chunk_results["rejected"] = results["rejected"]
+            if results.get("selector_drift"):
+                chunk_results["selector_drift"] = results["selector_drift"]
+            if results.get("provenance"):
+                chunk_results["provenance"] = results["provenance"]
+            if results.get("log_redactions"):
+                chunk_results["log_redactions"] = results["log_redactions"]
+            if results.get("block_cooldowns"):
+                chunk_results["block_cooldowns"] = results["block_cooldowns"]
+            if results.get("logins"):
+                chunk_results["logins"] = results["logins"]
+            if results.get("result_warnings"):
+                chunk_results["result_warnings"] = results["result_warnings"]
+            if results.get("paused_seconds"):
+                chunk_results["paused_seconds"] = results["paused_seconds"]
+            if results.get("duplicates_merged"):
+                chunk_results["duplicates_merged"] = results["duplicates_merged"]
+            if results.get("conflicts_flagged"):
+                chunk_results["conflicts_flagged"] = results["conflicts_flagged"]
+            if results.get("unmapped_availability_strings"):
+                chunk_results["unmapped_availability_strings"] = results["unmapped_availability_strings"]
+            if results.get("output_fallbacks"):
+                chunk_results["output_fallbacks"] = results["output_fallbacks"]
+            if results.get("portal_changes"):
+                chunk_results["portal_changes"] = results["portal_changes"]
+            if results.get("log_truncation"):
+                chunk_results["log_truncation"] = results["log_truncation"]
+            if results.get("coverage"):
+                chunk_results["coverage"] = results["coverage"]
+                chunk_results["outcome"] = results["outcome"]
+            if results.get("disk_pauses"):
+                chunk_results["disk_pauses"] = results["disk_pauses"]
+            if results.get("browser_launch_failures"):
+                chunk_results["browser_launch_failures"] = results["browser_launch_failures"]
 
             client.submit_chunk_results(chunk_id, "completed", results=chunk_results)
 
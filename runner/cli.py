@@ -6,9 +6,11 @@ import logging
 import os
 import sys
 
-from core.api_client import ConnectionError, ScraperAPIClient
+from core.api_client import ConnectionError, IncompatibleServerError, ScraperAPIClient
+from core.health import register_state_dump_signal
 from utils.structured_logging import setup_structured_logging
 
+from runner import DEBUG_RUN_MAX_SKUS, DebugRunOptions
 from runner.chunk_mode import run_chunk_worker_mode
 from runner.full_mode import run_full_mode
 from runner.realtime_mode import run_realtime_mode
@@ -28,17 +30,61 @@ def parse_args() -> argparse.Namespace:
         help="Execution mode: 'full', 'chunk_worker', or 'realtime'",
     )
     parser.add_argument("--debug", action="store_true", help="Enable debug logging")
+    parser.add_argument("--skip-preflight", action="store_true", help="Skip browser/credential/disk preflight checks (debugging only)")
+    parser.add_argument(
+        "--github-annotations",
+        action="store_true",
+        help="Print ::notice/::error workflow commands and write $GITHUB_STEP_SUMMARY (full mode)",
+    )
+
+    debug_run = parser.add_argument_group("debug run", "Watch the browser scrape a few SKUs. Results are never uploaded.")
+    debug_run.add_argument("--headful", action="store_true", help="Show the browser for this run (overrides HEADLESS)")
+    debug_run.add_argument("--slow-mo-ms", type=int, default=0, help="Delay between browser operations in milliseconds")
+    debug_run.add_argument("--pause-on-error", action="store_true", help="Pause at a failing SKU until Enter is pressed")
+    debug_run.add_argument(
+        "--debug-max-skus",
+        type=int,
+        default=DEBUG_RUN_MAX_SKUS,
+        help=f"Maximum SKUs processed in a debug run (default: {DEBUG_RUN_MAX_SKUS})",
+    )
     args = parser.parse_args()
 
     if args.mode in {"full", "chunk_worker"} and not args.job_id:
         parser.error("--job-id is required unless --mode realtime")
 
+    if _is_debug_run(args):
+        if args.mode != "full":
+            parser.error("--headful, --slow-mo-ms and --pause-on-error are only supported with --mode full")
+        if args.slow_mo_ms < 0:
+            parser.error("--slow-mo-ms must be >= 0")
+        if not 1 <= args.debug_max_skus <= DEBUG_RUN_MAX_SKUS:
+            parser.error(f"--debug-max-skus must be between 1 and {DEBUG_RUN_MAX_SKUS}")
+
     return args
 
 
+def _is_debug_run(args: argparse.Namespace) -> bool:
+    return bool(args.headful or args.slow_mo_ms or args.pause_on_error)
+
+
+def _debug_options_from_args(args: argparse.Namespace) -> DebugRunOptions | None:
+    if not _is_debug_run(args):
+        return None
+    return DebugRunOptions(
+        headful=args.headful,
+        slow_mo_ms=args.slow_mo_ms,
+        pause_on_error=args.pause_on_error,
+        max_skus=args.debug_max_skus,
+    )
+
+
 def main() -> None:
     args = parse_args()
     setup_structured_logging(debug=args.debug)
+    register_state_dump_signal()
+
+    if args.skip_preflight:
+        os.environ["SKIP_PREFLIGHT"] = "1"
 
     api_url = args.api_url or os.environ.get("SCRAPER_API_URL")
     if not api_url:
@@ -50,13 +96,23 @@ def main() -> None:
     logger.info(f"[Runner] Performing pre-flight health check against {api_url}")
     try:
         client.health_check()
+        client.negotiate_capabilities()
     except ConnectionError as e:
         logger.error(f"[Runner] Pre-flight health check failed: {e}")
         sys.exit(1)
+    except IncompatibleServerError as e:
+        logger.error(f"[Runner] {e}")
+        sys.exit(1)
 
     if args.mode == "realtime":
         asyncio.run(run_realtime_mode(client, args.runner_name))
     elif args.mode == "chunk_worker":
         run_chunk_worker_mode(client, args.job_id, args.runner_name)
     else:
-        run_full_mode(client, args.job_id, args.runner_name)
+        run_full_mode(
+            client,
+            args.job_id,
+            args.runner_name,
+            debug_options=_debug_options_from_args(args),
+            github_annotations=args.github_annotations,
+        )
@@ -0,0 +1,87 @@
+"""
+Collapse duplicate products within one scraper's result set.
+
+The same product can reach a result set under SKUs that differ only in case or
+whitespace, and the server rejects such duplicates inconsistently. Records are
+keyed on the normalized SKU in a single pass, holding one record per product:
+
+- identical records (ignoring scraped_at and url) collapse silently
+- conflicting records (a different price, name, ...) are resolved by the
+  scraper's `dedup_policy`: `latest` keeps the most recently scraped record,
+  `lowest_price` the cheapest one, and `flag` holds the product back from the
+  upload for review
+
+Every conflict is reported with both sets of values so nothing is lost.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import Any, Literal
+
+DedupPolicy = Literal["latest", "lowest_price", "flag"]
+
+# Fields expected to differ between copies of the same product
+_VOLATILE_FIELDS = {"scraped_at", "url"}
+
+
+def normalize_sku(sku: str) -> str:
+    return sku.strip().upper()
+
+
+def _comparable(record: dict[str, Any]) -> dict[str, Any]:
+    return {k: v for k, v in record.items() if k not in _VOLATILE_FIELDS}
+
+
+def _price_cents(record: dict[str, Any]) -> float:
+    price = record.get("scraped_price")
+    if isinstance(price, dict) and price.get("amount_cents") is not None:
+        return price["amount_cents"]
+    return float("inf")
+
+
+@dataclass
+class DedupResult:
+    """Records kept for upload, keyed by SKU, and what was merged on the way."""
+
+    records: dict[str, dict[str, Any]] = field(default_factory=dict)
+    duplicates_merged: int = 0
+    conflicts: list[dict[str, Any]] = field(default_factory=list)
+
+
+def dedupe_records(records: dict[str, dict[str, Any]], policy: DedupPolicy = "latest") -> DedupResult:
+    """Collapse records whose SKUs match once normalized."""
+    result = DedupResult()
+    kept_sku: dict[str, str] = {}
+    flagged: set[str] = set()
+
+    for sku, record in records.items():
+        key = normalize_sku(sku)
+        if key not in kept_sku:
+            kept_sku[key] = sku
+            result.records[sku] = record
+            continue
+
+        result.duplicates_merged += 1
+        current_sku = kept_sku[key]
+        current = result.records.get(current_sku)
+        if current is None or _comparable(current) == _comparable(record):
+            continue
+
+        if policy == "flag":
+            flagged.add(current_sku)
+            winner = None
+        elif policy == "lowest_price":
+            winner = sku if _price_cents(record) < _price_cents(current) else current_sku
+        else:
+            winner = sku if str(record.get("scraped_at") or "") >= str(current.get("scraped_at") or "") else current_sku
+        result.conflicts.append({"sku": current_sku, "duplicate_sku": sku, "kept": winner, "values": [current, record]})
+
+        if winner == sku:
+            del result.records[current_sku]
+            kept_sku[key] = sku
+            result.records[sku] = record
+
+    for sku in flagged:
+        result.records.pop(sku, None)
+    return result
@@ -3,22 +3,48 @@ from __future__ import annotations
 import json
 import logging
 import sys
+import time
 
 from core.api_client import ScraperAPIClient
 from core.config_fetcher import ConfigFetchError, ConfigValidationError
 from utils.structured_logging import generate_trace_id
 
-from runner import run_job
+from runner import ConfigurationError, DebugRunOptions, PreflightFailed, run_job
+from runner.github_summary import (
+    EXIT_PREFLIGHT_OR_CONFIG,
+    EXIT_UPLOAD_FAILED,
+    outcome_exit_code,
+    report_github_error,
+    report_github_outcome,
+)
 
 logger = logging.getLogger(__name__)
 
 
-def run_full_mode(client: ScraperAPIClient, job_id: str, runner_name: str) -> None:
+def run_full_mode(
+    client: ScraperAPIClient,
+    job_id: str,
+    runner_name: str,
+    debug_options: DebugRunOptions | None = None,
+    github_annotations: bool = False,
+) -> None:
+    """Run a whole job and submit its results.
+
+    Exits 0 on success, 2 when some SKUs failed, 3 when a supplier blocked the
+    run, 4 on preflight or configuration errors and 5 when the results could
+    not be uploaded.
+    """
     trace_id = generate_trace_id()
+    started = time.monotonic()
     logger.info(
         f"[Full Mode] Starting job {job_id}",
         extra={"job_id": job_id, "trace_id": trace_id, "runner_name": runner_name},
     )
+
+    if debug_options is not None:
+        _run_debug_job(client, job_id, runner_name, debug_options)
+        return
+
     client.update_status(job_id, "running", runner_name=runner_name)
 
     job_config = client.get_job_config(job_id)
@@ -42,14 +68,58 @@ def run_full_mode(client: ScraperAPIClient, job_id: str, runner_name: str) -> No
 
     try:
         results = run_job(job_config, runner_name=runner_name)
-        client.submit_results(
+
+        def upload_progress(done: int, total: int) -> None:
+            logger.info(f"[Full Mode] Uploaded chunk {done}/{total}", extra={"job_id": job_id, "trace_id": trace_id})
+
+        uploaded = client.submit_results_chunked(
             job_id,
-            "completed",
+            results,
             runner_name=runner_name,
             lease_token=job_config.lease_token,
-            results=results,
+            progress_callback=upload_progress,
         )
+        if not uploaded:
+            logger.error(
+                "[Full Mode] Failed to upload results",
+                extra={
+                    "job_id": job_id,
+                    "trace_id": trace_id,
+                    "runner_name": runner_name,
+                    "error_type": "UploadFailed",
+                },
+            )
+            if github_annotations:
+                report_github_error("Upload failed", f"Results for job {job_id} were not accepted by the coordinator")
+            sys.exit(EXIT_UPLOAD_FAILED)
         print(json.dumps(results, indent=2))
+
+        if github_annotations:
+            report_github_outcome(job_id, results, time.monotonic() - started)
+        exit_code = outcome_exit_code(results)
+        if exit_code:
+            sys.exit(exit_code)
+    except PreflightFailed as e:
+        logger.error(
+            f"[Full Mode] {e}",
+            extra={
+                "job_id": job_id,
+                "trace_id": trace_id,
+                "runner_name": runner_name,
+                "error_type": "PreflightFailed",
+                "preflight": e.to_dict(),
+            },
+        )
+        client.submit_results(
+            job_id,
+            "failed",
+            runner_name=runner_name,
+            lease_token=job_config.lease_token,
+            error_message=str(e),
+        )
+        if github_annotations:
+            report_github_error("Preflight failed", str(e))
+        sys.exit(EXIT_PREFLIGHT_OR_CONFIG)
     except ConfigValidationError as e:
         logger.error(
             f"[Full Mode] Config validation failed: {e}",
@@ -69,7 +139,9 @@ def run_full_mode(client: ScraperAPIClient, job_id: str, runner_name: str) -> No
             lease_token=job_config.lease_token,
             error_message=f"Config validation failed for {e.config_slug}: {e}",
         )
-        sys.exit(1)
+        if github_annotations:
+            report_github_error("Config validation failed", f"{e.config_slug}: {e}")
+        sys.exit(EXIT_PREFLIGHT_OR_CONFIG)
     except ConfigFetchError as e:
         logger.error(
             f"[Full Mode] Config fetch failed: {e}",
@@ -88,7 +160,29 @@ def run_full_mode(client: ScraperAPIClient, job_id: str, runner_name: str) -> No
             lease_token=job_config.lease_token,
             error_message=f"Config fetch failed: {e}",
         )
-        sys.exit(1)
+        if github_annotations:
+            report_github_error("Config fetch failed", str(e))
+        sys.exit(EXIT_PREFLIGHT_OR_CONFIG)
+    except ConfigurationError as e:
+        logger.error(
+            f"[Full Mode] {e}",
+            extra={
+                "job_id": job_id,
+                "trace_id": trace_id,
+                "runner_name": runner_name,
+                "error_type": "ConfigurationError",
+            },
+        )
+        client.submit_results(
+            job_id,
+            "failed",
+            runner_name=runner_name,
+            lease_token=job_config.lease_token,
+            error_message=str(e),
+        )
+        if github_annotations:
+            report_github_error("Configuration error", str(e))
+        sys.exit(EXIT_PREFLIGHT_OR_CONFIG)
     except Exception as e:
         logger.exception(
             "Job failed with error",
@@ -106,4 +200,23 @@ def run_full_mode(client: ScraperAPIClient, job_id: str, runner_name: str) -> No
             lease_token=job_config.lease_token,
             error_message=str(e),
         )
+        if github_annotations:
+            report_github_error("Job failed", f"{type(e).__name__}: {e}")
         sys.exit(1)
+
+
+def _run_debug_job(client: ScraperAPIClient, job_id: str, runner_name: str, debug_options: DebugRunOptions) -> None:
+    """Run a headful debug job locally. Nothing is reported back to the coordinator."""
+    logger.warning(
+        f"[Full Mode] Debug run for job {job_id}: headful={debug_options.headful}, "
+        f"slow_mo_ms={debug_options.slow_mo_ms}, pause_on_error={debug_options.pause_on_error}. Results will not be submitted.",
+        extra={"job_id": job_id, "runner_name": runner_name},
+    )
+
+    job_config = client.get_job_config(job_id)
+    if not job_config:
+        logger.error("Failed to fetch job config", extra={"job_id": job_id, "runner_name": runner_name})
+        sys.exit(1)
+
+    results = run_job(job_config, runner_name=runner_name, debug_options=debug_options)
+    print(json.dumps(results, indent=2))
@@ -0,0 +1,149 @@
+"""
+Run outcome reporting for CI.
+
+Maps a finished job to a differentiated process exit code and, when running
+inside GitHub Actions, emits ::notice/::error workflow commands and a Markdown
+job summary so workflows don't have to parse free-form logs.
+"""
+
+from __future__ import annotations
+
+import logging
+import os
+from collections import Counter
+from pathlib import Path
+from typing import Any
+
+from core.block_cooldown import BLOCKED_CATEGORIES
+
+logger = logging.getLogger(__name__)
+
+EXIT_SUCCESS = 0
+EXIT_PARTIAL_FAILURE = 2
+EXIT_BLOCKED = 3
+EXIT_PREFLIGHT_OR_CONFIG = 4
+EXIT_UPLOAD_FAILED = 5
+
+MAX_ERROR_ANNOTATIONS = 10  # GitHub displays at most 10 error annotations per step
+
+
+def outcome_exit_code(results: dict[str, Any]) -> int:
+    """Exit code for a completed run: 0 success, 2 partial failures, low coverage or aborted on drift, 3 blocked."""
+    failed = results.get("failed_skus") or []
+    if any(f.get("category") in BLOCKED_CATEGORIES for f in failed):
+        return EXIT_BLOCKED
+    if failed or results.get("outcome") == "failed" or any(d.get("aborted") for d in results.get("selector_drift") or []):
+        return EXIT_PARTIAL_FAILURE
+    return EXIT_SUCCESS
+
+
+def _escape(value: str) -> str:
+    """Escape data for a workflow command (see GitHub's workflow command syntax)."""
+    return value.replace("%", "%25").replace("\r", "%0D").replace("\n", "%0A")
+
+
+def _escape_property(value: str) -> str:
+    """Escape a workflow command property such as title=."""
+    return _escape(value).replace(":", "%3A").replace(",", "%2C")
+
+
+def _products_found(results: dict[str, Any]) -> int:
+    return sum(len(scrapers) for scrapers in (results.get("data") or {}).values())
+
+
+def format_annotations(job_id: str, results: dict[str, Any], duration_seconds: float) -> list[str]:
+    """Workflow command lines summarizing the run."""
+    failed = results.get("failed_skus") or []
+    exit_code = outcome_exit_code(results)
+
+    lines = [
+        f"::notice title={_escape_property(f'Scrape job {job_id}')}::"
+        + _escape(
+            f"{_products_found(results)} products found across {results.get('skus_processed', 0)} SKUs, "
+            f"{len(failed)} failed, in {duration_seconds:.0f}s"
+        )
+    ]
+    if exit_code == EXIT_BLOCKED:
+        blocked = sorted({f["scraper"] for f in failed if f.get("category") in BLOCKED_CATEGORIES})
+        lines.append(f"::error title=Blocked::{_escape('Blocked by ' + ', '.join(blocked))}")
+    for drift in results.get("selector_drift") or []:
+        lines.append(f"::warning title=Selector drift::{_escape(drift['scraper'] + ': golden sample fields empty or malformed')}")
+    for warning in results.get("result_warnings") or []:
+        lines.append(f"::warning title=Result check::{_escape(warning['scraper'] + ': ' + warning['message'])}")
+    for failure in failed[:MAX_ERROR_ANNOTATIONS]:
+        message = f"{failure['scraper']}/{failure['sku']}: {failure.get('category')} - {failure.get('error') or 'workflow failed'}"
+        lines.append(f"::error title=Failed SKU::{_escape(message)}")
+    if len(failed) > MAX_ERROR_ANNOTATIONS:
+        lines.append(f"::error title=Failed SKUs::{len(failed) - MAX_ERROR_ANNOTATIONS} more failures not shown")
+    return lines
+
+
+def format_step_summary(job_id: str, results: dict[str, Any], duration_seconds: float) -> str:
+    """Markdown job summary with the per-category error table."""
+    failed = results.get("failed_skus") or []
+    outcome = {
+        EXIT_SUCCESS: "Success",
+        EXIT_PARTIAL_FAILURE: "Partial failure",
+        EXIT_BLOCKED: "Blocked",
+    }[outcome_exit_code(results)]
+    warnings = results.get("result_warnings") or []
+    if warnings and outcome == "Success":
+        outcome = "Completed with warnings"
+
+    lines = [
+        f"## Scrape job `{job_id}`: {outcome}",
+        "",
+        "| Metric | Value |",
+        "| --- | --- |",
+        f"| Products found | {_products_found(results)} |",
+        f"| SKUs processed | {results.get('skus_processed', 0)} |",
+        f"| Failed SKUs | {len(failed)} |",
+        f"| Scrapers | {', '.join(results.get('scrapers_run') or []) or '-'} |",
+        f"| Duration | {duration_seconds:.0f}s |",
+    ]
+    coverage = results.get("coverage") or {}
+    if coverage:
+        lines += [f"| Coverage ({scraper}) | {c['found']}/{c['attempted']} ({c['percent']}%), {c['outcome']} |" for scraper, c in sorted(coverage.items())]
+
+    if failed:
+        by_category = Counter((f["scraper"], f.get("category") or "unknown") for f in failed)
+        lines += ["", "### Errors by category", "", "| Scraper | Category | Count |", "| --- | --- | --- |"]
+        lines += [f"| {scraper} | {category} | {count} |" for (scraper, category), count in sorted(by_category.items())]
+
+    drift = results.get("selector_drift") or []
+    if drift:
+        lines += ["", "### Selector drift suspected", ""]
+        lines += [
+            f"- {d['scraper']}: {len(d['missing'])}/{d['expected_fields']} golden fields empty or malformed" + (" (aborted)" if d.get("aborted") else "")
+            for d in drift
+        ]
+
+    if warnings:
+        lines += ["", "### Result checks", ""]
+        lines += [f"- {w['scraper']}: {w['message']}" for w in warnings]
+
+    deferred = results.get("deferred_scrapers") or []
+    if deferred:
+        lines += ["", "### Deferred", ""]
+        lines += [f"- {d['scraper']}: {d['reason']} (resume after {d['resume_after']})" for d in deferred]
+
+    return "\n".join(lines) + "\n"
+
+
+def report_github_outcome(job_id: str, results: dict[str, Any], duration_seconds: float) -> None:
+    """Print annotations and append the job summary to $GITHUB_STEP_SUMMARY if set."""
+    for line in format_annotations(job_id, results, duration_seconds):
+        print(line, flush=True)
+
+    summary_path = os.environ.get("GITHUB_STEP_SUMMARY")
+    if summary_path:
+        try:
+            with open(Path(summary_path), "a", encoding="utf-8") as f:
+                f.write(format_step_summary(job_id, results, duration_seconds))
+        except OSError as e:
+            logger.warning(f"[Runner] Could not write GitHub step summary to {summary_path}: {e}")
+
+
+def report_github_error(title: str, message: str) -> None:
+    """Print a single ::error annotation for runs that never produced results."""
+    print(f"::error title={_escape_property(title)}::{_escape(message)}", flush=True)
@@ -0,0 +1,231 @@
+"""
+Golden-sample check for selector drift.
+
+Supplier redesigns rarely make a scraper fail outright; instead names come back
+empty or prices stop parsing. Each scraper can carry a small curated set of
+SKUs (`golden` in its config) that are known to exist. They are scraped at the
+start of every full job (the first chunk of a chunked one, see
+job_config_for_chunk) and compared on field presence - if too many expected
+fields come back empty or malformed the run is flagged "selector drift
+suspected", and optionally aborted before the real SKU list is worked through.
+
+Usage:
+    python -m runner.golden_check --scraper phillips
+"""
+
+from __future__ import annotations
+
+import argparse
+import asyncio
+import contextlib
+import json
+import logging
+import re
+import sys
+from dataclasses import asdict, dataclass, field
+from pathlib import Path
+from typing import Any
+
+import yaml
+
+from core.models import PriceParseError, parse_price
+from scrapers.models.config import GoldenSampleConfig, ScraperConfig
+
+logger = logging.getLogger(__name__)
+
+GOLDEN_CHECK_TIMEOUT = 300  # seconds for the whole sample, including browser startup
+PLACEHOLDER_VALUES = {"n/a", "na", "null", "none", "undefined", "-"}
+PRICE_FIELDS = {"Price", "price"}
+IMAGE_FIELDS = {"Images", "Image URLs", "Image_URLs", "image_url"}
+
+
+@dataclass
+class GoldenCheckResult:
+    """Outcome of scraping one scraper's golden sample."""
+
+    scraper: str
+    checked_skus: int
+    expected_fields: int
+    missing: list[dict[str, str]] = field(default_factory=list)
+    max_missing_ratio: float = 0.25
+
+    @property
+    def missing_ratio(self) -> float:
+        return len(self.missing) / self.expected_fields if self.expected_fields else 0.0
+
+    @property
+    def drift_suspected(self) -> bool:
+        return self.missing_ratio > self.max_missing_ratio
+
+    def to_dict(self) -> dict[str, Any]:
+        return {**asdict(self), "missing_ratio": round(self.missing_ratio, 3), "drift_suspected": self.drift_suspected}
+
+
+def field_problem(name: str, value: Any) -> str | None:
+    """Why a field value doesn't count as present, or None if it's fine."""
+    if value is None or (isinstance(value, str | list | dict) and not value):
+        return "empty"
+    if isinstance(value, str):
+        if not value.strip():
+            return "empty"
+        if value.strip().lower() in PLACEHOLDER_VALUES:
+            return "placeholder"
+    if name in PRICE_FIELDS:
+        try:
+            parse_price(value)
+        except PriceParseError:
+            return "malformed"
+    if name in IMAGE_FIELDS:
+        urls = value if isinstance(value, list) else [value]
+        if not any(isinstance(url, str) and url.startswith(("http://", "https://", "//")) for url in urls):
+            return "malformed"
+    return None
+
+
+def evaluate_golden_results(scraper: str, golden: GoldenSampleConfig, results: dict[str, dict[str, Any] | None]) -> GoldenCheckResult:
+    """Compare scraped golden SKUs against the expected field presence.
+
+    Args:
+        scraper: Scraper name, for reporting
+        golden: The scraper's golden sample
+        results: Extracted data per golden SKU, or None if its workflow failed
+    """
+    check = GoldenCheckResult(
+        scraper=scraper,
+        checked_skus=len(golden.skus),
+        expected_fields=len(golden.skus) * len(golden.expected_fields),
+        max_missing_ratio=golden.max_missing_ratio,
+    )
+    for sku in golden.skus:
+        data = results.get(sku)
+        for name in golden.expected_fields:
+            problem = "workflow_failed" if data is None else field_problem(name, data.get(name))
+            if problem:
+                check.missing.append({"sku": sku, "field": name, "problem": problem})
+    return check
+
+
+async def check_golden_sample(executor: Any, test_mode: bool = False) -> GoldenCheckResult | None:
+    """Scrape the golden SKUs with an initialized executor, or None if the scraper has none."""
+    config = executor.config
+    golden = config.golden
+    if golden is None:
+        return None
+
+    results: dict[str, dict[str, Any] | None] = {}
+    for sku in golden.skus:
+        try:
+            result = await executor.execute_workflow(context={"sku": sku, "test_mode": test_mode}, quit_browser=False)
+        except Exception as e:
+            logger.warning(f"[Runner] {config.name}: golden SKU {sku} failed - {type(e).__name__}: {e}")
+            results[sku] = None
+            continue
+        results[sku] = result.get("results", {}) if result.get("success") else None
+
+    check = evaluate_golden_results(config.name, golden, results)
+    if check.drift_suspected:
+        logger.warning(
+            f"[Runner] {config.name}: selector drift suspected - {len(check.missing)}/{check.expected_fields} "
+            f"expected golden fields empty or malformed"
+        )
+    else:
+        logger.info(f"[Runner] {config.name}: golden sample passed ({len(check.missing)}/{check.expected_fields} fields missing)")
+    return check
+
+
+async def run_golden_check(config: ScraperConfig, headless: bool = True, timeout: float = GOLDEN_CHECK_TIMEOUT) -> dict[str, Any]:
+    """Run one scraper's golden sample on its own browser, outside a job."""
+    from scrapers.executor.workflow_executor import WorkflowExecutor
+
+    if config.golden is None:
+        return {"success": False, "error": f"{config.name} has no golden sample configured"}
+
+    executor = WorkflowExecutor(config, headless=headless)
+
+    async def _run() -> GoldenCheckResult | None:
+        await executor.initialize()
+        return await check_golden_sample(executor)
+
+    try:
+        check = await asyncio.wait_for(_run(), timeout=timeout)
+        return {"success": True, **check.to_dict()} if check else {"success": False, "error": "No golden sample"}
+    except asyncio.TimeoutError:
+        return {"success": False, "error": f"Golden check timed out after {timeout:.0f}s"}
+    except Exception as e:
+        logger.error(f"[Runner] Golden check for {config.name} failed: {type(e).__name__} - {e}")
+        return {"success": False, "error": f"{type(e).__name__}: {e}"}
+    finally:
+        if executor.browser:
+            try:
+                await executor.browser.quit()
+            except Exception as e:
+                logger.debug(f"Browser quit error: {e}")
+
+
+def _replace_top_level_block(text: str, key: str, block: str) -> str:
+    """Swap a top-level YAML mapping entry (the key line and everything indented under it) for block, or append it."""
+    lines = text.splitlines(keepends=True)
+    start = next((i for i, line in enumerate(lines) if re.match(rf"{re.escape(key)}\s*:", line)), None)
+    if start is None:
+        return text + ("\n" if text and not text.endswith("\n") else "") + block
+    end = start + 1
+    while end < len(lines) and (lines[end][:1] in (" ", "\t") or not lines[end].strip()):
+        end += 1
+    # Leave blank lines after the block in place
+    while end > start + 1 and not lines[end - 1].strip():
+        end -= 1
+    return "".join(lines[:start]) + block + "".join(lines[end:])
+
+
+def job_config_for_chunk(job_config: dict[str, Any] | None, chunk_index: int) -> dict[str, Any] | None:
+    """A chunk's job_config. Only the first chunk runs the golden check, so a job checks once, not per chunk."""
+    if chunk_index == 0:
+        return job_config
+    return {**(job_config or {}), "skip_golden_check": True}
+
+
+def set_golden_skus(config_path: Path, skus: list[str]) -> GoldenSampleConfig:
+    """Replace the golden SKUs in a scraper's YAML config, keeping its other golden settings.
+
+    Raises:
+        ValueError: If the updated config no longer validates.
+    """
+    text = config_path.read_text(encoding="utf-8")
+    data = yaml.safe_load(text) or {}
+    data["golden"] = {**(data.get("golden") or {}), "skus": [sku.strip() for sku in skus if sku.strip()]}
+
+    # Validate before touching the file so a bad edit can't break the scraper
+    config = ScraperConfig(**data)
+    # Rewrite only the golden block, so comments and layout elsewhere in the file survive
+    block = yaml.safe_dump({"golden": data["golden"]}, sort_keys=False, allow_unicode=True)
+    config_path.write_text(_replace_top_level_block(text, "golden", block), encoding="utf-8")
+    logger.info(f"[Runner] Updated golden sample for {config.name}: {len(config.golden.skus)} SKU(s)")
+    return config.golden
+
+
+def main() -> None:
+    from runner.selector_tester import load_scraper_config
+
+    parser = argparse.ArgumentParser(description="Run a scraper's golden sample to check for selector drift")
+    parser.add_argument("--scraper", required=True, help="Scraper name or path to its YAML config")
+    parser.add_argument("--headful", action="store_true", help="Show the browser")
+    parser.add_argument("--timeout", type=float, default=GOLDEN_CHECK_TIMEOUT, help=f"Seconds before giving up (default: {GOLDEN_CHECK_TIMEOUT})")
+    args = parser.parse_args()
+
+    logging.basicConfig(level=logging.INFO, stream=sys.stderr)
+
+    # Keep stdout clean for the JSON result
+    with contextlib.redirect_stdout(sys.stderr):
+        try:
+            config = load_scraper_config(args.scraper)
+        except Exception as e:
+            result: dict[str, Any] = {"success": False, "error": str(e)}
+        else:
+            result = asyncio.run(run_golden_check(config, headless=not args.headful, timeout=args.timeout))
+
+    print(json.dumps(result))
+    sys.exit(0 if result["success"] and not result.get("drift_suspected") else 1)
+
+
+if __name__ == "__main__":
+    main()
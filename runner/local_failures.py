@@ -0,0 +1,83 @@
+"""
+Tell this runner's own problems apart from scraper and site failures.
+
+A chunk that fails because Chromium or a library it needs is missing, or the
+disk is full, would run fine on another runner, so the daemon releases it back
+to the coordinator's queue instead of failing it for the whole fleet.
+Everything else is reported as a normal failure.
+
+run_job doesn't raise when a scraper's browser fails to launch; it records the
+failure in results["browser_launch_failures"] and carries on with the other
+scrapers. When that covers every scraper in the run, check_browser_launches
+turns the results into a BrowserLaunchFailed so the chunk isn't reported as
+completed with nothing scraped. Likewise a job that gave up waiting for disk
+space (see core.disk_guard) holds back its remaining SKUs, and
+check_disk_space turns that into a DiskSpaceExhausted so the chunk is released.
+
+When in doubt a failure is treated as the scraper's or site's. Releasing a
+chunk that fails everywhere would bounce it between runners forever, while
+failing a chunk another runner could have done only costs a retry.
+"""
+
+from __future__ import annotations
+
+import errno
+from typing import Any
+
+from runner.browser_launch import LAUNCH_BROWSER_MISSING, LAUNCH_MISSING_LIBRARIES, classify_launch_failure
+from runner.preflight import PreflightFailed
+
+# Preflight requirements that depend only on this machine; missing credentials come from the job itself
+LOCAL_PREFLIGHT_REQUIREMENTS = {"browser", "disk_space"}
+
+LOCAL_OS_ERRNOS = {errno.ENOSPC, getattr(errno, "EDQUOT", errno.ENOSPC)}
+
+LOCAL_LAUNCH_CATEGORIES = {LAUNCH_BROWSER_MISSING, LAUNCH_MISSING_LIBRARIES}
+
+
+class BrowserLaunchFailed(RuntimeError):
+    """Every scraper in a run failed to launch its browser."""
+
+    def __init__(self, failures: list[dict[str, Any]]) -> None:
+        self.failures = failures
+        self.categories = {failure["category"] for failure in failures}
+        super().__init__(f"Browser failed to launch for every scraper ({', '.join(sorted(self.categories))})")
+
+
+def check_browser_launches(results: dict[str, Any]) -> None:
+    """Raise BrowserLaunchFailed if no scraper in the run got a browser."""
+    failures = results.get("browser_launch_failures") or []
+    scrapers = set(results.get("scrapers_run") or [])
+    if scrapers and scrapers <= {failure["scraper"] for failure in failures}:
+        raise BrowserLaunchFailed(failures)
+
+
+class DiskSpaceExhausted(OSError):
+    """A job stopped because free disk space stayed critically low."""
+
+    def __init__(self, scrapers: list[str]) -> None:
+        super().__init__(errno.ENOSPC, f"Disk space stayed critically low; held back SKUs for {', '.join(scrapers)}")
+
+
+def check_disk_space(results: dict[str, Any]) -> None:
+    """Raise DiskSpaceExhausted if the run held back SKUs for lack of disk space."""
+    scrapers = [deferred["scraper"] for deferred in results.get("deferred_scrapers") or [] if deferred.get("reason") == "disk_full"]
+    if scrapers:
+        raise DiskSpaceExhausted(scrapers)
+
+
+def local_failure_reason(error: BaseException) -> str | None:
+    """Why the failure is this runner's own, or None if it belongs to the scraper or site."""
+    if isinstance(error, PreflightFailed):
+        requirements = {issue.requirement for issue in error.issues}
+        if requirements and requirements <= LOCAL_PREFLIGHT_REQUIREMENTS:
+            return "preflight: " + ", ".join(sorted(requirements))
+        return None
+    if isinstance(error, BrowserLaunchFailed):
+        return ", ".join(sorted(error.categories)) if error.categories <= LOCAL_LAUNCH_CATEGORIES else None
+    if isinstance(error, OSError) and error.errno in LOCAL_OS_ERRNOS:
+        return "disk_full"
+    launch = classify_launch_failure(error)
+    if launch is not None and launch["category"] in LOCAL_LAUNCH_CATEGORIES:
+        return launch["category"]
+    return None
@@ -0,0 +1,153 @@
+"""
+Preflight checks run before a job starts scraping.
+
+Catches problems that would otherwise surface minutes into a job as an opaque
+Playwright error: a missing browser, missing supplier credentials, or a full
+disk. Every unmet requirement is collected so the caller can fix them all at
+once, each with a remediation action the UI can link to.
+
+Every issue also carries a stable message code and its parameters, so the UI
+can render the message from its own catalog in the user's language; the
+English `message` stays for logs and older clients.
+"""
+
+from __future__ import annotations
+
+import logging
+import os
+from dataclasses import asdict, dataclass, field
+from pathlib import Path
+from typing import Any
+
+from core.api_client import JobConfig
+from core.health import runner_health
+from core.version_info import has_chromium, resolve_browsers_dir
+
+logger = logging.getLogger(__name__)
+
+# Remediation actions understood by the frontend
+ACTION_INSTALL_BROWSER = "install_browser"
+ACTION_ADD_CREDENTIALS = "add_credentials"
+ACTION_FREE_DISK = "free_disk_space"
+
+# Message codes, one per kind of issue; the desktop app's catalog has an entry for each
+CODE_MISSING_BROWSER = "preflight.missing_browser"
+CODE_MISSING_BROWSER_REVISION = "preflight.missing_browser_revision"
+CODE_MISSING_CREDENTIALS = "preflight.missing_credentials"
+CODE_LOW_DISK_SPACE = "preflight.low_disk_space"
+MESSAGE_CODES = (CODE_MISSING_BROWSER, CODE_MISSING_BROWSER_REVISION, CODE_MISSING_CREDENTIALS, CODE_LOW_DISK_SPACE)
+
+
+@dataclass
+class PreflightIssue:
+    """A single unmet requirement."""
+
+    requirement: str
+    message: str
+    action: str
+    scraper: str | None = None
+    code: str = ""
+    params: dict[str, Any] = field(default_factory=dict)
+
+
+class PreflightFailed(Exception):
+    """Raised when one or more preflight requirements are not met."""
+
+    def __init__(self, issues: list[PreflightIssue]):
+        self.issues = issues
+        details = "; ".join(f"{i.scraper + ': ' if i.scraper else ''}{i.message}" for i in issues)
+        super().__init__(f"Preflight failed ({len(issues)} issue(s)): {details}")
+
+    def to_dict(self) -> dict[str, Any]:
+        return {"error": "preflight_failed", "issues": [asdict(i) for i in self.issues]}
+
+
+def preflight_skipped(job_config: JobConfig) -> bool:
+    """Whether preflight was disabled for this job (debugging only)."""
+    if os.environ.get("SKIP_PREFLIGHT", "").lower() in ("1", "true"):
+        return True
+    return bool((job_config.job_config or {}).get("skip_preflight"))
+
+
+def _check_browser(config: Any) -> PreflightIssue | None:
+    revision = getattr(config, "browser_revision", None)
+    if revision:
+        from utils.scraping.playwright_browser import BROWSER_REVISIONS_DIR_ENV
+
+        root = os.environ.get(BROWSER_REVISIONS_DIR_ENV)
+        if root and not (Path(root) / revision).is_dir():
+            return PreflightIssue(
+                requirement="browser",
+                message=f"Pinned browser revision '{revision}' is not installed",
+                action=ACTION_INSTALL_BROWSER,
+                scraper=config.name,
+                code=CODE_MISSING_BROWSER_REVISION,
+                params={"revision": revision},
+            )
+        if root:
+            return None
+
+    browsers_path = resolve_browsers_dir().path
+    if browsers_path is not None and not has_chromium(browsers_path):
+        return PreflightIssue(
+            requirement="browser",
+            message=f"Chromium is not installed under {browsers_path}",
+            action=ACTION_INSTALL_BROWSER,
+            scraper=config.name,
+            code=CODE_MISSING_BROWSER,
+            params={"browser": "chromium", "path": str(browsers_path)},
+        )
+    return None
+
+
+def _has_inline_credentials(config: Any) -> bool:
+    """Whether a workflow step already carries its own username and password."""
+    return any(step.params.get("username") and step.params.get("password") for step in config.workflows)
+
+
+def run_preflight(job_config: JobConfig, configs: list[Any]) -> None:
+    """Verify everything the job needs is in place.
+
+    Args:
+        job_config: The job being started (used for injected credentials)
+        configs: Parsed ScraperConfig objects for the scrapers that will run
+
+    Raises:
+        PreflightFailed: Listing every unmet requirement.
+    """
+    issues: list[PreflightIssue] = []
+    options_by_name = {s.name: s.options or {} for s in job_config.scrapers}
+
+    for config in configs:
+        browser_issue = _check_browser(config)
+        if browser_issue:
+            issues.append(browser_issue)
+
+        has_credentials = bool(options_by_name.get(config.name, {}).get("_credentials")) or _has_inline_credentials(config)
+        if config.requires_login() and not has_credentials:
+            issues.append(
+                PreflightIssue(
+                    requirement="credentials",
+                    message="Supplier credentials are required but none were provided",
+                    action=ACTION_ADD_CREDENTIALS,
+                    scraper=config.name,
+                    code=CODE_MISSING_CREDENTIALS,
+                    params={"scraper": config.name},
+                )
+            )
+
+    free_mb = runner_health.free_disk_mb()
+    if free_mb is not None and free_mb < runner_health.min_free_disk_mb:
+        issues.append(
+            PreflightIssue(
+                requirement="disk_space",
+                message=f"Only {free_mb:.0f}MB free, need at least {runner_health.min_free_disk_mb}MB for results",
+                action=ACTION_FREE_DISK,
+                code=CODE_LOW_DISK_SPACE,
+                params={"free_mb": round(free_mb), "min_free_mb": runner_health.min_free_disk_mb},
+            )
+        )
+
+    if issues:
+        raise PreflightFailed(issues)
+    logger.info(f"[Runner] Preflight passed for {len(configs)} scraper(s)")
@@ -0,0 +1,125 @@
+"""
+Quick price and stock check for a handful of SKUs.
+
+Buyers at the counter want "what's this item right now" in seconds, not a
+queued job. A quick check scrapes at most five SKUs with one scraper and
+returns the parsed records inline with per-SKU timing. Nothing is written to
+disk or uploaded.
+
+It goes through the normal WorkflowExecutor, so the scraper's pacing applies
+and a cached login session (see core.session_cache) is reused when there is
+one. Scrapers in a block cooldown are refused rather than hammered again.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+import time
+from typing import Any
+
+from core.block_cooldown import block_cooldowns
+from core.models import PriceParseError, normalize_availability, parse_price
+from scrapers.models.config import ScraperConfig
+
+logger = logging.getLogger(__name__)
+
+QUICK_CHECK_MAX_SKUS = 5
+QUICK_CHECK_TIMEOUT = 30  # seconds, including browser startup and login
+
+
+class QuickCheckRefused(Exception):
+    """Raised when a quick check can't run right now. Carries a structured error for the caller."""
+
+    def __init__(self, error: str, message: str, **details: Any) -> None:
+        self.error = error
+        self.details = details
+        super().__init__(message)
+
+    def to_dict(self) -> dict[str, Any]:
+        return {"error": self.error, "message": str(self), **self.details}
+
+
+def _record(config: ScraperConfig, extracted: dict[str, Any]) -> dict[str, Any]:
+    record: dict[str, Any] = {
+        "title": extracted.get("Name") or extracted.get("product_name"),
+        "brand": extracted.get("Brand"),
+        "weight": extracted.get("Weight"),
+        "images": extracted.get("Image URLs", []) or extracted.get("Images", []),
+        "availability": extracted.get("Availability"),
+    }
+    try:
+        price = parse_price(extracted.get("Price"))
+    except PriceParseError as e:
+        record["price_error"] = e.reason
+    else:
+        if price is not None:
+            record["scraped_price"] = price.model_dump(mode="json", exclude={"raw"})
+    availability = normalize_availability(record["availability"], config.availability_rules())
+    if availability is not None:
+        record["availability_status"] = availability.model_dump(mode="json", include={"status", "quantity", "restock_date"})
+    return config.redact_upload_record(record)
+
+
+async def quick_check(
+    config: ScraperConfig,
+    skus: list[str],
+    headless: bool = True,
+    timeout: float = QUICK_CHECK_TIMEOUT,
+) -> dict[str, Any]:
+    """
+    Scrape up to QUICK_CHECK_MAX_SKUS SKUs and return their records inline.
+
+    Raises:
+        QuickCheckRefused: Too many SKUs, or the scraper is in a block cooldown
+    """
+    from scrapers.executor.workflow_executor import WorkflowExecutor
+
+    if not skus or len(skus) > QUICK_CHECK_MAX_SKUS:
+        raise QuickCheckRefused("too_many_skus", f"A quick check takes 1 to {QUICK_CHECK_MAX_SKUS} SKUs", max_skus=QUICK_CHECK_MAX_SKUS)
+    cooldown_end = block_cooldowns.cooldown_until(config.name)
+    if cooldown_end is not None:
+        raise QuickCheckRefused("block_cooldown", f"{config.name} is in block cooldown", resume_after=cooldown_end.isoformat())
+
+    started = time.monotonic()
+    results: list[dict[str, Any]] = []
+    executor = WorkflowExecutor(config, headless=headless, timeout=30, worker_id="QuickCheck")
+
+    async def _run() -> None:
+        await executor.initialize()
+        for sku in skus:
+            sku_started = time.monotonic()
+            entry: dict[str, Any] = {"sku": sku}
+            try:
+                result = await executor.execute_workflow(context={"sku": sku}, quit_browser=False)
+            except Exception as e:
+                entry.update(success=False, error=f"{type(e).__name__}: {e}")
+            else:
+                if result.get("success"):
+                    entry.update(success=True, record=_record(config, result.get("results", {})))
+                else:
+                    entry.update(success=False, error=result.get("error") or "workflow failed")
+            entry["seconds"] = round(time.monotonic() - sku_started, 2)
+            results.append(entry)
+
+    try:
+        await asyncio.wait_for(_run(), timeout=timeout)
+        timed_out = False
+    except asyncio.TimeoutError:
+        timed_out = True
+        logger.warning(f"[Runner] Quick check for {config.name} timed out after {timeout:.0f}s")
+    finally:
+        if executor.browser:
+            try:
+                await executor.browser.quit()
+            except Exception as e:
+                logger.debug(f"Browser quit error: {e}")
+
+    checked = {entry["sku"] for entry in results}
+    results += [{"sku": sku, "success": False, "error": "timed out"} for sku in skus if sku not in checked]
+    return {
+        "success": not timed_out and all(entry["success"] for entry in results),
+        "scraper": config.name,
+        "results": results,
+        "seconds": round(time.monotonic() - started, 2),
+    }
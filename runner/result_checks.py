@@ -0,0 +1,109 @@
+"""
+Statistical sanity checks on a finished result set.
+
+A parser bug rarely fails a run outright: it fills every price with the same
+value or leaves names empty, and the run still looks successful. After all SKUs
+are scraped and duplicates merged (see runner.dedup), each scraper's records
+are checked for:
+
+- empty_names: too many products without a title
+- identical_prices: too many products sharing one price
+- uniform_availability: every product reporting the same availability, only
+  when the scraper enables check_availability
+
+Each tripped check becomes a finding and the job completes with warnings.
+Checks need `min_products` records to say anything, so small and test runs are
+left alone. Everything is a single pass with counters, cheap even at 100k rows.
+
+Coverage is judged separately: the share of attempted SKUs that produced a
+record, with SKUs quarantined as conflicting duplicates left out of both sides.
+Full coverage is a success, coverage at or above the scraper's
+`min_coverage_percent` is partial and anything below it is failed.
+"""
+
+from __future__ import annotations
+
+from collections import Counter
+from typing import Any
+
+from scrapers.models.config import ResultChecksConfig
+
+OUTCOME_SUCCESS = "success"
+OUTCOME_PARTIAL = "partial"
+OUTCOME_FAILED = "failed"
+
+# Worst first, so a job's outcome is its worst scraper's
+OUTCOME_ORDER = (OUTCOME_FAILED, OUTCOME_PARTIAL, OUTCOME_SUCCESS)
+
+
+def _finding(scraper: str, check: str, message: str, **details: Any) -> dict[str, Any]:
+    return {"scraper": scraper, "check": check, "message": message, **details}
+
+
+def check_results(scraper: str, records: dict[str, dict[str, Any]], checks: ResultChecksConfig | None = None) -> list[dict[str, Any]]:
+    """Findings for one scraper's upload records, keyed by SKU. Empty when everything looks plausible."""
+    checks = checks or ResultChecksConfig()
+    total = len(records)
+    if total < checks.min_products:
+        return []
+
+    findings: list[dict[str, Any]] = []
+
+    empty_names = sum(1 for record in records.values() if not str(record.get("title") or "").strip())
+    if empty_names / total > checks.max_empty_name_ratio:
+        findings.append(
+            _finding(scraper, "empty_names", f"{empty_names}/{total} products have no name", ratio=round(empty_names / total, 3))
+        )
+
+    prices = Counter(
+        record["scraped_price"].get("amount_cents")
+        for record in records.values()
+        if isinstance(record.get("scraped_price"), dict) and record["scraped_price"].get("amount_cents") is not None
+    )
+    if len(prices) and sum(prices.values()) >= checks.min_products:
+        price, count = prices.most_common(1)[0]
+        priced = sum(prices.values())
+        if count / priced > checks.max_identical_price_ratio:
+            findings.append(
+                _finding(
+                    scraper,
+                    "identical_prices",
+                    f"{count}/{priced} priced products share the price {price} cents",
+                    ratio=round(count / priced, 3),
+                )
+            )
+
+    if checks.check_availability:
+        availability = Counter(record.get("availability") for record in records.values() if record.get("availability") is not None)
+        if len(availability) == 1 and sum(availability.values()) >= checks.min_products:
+            value = next(iter(availability))
+            findings.append(_finding(scraper, "uniform_availability", f"Every product reports availability '{value}'", value=value))
+
+    return findings
+
+
+def compute_coverage(attempted: set[str], found: set[str], quarantined: set[str], min_coverage_percent: float | None = None) -> dict[str, Any]:
+    """Coverage and outcome for one scraper's SKUs. Nothing attempted counts as full coverage."""
+    attempted = attempted - quarantined
+    found = (found & attempted) - quarantined
+    percent = 100.0 if not attempted else round(len(found) / len(attempted) * 100, 2)
+    min_percent = min_coverage_percent or 0.0
+    if len(found) == len(attempted):
+        outcome = OUTCOME_SUCCESS
+    elif found and percent >= min_percent:
+        outcome = OUTCOME_PARTIAL
+    else:
+        outcome = OUTCOME_FAILED
+    return {
+        "attempted": len(attempted),
+        "found": len(found),
+        "quarantined": len(quarantined),
+        "percent": percent,
+        "min_percent": min_percent,
+        "outcome": outcome,
+    }
+
+
+def worst_outcome(outcomes: list[str]) -> str:
+    """The job outcome: its worst scraper outcome, or success when nothing ran."""
+    return next((outcome for outcome in OUTCOME_ORDER if outcome in outcomes), OUTCOME_SUCCESS)
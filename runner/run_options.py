@@ -0,0 +1,54 @@
+"""
+Per-run option overrides.
+
+A one-off tweak (visible browser, longer timeout, another browser revision)
+shouldn't mean editing settings, running and remembering to change them back.
+The desktop app sends such tweaks in the job's `job_config.run_options`; they
+apply to that job only and are never written anywhere.
+
+Every option is resolved with one precedence, lowest first:
+
+    global settings < per-scraper override < run options
+
+An option left unset (None) at one layer falls through to the layer below.
+The validated run options are recorded in the job's provenance.
+"""
+
+from __future__ import annotations
+
+from typing import Any
+
+from pydantic import BaseModel, ConfigDict, Field
+
+
+class RunOptions(BaseModel):
+    """
+    Overrides for a single job. Unset fields keep the scraper's and global values.
+
+    Options the desktop app applies itself (chunk size, proxy) are passed
+    through untouched so they still show up in provenance.
+    """
+
+    model_config = ConfigDict(extra="allow")
+
+    headless: bool | None = Field(None, description="Run the browser headless (false shows the window)")
+    timeout: int | None = Field(None, ge=1, le=300, description="Browser timeout in seconds")
+    browser_revision: str | None = Field(None, description="Pinned browser revision to launch instead of the scraper's")
+
+    @classmethod
+    def from_job_config(cls, job_config: dict[str, Any] | None) -> RunOptions:
+        """The run options in a job's job_config, or an empty set. Raises pydantic.ValidationError when invalid."""
+        return cls.model_validate((job_config or {}).get("run_options") or {})
+
+
+def merge_options(settings: dict[str, Any], scraper_override: dict[str, Any] | None, run_options: dict[str, Any] | None) -> dict[str, Any]:
+    """
+    Merge option layers with run options > scraper override > global settings.
+
+    None never overrides a value from a lower layer, so a layer only needs the
+    keys it actually changes.
+    """
+    merged = dict(settings)
+    for layer in (scraper_override, run_options):
+        merged.update({key: value for key, value in (layer or {}).items() if value is not None})
+    return merged
@@ -0,0 +1,183 @@
+"""
+One-shot selector tester for scraper authors.
+
+Loads a live page with the scraper's own browser setup (and login, if the
+scraper requires one), evaluates a single CSS or XPath selector, and reports
+what matched along with a screenshot that highlights the matches.
+
+Usage:
+    python -m runner.selector_tester --scraper phillips --url https://... --selector ".product-title"
+
+The result is printed to stdout as a single JSON object; all other output goes
+to stderr so the desktop app can parse stdout directly.
+"""
+
+from __future__ import annotations
+
+import argparse
+import asyncio
+import base64
+import contextlib
+import json
+import logging
+import sys
+from pathlib import Path
+from typing import Any
+
+from scrapers.models.config import ScraperConfig, WorkflowStep
+
+logger = logging.getLogger(__name__)
+
+SELECTOR_TEST_TIMEOUT = 60  # seconds, including browser startup and login
+MAX_SAMPLE_MATCHES = 5
+SELECTOR_TYPES = ("css", "xpath")
+CONFIGS_DIR = Path(__file__).parent.parent / "scrapers" / "configs"
+
+_DESCRIBE_ELEMENT_JS = """el => ({
+    tag: el.tagName.toLowerCase(),
+    text: (el.innerText || el.textContent || "").trim().slice(0, 500),
+    attributes: Object.fromEntries([...el.attributes].map(a => [a.name, a.value])),
+})"""
+
+_HIGHLIGHT_JS = """els => els.forEach(el => {
+    el.style.outline = "3px solid #ff00ff";
+    el.style.outlineOffset = "2px";
+})"""
+
+
+def load_scraper_config(scraper_name: str) -> ScraperConfig:
+    """Load a scraper config from a YAML path or by name from scrapers/configs."""
+    from scrapers.parser.yaml_parser import ScraperConfigParser
+
+    path = Path(scraper_name)
+    if not path.suffix:
+        path = CONFIGS_DIR / f"{scraper_name}.yaml"
+    if not path.exists():
+        raise FileNotFoundError(f"Scraper config not found: {path}")
+    return ScraperConfigParser().load_from_file(path)
+
+
+def _login_steps(config: ScraperConfig) -> list[WorkflowStep]:
+    steps = [step for step in config.workflows if step.action == "login"]
+    if not steps and config.login:
+        steps = [WorkflowStep(action="login")]
+    return steps
+
+
+async def _evaluate(executor: Any, url: str, selector: str, selector_type: str, screenshot_path: str | None) -> dict[str, Any]:
+    if executor.config.requires_login():
+        logger.info(f"[Selector Tester] Logging in to {executor.config.name} before loading the page")
+        await executor.execute_steps(_login_steps(executor.config))
+
+    await executor.browser.get(url)
+    page = executor.browser.page
+
+    locator = page.locator(f"xpath={selector}" if selector_type == "xpath" else selector)
+    count = await locator.count()
+    matches = [await locator.nth(i).evaluate(_DESCRIBE_ELEMENT_JS) for i in range(min(count, MAX_SAMPLE_MATCHES))]
+
+    if count:
+        await locator.evaluate_all(_HIGHLIGHT_JS)
+    screenshot = await page.screenshot(path=screenshot_path, full_page=True)
+
+    result: dict[str, Any] = {"match_count": count, "matches": matches, "page_url": page.url}
+    if screenshot_path:
+        result["screenshot_path"] = screenshot_path
+    else:
+        result["screenshot_base64"] = base64.b64encode(screenshot).decode("ascii")
+    return result
+
+
+async def run_selector_test(
+    config: ScraperConfig,
+    url: str,
+    selector: str,
+    selector_type: str = "css",
+    headless: bool = True,
+    screenshot_path: str | None = None,
+    timeout: float = SELECTOR_TEST_TIMEOUT,
+) -> dict[str, Any]:
+    """
+    Evaluate one selector against a live page.
+
+    The browser is always closed before returning, so this is safe to call
+    repeatedly.
+
+    Args:
+        config: Scraper whose browser settings and login are used
+        url: Product page to load
+        selector: CSS selector or XPath expression
+        selector_type: "css" or "xpath"
+        headless: Whether to hide the browser
+        screenshot_path: Where to save the highlighted screenshot; returned as base64 if None
+        timeout: Overall limit in seconds
+
+    Returns:
+        Dictionary with success, match_count, up to 5 matches (tag, text,
+        attributes) and the screenshot, or success=False with an error
+    """
+    from scrapers.executor.workflow_executor import WorkflowExecutor
+
+    if selector_type not in SELECTOR_TYPES:
+        return {"success": False, "error": f"Unknown selector_type '{selector_type}', expected one of {', '.join(SELECTOR_TYPES)}"}
+
+    executor = WorkflowExecutor(config, headless=headless)
+
+    async def _run() -> dict[str, Any]:
+        await executor.initialize()
+        return await _evaluate(executor, url, selector, selector_type, screenshot_path)
+
+    try:
+        result = await asyncio.wait_for(_run(), timeout=timeout)
+        return {"success": True, "selector": selector, "selector_type": selector_type, **result}
+    except asyncio.TimeoutError:
+        return {"success": False, "error": f"Selector test timed out after {timeout:.0f}s"}
+    except Exception as e:
+        logger.error(f"[Selector Tester] {config.name}: {type(e).__name__} - {e}")
+        return {"success": False, "error": f"{type(e).__name__}: {e}"}
+    finally:
+        if executor.browser:
+            try:
+                await executor.browser.quit()
+            except Exception as e:
+                logger.debug(f"Browser quit error: {e}")
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Test a selector against a live page")
+    parser.add_argument("--scraper", required=True, help="Scraper name or path to its YAML config")
+    parser.add_argument("--url", required=True, help="Product page URL")
+    parser.add_argument("--selector", required=True, help="CSS selector or XPath expression")
+    parser.add_argument("--selector-type", choices=SELECTOR_TYPES, default="css")
+    parser.add_argument("--screenshot", help="Save the highlighted screenshot here instead of returning base64")
+    parser.add_argument("--headful", action="store_true", help="Show the browser")
+    parser.add_argument("--timeout", type=float, default=SELECTOR_TEST_TIMEOUT, help=f"Seconds before giving up (default: {SELECTOR_TEST_TIMEOUT})")
+    args = parser.parse_args()
+
+    logging.basicConfig(level=logging.INFO, stream=sys.stderr)
+
+    # Keep stdout clean for the JSON result
+    with contextlib.redirect_stdout(sys.stderr):
+        try:
+            config = load_scraper_config(args.scraper)
+        except Exception as e:
+            result: dict[str, Any] = {"success": False, "error": str(e)}
+        else:
+            result = asyncio.run(
+                run_selector_test(
+                    config,
+                    args.url,
+                    args.selector,
+                    selector_type=args.selector_type,
+                    headless=not args.headful,
+                    screenshot_path=args.screenshot,
+                    timeout=args.timeout,
+                )
+            )
+
+    print(json.dumps(result))
+    sys.exit(0 if result["success"] else 1)
+
+
+if __name__ == "__main__":
+    main()
@@ -64,7 +64,7 @@ class ExtractSingleAction(BaseAction):
         if element:
             value = await self.ctx._extract_value_from_element(element, selector_config.attribute)
             self.ctx.results[field_name] = value
-            logger.debug(f"Extracted {field_name}: {value}")
+            logger.debug(f"Extracted {field_name}: {self.ctx.config.loggable(field_name, value)}")
         else:
             logger.warning(f"Element not found for field: {field_name}")
             self.ctx.results[field_name] = None
@@ -171,8 +171,9 @@ class ExtractAction(BaseAction):
                     if element:
                         value = await self.ctx._extract_value_from_element(element, selector_config.attribute)
                     self.ctx.results[result_key] = value
-                logger.debug(f"Extracted {result_key}: {self.ctx.results[result_key]}")
+                logger.debug(f"Extracted {result_key}: {self.ctx.config.loggable(result_key, self.ctx.results[result_key])}")
             except Exception as e:
                 logger.warning(f"Error extracting field {result_key}: {e}")
                 self.ctx.results[result_key] = [] if selector_config.multiple else None
-        logger.info(f"Extract action completed. Results: {self.ctx.results}")
+        loggable_results = {key: self.ctx.config.loggable(key, value) for key, value in self.ctx.results.items()}
+        logger.info(f"Extract action completed. Results: {loggable_results}")
@@ -143,7 +143,7 @@ class ExtractAndTransformAction(BaseAction):
                     value = self._apply_transformations(value, transforms)
 
             self.ctx.results[name] = value
-            logger.debug(f"Extracted '{name}': {value[:100] if isinstance(value, str) else value}")
+            logger.debug(f"Extracted '{name}': {self.ctx.config.loggable(name, value[:100] if isinstance(value, str) else value)}")
 
         except Exception as e:
             logger.warning(f"Error extracting field '{name}': {e}")
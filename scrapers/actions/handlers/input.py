@@ -12,12 +12,13 @@ logger = logging.getLogger(__name__)
 
 @ActionRegistry.register("input_text")
 class InputTextAction(BaseAction):
-    """Action to input text into a form field."""
+    """Action to input text into a form field. Set sensitive to keep the text out of the logs."""
 
     async def execute(self, params: dict[str, Any]) -> None:
         selector = params.get("selector")
         text = params.get("text")
         clear_first = params.get("clear_first", True)
+        sensitive = params.get("sensitive", False)
 
         if not selector or text is None:
             raise WorkflowExecutionError("Input_text requires 'selector' and 'text' parameters")
@@ -33,6 +34,6 @@ class InputTextAction(BaseAction):
             else:
                 await element.type(str(text))
 
-            logger.debug(f"Input text into {selector}: {text}")
+            logger.debug(f"Input text into {selector}: {'[hidden]' if sensitive else text}")
         except Exception as e:
             raise WorkflowExecutionError(f"Failed to input text into {selector}: {e}")
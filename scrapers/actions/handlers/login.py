@@ -2,8 +2,11 @@ from __future__ import annotations
 import asyncio
 
 import logging
+import time
 from typing import Any, cast
 
+from core.auth_challenge import auth_challenges
+from core.portal_fingerprint import fingerprint_page, portal_fingerprints
 from scrapers.actions.base import BaseAction
 from scrapers.actions.registry import ActionRegistry
 from scrapers.exceptions import WorkflowExecutionError
@@ -11,6 +14,19 @@ from scrapers.exceptions import WorkflowExecutionError
 logger = logging.getLogger(__name__)
 
 
+def resolve_login_params(config: Any, params: dict[str, Any]) -> dict[str, Any]:
+    """Login step params filled in from the config's login block and any injected credentials."""
+    params = dict(params)
+    if config.login and not params.get("url"):
+        params.update(config.login.model_dump())
+
+    creds = getattr(config, "options", {}) or {}
+    if "_credentials" in creds:
+        params["username"] = creds["_credentials"].get("username")
+        params["password"] = creds["_credentials"].get("password")
+    return params
+
+
 @ActionRegistry.register("login")
 class LoginAction(BaseAction):
     """Action to execute login workflow with session persistence."""
@@ -61,14 +77,7 @@ class LoginAction(BaseAction):
                 logger.info(f"Skipping login for {scraper_name} - session already authenticated")
                 return
 
-        if self.ctx.config.login and not params.get("url"):
-            params.update(self.ctx.config.login.model_dump())
-
-        creds = getattr(self.ctx.config, "options", {}) or {}
-        if "_credentials" in creds:
-            params["username"] = creds["_credentials"].get("username")
-            params["password"] = creds["_credentials"].get("password")
-
+        params = resolve_login_params(self.ctx.config, params)
         username = params.get("username")
         password = params.get("password")
 
@@ -84,29 +93,32 @@ class LoginAction(BaseAction):
             return
 
         logger.info(f"Logging in to {scraper_name} at {login_url}")
+        started = time.monotonic()
 
         try:
             # Navigate
             from scrapers.models.config import WorkflowStep
 
-            self.ctx._execute_step(WorkflowStep(action="navigate", params={"url": login_url}))
+            await self.ctx._execute_step(WorkflowStep(action="navigate", params={"url": login_url}))
 
             # In test mode, validate login selectors exist on the page
             if test_mode:
-                self._validate_login_selectors(params)
+                await self._validate_login_selectors(params)
 
             # Check if already logged in
             success_indicator = params.get("success_indicator")
             if success_indicator:
                 try:
                     # Check quickly if we are already logged in
-                    self.ctx._execute_step(
+                    await self.ctx._execute_step(
                         WorkflowStep(
                             action="wait_for",
                             params={"selector": success_indicator, "timeout": 5},
                         )
                     )
                     logger.info(f"Already logged in to {scraper_name}")
+                    self.ctx.mark_session_authenticated()
+                    self.ctx.record_session_reused()
                     if test_mode:
                         # If we are already logged in, we can't verify form fields (they are hidden).
                         # Emit 'FOUND' for success indicator, 'SKIPPED' for others.
@@ -128,33 +140,41 @@ class LoginAction(BaseAction):
                     # Not logged in (or indicator not found), proceed with login
                     pass
 
+            # Fingerprint before waiting for the form: a redesign that renames
+            # the username field fails that wait, and is exactly what to catch.
+            # Only an already-logged-in redirect (handled above) is not the login page.
+            await self._fingerprint_login_page()
+
             # Wait for the login form to be ready before inputting credentials
             username_field = params.get("username_field")
             if username_field:
                 # Wait for username field to appear (with timeout)
-                self.ctx._execute_step(
+                await self.ctx._execute_step(
                     WorkflowStep(
                         action="wait_for",
                         params={"selector": username_field, "timeout": 15},
                     )
                 )
                 # Input username
-                self.ctx._execute_step(WorkflowStep(action="input_text", params={"selector": username_field, "text": username}))
+                await self.ctx._execute_step(WorkflowStep(action="input_text", params={"selector": username_field, "text": username}))
 
             # Input password
             password_field = params.get("password_field")
             if password_field:
-                self.ctx._execute_step(WorkflowStep(action="input_text", params={"selector": password_field, "text": password}))
+                await self.ctx._execute_step(WorkflowStep(action="input_text", params={"selector": password_field, "text": password, "sensitive": True}))
 
             # Click submit
             submit_button = params.get("submit_button")
             if submit_button:
-                self.ctx._execute_step(WorkflowStep(action="click", params={"selector": submit_button}))
+                await self.ctx._execute_step(WorkflowStep(action="click", params={"selector": submit_button}))
+
+            if params.get("otp_field"):
+                await self._answer_otp_challenge(params)
 
             # Wait for success
             timeout = params.get("timeout", 30)
             if success_indicator:
-                self.ctx._execute_step(
+                await self.ctx._execute_step(
                     WorkflowStep(
                         action="wait_for",
                         params={"selector": success_indicator, "timeout": timeout},
@@ -168,17 +188,51 @@ class LoginAction(BaseAction):
 
             # Mark session as authenticated
             self.ctx.mark_session_authenticated()
+            await self.ctx.record_login(time.monotonic() - started)
         except Exception as e:
             logger.error(f"Login failed for {scraper_name}: {e}")
             raise WorkflowExecutionError(f"Login failed for {scraper_name}: {e}") from e
 
+    async def _answer_otp_challenge(self, params: dict[str, Any]) -> None:
+        """Enter an emailed one-time code if the portal asks for one after the password."""
+        from scrapers.models.config import WorkflowStep
+
+        scraper_name = self.ctx.config.name
+        otp_field = params["otp_field"]
+        try:
+            await self.ctx._execute_step(WorkflowStep(action="wait_for", params={"selector": otp_field, "timeout": 10}))
+        except Exception:
+            # No challenge this time, e.g. the portal remembers this browser
+            return
+
+        if not auth_challenges.can_answer(getattr(self.ctx, "context", {})):
+            raise WorkflowExecutionError(f"Interactive auth required: {scraper_name} asked for a one-time code and nobody can enter it in this run")
+
+        timeout = int(params.get("otp_timeout_seconds") or 300)
+        logger.warning(f"{scraper_name} asked for a one-time code, waiting up to {timeout}s for it")
+        if self.ctx.event_emitter and hasattr(self.ctx.event_emitter, "auth_challenge"):
+            self.ctx.event_emitter.auth_challenge(scraper=scraper_name, challenge_type="otp", timeout_seconds=timeout)
+        code = await auth_challenges.wait_for_response(scraper_name, timeout)
+        if code is None:
+            raise WorkflowExecutionError(f"No one-time code entered for {scraper_name} within {timeout}s")
+
+        await self.ctx._execute_step(WorkflowStep(action="input_text", params={"selector": otp_field, "text": code, "sensitive": True}))
+        if params.get("otp_submit_button"):
+            await self.ctx._execute_step(WorkflowStep(action="click", params={"selector": params["otp_submit_button"]}))
+
+    async def _fingerprint_login_page(self) -> None:
+        """Compare the login form's structure with the last one seen, to catch portal redesigns early."""
+        try:
+            html = await self.ctx.browser.page.content()
+            portal_fingerprints.record(self.ctx.config.name, fingerprint_page(html))
+        except Exception as e:
+            logger.debug(f"Could not fingerprint login page for {self.ctx.config.name}: {e}")
+
     async def _validate_login_selectors(self, params: dict[str, Any]) -> None:
         """
         Validate presence of login selectors on the page and log results for UI.
         Used in test_mode.
         """
-        import time
-
         # Small wait to ensure page is interactive/loaded beyond basic navigation
         await asyncio.sleep(2)
 
@@ -193,7 +247,7 @@ class LoginAction(BaseAction):
                 continue
 
             # Check if element exists
-            element = self.ctx.find_element_safe(selector)
+            element = await self.ctx.find_element_safe(selector, required=False)
             status = "FOUND" if element else "MISSING"
 
             # Log in format expected by TestingPage: [LOGIN_SELECTOR] name: 'STATUS'
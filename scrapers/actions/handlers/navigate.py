@@ -2,8 +2,10 @@ from __future__ import annotations
 
 import asyncio
 import logging
+import time
 from typing import Any
 
+from core.pacing import pacing_control
 from scrapers.actions.base import BaseAction
 from scrapers.actions.registry import ActionRegistry
 from scrapers.exceptions import WorkflowExecutionError
@@ -20,8 +22,11 @@ class NavigateAction(BaseAction):
         if not url:
             raise WorkflowExecutionError("Navigate action requires 'url' parameter")
 
-        logger.info(f"Navigating to: {url}")
-        await self.ctx.browser.get(url)
+        if self.ctx.config.adaptive_pacing:
+            await self._navigate_paced(url)
+        else:
+            logger.info(f"Navigating to: {url}")
+            await self.ctx.browser.get(url)
 
         # Check HTTP status if monitoring is enabled
         if self.ctx.config.http_status and self.ctx.config.http_status.enabled:
@@ -51,3 +56,30 @@ class NavigateAction(BaseAction):
 
         # Mark that first navigation is done
         self.ctx.first_navigation_done = True
+
+    async def _navigate_paced(self, url: str) -> None:
+        """Navigate after the delay set by the desktop app, then report how the request went."""
+        scraper = self.ctx.config.name
+        delay_ms, previous_delay_ms = pacing_control.apply(scraper)
+        if delay_ms != previous_delay_ms:
+            reason = pacing_control.reason(scraper)
+            logger.info(f"[Pacing] {scraper}: request delay {previous_delay_ms}ms -> {delay_ms}ms ({reason or 'no reason given'})")
+            if self.ctx.event_emitter:
+                self.ctx.event_emitter.pacing_changed(scraper=scraper, delay_ms=delay_ms, previous_delay_ms=previous_delay_ms, reason=reason)
+        if delay_ms > 0:
+            await asyncio.sleep(delay_ms / 1000)
+
+        logger.info(f"Navigating to: {url}")
+        started = time.monotonic()
+        await self.ctx.browser.get(url)
+        latency_ms = int((time.monotonic() - started) * 1000)
+
+        if self.ctx.event_emitter:
+            self.ctx.event_emitter.request_completed(
+                scraper=scraper,
+                url=url,
+                status_code=await self.ctx.browser.check_http_status(),
+                latency_ms=latency_ms,
+                delay_ms=delay_ms,
+                sku=self.ctx.context.get("sku"),
+            )
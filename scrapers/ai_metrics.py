@@ -8,10 +8,12 @@ Integrates with the existing AICostTracker for cost monitoring.
 import logging
 import time
 from typing import Any
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 from collections import defaultdict, deque
 from datetime import datetime, timedelta
 
+from core.instance import load_runner_tags
+
 logger = logging.getLogger(__name__)
 
 # Alert thresholds
@@ -20,6 +22,9 @@ LOW_SUCCESS_RATE_THRESHOLD = 0.70  # 70%
 LOW_SUCCESS_RATE_WINDOW = timedelta(hours=1)
 REPEATED_FAILURES_THRESHOLD = 3
 
+# Push snapshot cap; high-cardinality per-site series beyond this are dropped
+DEFAULT_PUSH_MAX_SERIES = 200
+
 
 @dataclass
 class Alert:
@@ -32,6 +37,27 @@ class Alert:
     metadata: dict[str, Any]
 
 
+@dataclass
+class MetricSample:
+    """A single exported series value."""
+
+    name: str
+    type: str  # counter, gauge
+    value: float
+    labels: dict[str, str] = field(default_factory=dict)
+    help: str = ""
+
+    def key(self) -> str:
+        return self.name + "".join(f"|{k}={v}" for k, v in sorted(self.labels.items()))
+
+    def formatted(self) -> str:
+        if self.name.endswith("cost_total"):
+            return f"{self.value:.6f}"
+        if isinstance(self.value, float):
+            return f"{self.value:.4f}"
+        return str(self.value)
+
+
 class AIMetricsCollector:
     """Collects metrics for AI scraper monitoring.
 
@@ -45,6 +71,7 @@ class AIMetricsCollector:
 
     def __init__(self):
         # Counters
+        self._location_tag = load_runner_tags()["location_tag"]
         self._extraction_count = 0
         self._extraction_success_count = 0
         self._extraction_failure_count = 0
@@ -72,6 +99,11 @@ class AIMetricsCollector:
         self._circuit_breaker_active: dict[str, bool] = {}
         self._consecutive_failures: dict[str, int] = defaultdict(int)
 
+        # Counter values at the last acknowledged push snapshot, for deltas
+        self._last_pushed: dict[str, float] = {}
+        # Counter values in the latest snapshot, until acknowledge_push
+        self._pending_push: dict[str, float] = {}
+
     def record_extraction(
         self,
         scraper_name: str,
@@ -285,6 +317,41 @@ class AIMetricsCollector:
             ],
         }
 
+    def get_samples(self) -> list[MetricSample]:
+        """Current value of every exported series.
+
+        Shared by the Prometheus text export and the push snapshot so the two
+        never disagree.
+        """
+        samples = [
+            MetricSample("ai_extraction_count", "counter", self._extraction_count, help="Total number of AI extractions"),
+            MetricSample("ai_extraction_success", "counter", self._extraction_success_count, help="Total number of successful AI extractions"),
+            MetricSample("ai_extraction_failure", "counter", self._extraction_failure_count, help="Total number of failed AI extractions"),
+            MetricSample("ai_cost_total", "counter", round(self._total_cost_usd, 6), help="Total cost of AI extractions in USD"),
+            MetricSample("ai_fallback_count", "counter", self._fallback_count, help="Total number of fallback to static scraping"),
+            MetricSample("ai_success_rate", "gauge", round(self.get_success_rate(), 4), help="Current success rate"),
+        ]
+
+        # Only the location tag becomes a label: one value per runner keeps cardinality bounded
+        if self._location_tag:
+            samples.append(MetricSample("runner_info", "gauge", 1, {"location": str(self._location_tag)}, help="Runner location tag"))
+
+        # Per-site metrics
+        for site, stats in self._site_extractions.items():
+            total = stats["success"] + stats["failure"]
+            site_success_rate = stats["success"] / total if total > 0 else 1.0
+            labels = {"site": site}
+
+            samples.append(MetricSample("ai_site_extractions", "counter", stats["count"], labels))
+            samples.append(MetricSample("ai_site_success_rate", "gauge", round(site_success_rate, 4), labels))
+            samples.append(MetricSample("ai_site_cost_total", "counter", round(stats["total_cost"], 6), labels))
+
+        # Circuit breaker status
+        for site, active in self._circuit_breaker_active.items():
+            samples.append(MetricSample("ai_circuit_breaker_active", "gauge", 1 if active else 0, {"site": site}))
+
+        return samples
+
     def get_prometheus_metrics(self) -> str:
         """Export metrics in Prometheus text format.
 
@@ -293,47 +360,58 @@ class AIMetricsCollector:
         """
         lines = []
 
-        # Counter metrics
-        lines.append(f"# HELP ai_extraction_count Total number of AI extractions")
-        lines.append(f"# TYPE ai_extraction_count counter")
-        lines.append(f"ai_extraction_count {{}} {self._extraction_count}")
-
-        lines.append(f"# HELP ai_extraction_success Total number of successful AI extractions")
-        lines.append(f"# TYPE ai_extraction_success counter")
-        lines.append(f"ai_extraction_success {{}} {self._extraction_success_count}")
+        for sample in self.get_samples():
+            if sample.help:
+                lines.append(f"# HELP {sample.name} {sample.help}")
+                lines.append(f"# TYPE {sample.name} {sample.type}")
+            labels = ",".join(f'{k}="{v}"' for k, v in sample.labels.items())
+            lines.append(f"{sample.name}{{{labels}}} {sample.formatted()}" if labels else f"{sample.name} {{}} {sample.formatted()}")
 
-        lines.append(f"# HELP ai_extraction_failure Total number of failed AI extractions")
-        lines.append(f"# TYPE ai_extraction_failure counter")
-        lines.append(f"ai_extraction_failure {{}} {self._extraction_failure_count}")
-
-        lines.append(f"# HELP ai_cost_total Total cost of AI extractions in USD")
-        lines.append(f"# TYPE ai_cost_total counter")
-        lines.append(f"ai_cost_total {{}} {self._total_cost_usd:.6f}")
+        return "\n".join(lines) + "\n"
 
-        lines.append(f"# HELP ai_fallback_count Total number of fallback to static scraping")
-        lines.append(f"# TYPE ai_fallback_count counter")
-        lines.append(f"ai_fallback_count {{}} {self._fallback_count}")
+    def get_push_snapshot(self, max_series: int = DEFAULT_PUSH_MAX_SERIES) -> dict[str, Any]:
+        """Compact JSON snapshot for runners that can't be scraped (e.g. behind NAT).
 
-        # Gauge metrics
-        lines.append(f"# HELP ai_success_rate Current success rate")
-        lines.append(f"# TYPE ai_success_rate gauge")
-        lines.append(f"ai_success_rate {{}} {self.get_success_rate():.4f}")
+        Counters also carry the delta since the last acknowledged snapshot so the
+        server can reconstruct rates. Call acknowledge_push once the snapshot has
+        been delivered; until then the next snapshot's deltas still cover this
+        interval. Labeled series beyond max_series are dropped, keeping the
+        unlabeled totals.
 
-        # Per-site metrics
-        for site, stats in self._site_extractions.items():
-            total = stats["success"] + stats["failure"]
-            site_success_rate = stats["success"] / total if total > 0 else 1.0
+        Args:
+            max_series: Cap on the number of series included
 
-            lines.append(f'ai_site_extractions{{site="{site}"}} {stats["count"]}')
-            lines.append(f'ai_site_success_rate{{site="{site}"}} {site_success_rate:.4f}')
-            lines.append(f'ai_site_cost_total{{site="{site}"}} {stats["total_cost"]:.6f}')
+        Returns:
+            Dictionary with timestamp, series, and the number of dropped series
+        """
+        samples = self.get_samples()
+        unlabeled = [s for s in samples if not s.labels]
+        labeled = [s for s in samples if s.labels]
+        kept = unlabeled + labeled[: max(0, max_series - len(unlabeled))]
+
+        series = []
+        pending: dict[str, float] = {}
+        for sample in kept:
+            entry: dict[str, Any] = {"n": sample.name, "v": sample.value}
+            if sample.labels:
+                entry["l"] = sample.labels
+            if sample.type == "counter":
+                key = sample.key()
+                entry["d"] = round(sample.value - self._last_pushed.get(key, 0), 6)
+                pending[key] = sample.value
+            series.append(entry)
+        self._pending_push = pending
 
-        # Circuit breaker status
-        for site, active in self._circuit_breaker_active.items():
-            status = 1 if active else 0
-            lines.append(f'ai_circuit_breaker_active{{site="{site}"}} {status}')
+        return {
+            "ts": datetime.now().isoformat(),
+            "series": series,
+            "dropped": len(samples) - len(kept),
+        }
 
-        return "\n".join(lines) + "\n"
+    def acknowledge_push(self) -> None:
+        """Make the latest push snapshot the baseline for the next one's deltas."""
+        self._last_pushed.update(self._pending_push)
+        self._pending_push = {}
 
 
 # Global metrics collector instance
@@ -42,6 +42,10 @@ class ScraperContext(Protocol):
 
     def mark_session_authenticated(self) -> None: ...
 
+    async def record_login(self, seconds: float) -> None: ...
+
+    def record_session_reused(self) -> None: ...
+
     # Metadata
     event_emitter: Any | None
     worker_id: str | None
@@ -20,6 +20,7 @@ class NormalizationEngine:
         self,
         results: dict[str, Any],
         normalization_rules: list[dict[str, Any]] | None = None,
+        redacted_fields: set[str] | None = None,
     ) -> dict[str, Any]:
         """Apply normalization rules to results.
 
@@ -30,6 +31,7 @@ class NormalizationEngine:
                 - field: str - Name of the field to normalize
                 - action: str - Normalization action to apply
                 - params: dict - Optional parameters for the action
+            redacted_fields: Fields whose values are kept out of the logs
 
         Supported actions:
         - title_case: Convert to title case
@@ -60,7 +62,10 @@ class NormalizationEngine:
                     try:
                         normalized_value = self._apply_normalization(value, action, params)
                         results[field] = normalized_value
-                        logger.debug(f"Normalized field '{field}': '{value}' -> '{normalized_value}'")
+                        if field in (redacted_fields or set()):
+                            logger.debug(f"Normalized field '{field}' with '{action}'")
+                        else:
+                            logger.debug(f"Normalized field '{field}': '{value}' -> '{normalized_value}'")
                     except Exception as e:
                         logger.warning(f"Failed to normalize field '{field}' with action '{action}': {e}")
 
@@ -12,7 +12,7 @@ from core.events import EventEmitter
 from core.retry_executor import RetryExecutor
 from scrapers.actions import ActionRegistry
 from scrapers.exceptions import ConfigurationError, ErrorContext
-from scrapers.models.config import WorkflowStep
+from scrapers.models.config import ScraperConfig, WorkflowStep
 
 logger = logging.getLogger(__name__)
 
@@ -407,6 +407,9 @@ class StepExecutor:
         error: str | None = None,
     ) -> None:
         """Track extraction result for v2 events."""
+        config = getattr(self.context, "config", None)
+        if isinstance(config, ScraperConfig):
+            value = config.loggable(field_name, value)
         self._step_extraction_results[field_name] = {
             "value": value,
             "status": status,
@@ -25,6 +25,7 @@ from core.anti_detection_manager import AntiDetectionManager
 from core.failure_analytics import FailureAnalytics
 from core.failure_classifier import FailureClassifier, FailureType
 from core.retry_executor import CircuitBreakerConfig, RetryExecutor
+from core.session_cache import account_key, session_cache
 from core.settings_manager import PROJECT_ROOT, SettingsManager
 from scrapers.actions import ActionRegistry
 from scrapers.exceptions import (
@@ -65,6 +66,7 @@ class WorkflowExecutor:
         job_id: str | None = None,
         event_emitter: Any | None = None,
         debug_callback: Any | None = None,
+        slow_mo_ms: int = 0,
     ) -> None:
         """
         Initialize the workflow executor.
@@ -77,6 +79,7 @@ class WorkflowExecutor:
             max_retries: Override default max retries (uses config.retries if None)
             worker_id: Optional identifier for the worker (used for profile isolation)
             stop_event: Optional threading.Event to check for cancellation
+            slow_mo_ms: Delay between browser operations, used by headful debug runs
         """
         self.config = config
         self.headless = headless
@@ -87,6 +90,7 @@ class WorkflowExecutor:
         self.job_id = job_id
         self.event_emitter = event_emitter
         self.debug_callback = debug_callback
+        self.slow_mo_ms = slow_mo_ms
         self.settings = SettingsManager()
         self.scraper_type = getattr(config, "scraper_type", "static")
 
@@ -164,6 +168,8 @@ class WorkflowExecutor:
         self.session_authenticated = False
         self.session_auth_time: float | None = None
         self.session_timeout = 1800  # 30 minutes default session timeout
+        # Whether the browser started from a cached login session
+        self.session_restored = False
 
         # Error tracking for current workflow run
         self.step_errors: list[dict[str, Any]] = []
@@ -189,14 +195,20 @@ class WorkflowExecutor:
             if backend == "playwright":
                 from utils.scraping.playwright_browser import (
                     create_playwright_browser,
+                    resolve_browsers_path,
                 )
 
                 logger.info(f"Initializing Playwright browser for scraper: {self.config.name}")
+                storage_state = session_cache.restore(self.config.name, self._session_account())
+                self.session_restored = storage_state is not None
                 self.browser = await create_playwright_browser(
                     site_name=self.config.name,
                     headless=self.headless,
                     profile_suffix=profile_suffix,
                     timeout=self.timeout,
+                    slow_mo_ms=self.slow_mo_ms,
+                    browsers_path=resolve_browsers_path(getattr(self.config, "browser_revision", None)),
+                    storage_state=storage_state,
                 )
             else:
                 raise BrowserError("Unsupported browser backend.")
@@ -325,6 +337,10 @@ class WorkflowExecutor:
         """
         Execute the complete workflow defined in the configuration.
 
+        If the workflow fails because the portal dropped the logged-in session
+        mid-run (the browser is back on the login form), it logs in again and
+        runs the workflow once more.
+
         Args:
             context: Dictionary of context variables (e.g. {'sku': '123'})
             quit_browser: Whether to quit the browser after execution
@@ -335,6 +351,24 @@ class WorkflowExecutor:
         Raises:
             WorkflowExecutionError: If workflow execution fails critically
         """
+        try:
+            try:
+                return await self._run_workflow(context)
+            except WorkflowExecutionError:
+                cancelled = self.stop_event is not None and self.stop_event.is_set()
+                if cancelled or not await self._session_expired_mid_run():
+                    raise
+            logger.warning(f"Login session for {self.config.name} expired mid-run; logging in again")
+            self.reset_session()
+            return await self._run_workflow(context)
+        finally:
+            if quit_browser and self.browser:
+                self.browser.quit()
+            if quit_browser and self.ai_browser:
+                await self._close_ai_browser()
+
+    async def _run_workflow(self, context: dict[str, Any] | None) -> dict[str, Any]:
+        """Run every workflow step once, as execute_workflow describes."""
         try:
             total_steps = len(self.config.workflows)
             logger.info(f"Starting workflow execution for: {self.config.name} ({total_steps} steps)")
@@ -433,11 +467,38 @@ class WorkflowExecutor:
                 f"Workflow execution failed: {e}",
                 context=ErrorContext(site_name=self.config.name),
             )
-        finally:
-            if quit_browser and self.browser:
-                self.browser.quit()
-            if quit_browser and self.ai_browser:
-                await self._close_ai_browser()
+
+    def _login_params(self) -> dict[str, Any] | None:
+        """The workflow's login step params as LoginAction resolves them, or None without a login step."""
+        from scrapers.actions.handlers.login import resolve_login_params
+
+        step = next((step for step in self.config.workflows if step.action == "login"), None)
+        if step is None:
+            return None
+        return resolve_login_params(self.config, step.params or {})
+
+    def _session_account(self) -> str:
+        """Cache key part for the account this scraper logs in as."""
+        params = self._login_params() or {}
+        return account_key(params.get("username"))
+
+    async def _session_expired_mid_run(self) -> bool:
+        """Whether an authenticated session was dropped and the browser sent back to the login form."""
+        if not self.session_authenticated or not self.browser:
+            return False
+        params = self._login_params()
+        if not params:
+            return False
+        login_url = params.get("url")
+        if login_url and self.browser.current_url.split("?")[0].rstrip("/") == str(login_url).split("?")[0].rstrip("/"):
+            return True
+        username_field = params.get("username_field")
+        if not username_field:
+            return False
+        try:
+            return await self.find_element_safe(username_field, required=False) is not None
+        except Exception:
+            return False
 
     async def execute_steps(self, steps: list[Any], context: dict[str, Any] | None = None) -> dict[str, Any]:
         """
@@ -589,6 +650,24 @@ class WorkflowExecutor:
         self.session_auth_time = time.time()
         logger.info(f"Session marked as authenticated for scraper: {self.config.name}")
 
+    async def record_login(self, seconds: float) -> None:
+        """Count a completed login and cache the logged-in session for the next browser."""
+        if self.session_restored:
+            logger.info(f"Cached login session for {self.config.name} had expired; logged in again")
+        state = None
+        try:
+            state = await self.browser.export_storage_state() if self.browser else None
+        except Exception as e:
+            logger.debug(f"Could not export session for {self.config.name}: {e}")
+        session_cache.record_login(self.config.name, seconds, state, self._session_account())
+        self.session_restored = False
+
+    def record_session_reused(self) -> None:
+        """Count a restored login session that the login step found still logged in."""
+        if self.session_restored:
+            session_cache.record_reuse(self.config.name)
+            self.session_restored = False
+
     def reset_session(self) -> None:
         """Reset the authentication session."""
         self.session_authenticated = False
@@ -632,7 +711,7 @@ class WorkflowExecutor:
                 }
             )
 
-        normalization_engine.normalize_results(self.results, rule_dicts)
+        normalization_engine.normalize_results(self.results, rule_dicts, redacted_fields=self.config.redacted_source_fields())
 
     async def _capture_debug_on_failure(
         self,
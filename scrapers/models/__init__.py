@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from .config import LoginConfig, ScraperConfig, SelectorConfig, ValidationConfig, WorkflowStep
+from .config import LoginConfig, MaintenanceWindow, ScraperConfig, SelectorConfig, ValidationConfig, WorkflowStep
 from .result import (
     SkuResult,
     SkuType,
@@ -13,6 +13,7 @@ from .result import (
 
 __all__ = [
     "LoginConfig",
+    "MaintenanceWindow",
     "ScraperConfig",
     "SelectorConfig",
     "ValidationConfig",
@@ -1,14 +1,54 @@
 from __future__ import annotations
 
+import functools
+import hashlib
+import hmac
+import json
+import re
+from datetime import datetime, time, timedelta, timezone
+from pathlib import PurePosixPath, PureWindowsPath
 from typing import Any, Literal
+from zoneinfo import ZoneInfo, ZoneInfoNotFoundError
 
-from pydantic import BaseModel, ConfigDict, Field, field_validator
+from pydantic import BaseModel, ConfigDict, Field, PrivateAttr, field_validator
 
 from core.anti_detection_manager import AntiDetectionConfig
+from core.models import AvailabilityStatus
 
 
 KNOWN_SCHEMA_VERSIONS = {"1.0"}
 
+# Browser revisions become directory names under the browsers dir, so keep them path-safe.
+BROWSER_REVISION_PATTERN = re.compile(r"^[A-Za-z0-9][A-Za-z0-9._-]*$")
+LOG_REDACTION_RULE_PATTERN = re.compile(r"^[a-z0-9_]+$")
+
+# Fields of an uploaded product record that can be redacted, mapped to the
+# extracted fields they are built from
+REDACTABLE_FIELDS = {
+    "title": ("Name", "product_name"),
+    "brand": ("Brand", "brand"),
+    "weight": ("Weight",),
+    "description": ("Description", "description"),
+    "images": ("Images", "Image URLs", "Image_URLs", "image_url"),
+    "availability": ("Availability", "availability"),
+    "url": ("URL",),
+    "scraped_price": ("Price", "price"),
+}
+REDACTED = "[REDACTED]"
+
+
+def source_config_hash(source: dict[str, Any]) -> str:
+    """Short stable hash of a scraper's source YAML/options, independent of key order."""
+    canonical = json.dumps(source, sort_keys=True, separators=(",", ":"), default=str)
+    return hashlib.sha256(canonical.encode("utf-8")).hexdigest()[:16]
+
+
+@functools.lru_cache(maxsize=1)
+def _redaction_key() -> bytes:
+    from core.instance import load_redaction_key
+
+    return load_redaction_key()
+
 
 class SelectorConfig(BaseModel):
     """Configuration for CSS selectors used in scraping."""
@@ -38,6 +78,9 @@ class LoginConfig(BaseModel):
     submit_button: str = Field(..., description="CSS selector for submit button")
     success_indicator: str | None = Field(None, description="CSS selector indicating successful login")
     failure_indicators: dict[str, Any] | None = Field(None, description="Indicators for detecting login failures")
+    otp_field: str | None = Field(None, description="CSS selector for the one-time code input some portals show after the password")
+    otp_submit_button: str | None = Field(None, description="CSS selector for the button that submits the one-time code")
+    otp_timeout_seconds: int = Field(300, ge=10, le=1800, description="How long to wait for someone to enter the one-time code")
 
 
 class HttpStatusConfig(BaseModel):
@@ -62,6 +105,37 @@ class ValidationConfig(BaseModel):
     no_results_text_patterns: list[str] | None = Field(None, description="Text patterns to detect 'no results' pages")
 
 
+class AvailabilityMapping(BaseModel):
+    """Maps supplier availability strings matching `pattern` to a normalized status.
+
+    The pattern is a case-insensitive regex searched in the raw string. Named
+    groups `quantity` and `restock_date` are parsed into the normalized result.
+    """
+
+    pattern: str = Field(..., description="Regex searched in the raw availability string")
+    status: AvailabilityStatus = Field(..., description="Normalized status for matching strings")
+
+    @field_validator("pattern")
+    @classmethod
+    def validate_pattern(cls, value: str) -> str:
+        try:
+            re.compile(value)
+        except re.error as e:
+            raise ValueError(f"Invalid availability pattern '{value}': {e}") from None
+        return value
+
+
+class ResultChecksConfig(BaseModel):
+    """Thresholds for the sanity checks run on a scraper's finished result set."""
+
+    min_products: int = Field(10, ge=1, description="Fewer products than this are too few to judge and skip the checks")
+    max_empty_name_ratio: float = Field(0.2, ge=0, le=1, description="Largest fraction of products allowed without a name")
+    max_identical_price_ratio: float = Field(0.5, ge=0, le=1, description="Largest fraction of priced products allowed to share one price")
+    check_availability: bool = Field(
+        False, description="Warn when every product reports the same availability (opt-in: in-stock-only catalogs trip it legitimately)"
+    )
+
+
 class NormalizationRule(BaseModel):
     """Rule for normalizing extracted data."""
 
@@ -73,6 +147,90 @@ class NormalizationRule(BaseModel):
     params: dict[str, Any] = Field(default_factory=dict, description="Parameters for the action")
 
 
+WEEKDAYS = ("mon", "tue", "wed", "thu", "fri", "sat", "sun")
+
+
+class RecurringWindow(BaseModel):
+    """A weekly time window in a given timezone.
+
+    `days` are the days the window starts on. A window whose end is at or before
+    its start runs past midnight into the next day.
+    """
+
+    days: list[Literal["mon", "tue", "wed", "thu", "fri", "sat", "sun"]] = Field(..., min_length=1, description="Days the window starts on")
+    start: str = Field(..., description="Local start time (HH:MM)")
+    end: str = Field(..., description="Local end time (HH:MM)")
+    timezone: str = Field("America/New_York", description="IANA timezone the start/end times are in")
+
+    @field_validator("start", "end")
+    @classmethod
+    def validate_time(cls, value: str) -> str:
+        try:
+            time.fromisoformat(value)
+        except ValueError:
+            raise ValueError(f"Invalid time '{value}'. Use HH:MM (24-hour).") from None
+        return value
+
+    @field_validator("timezone")
+    @classmethod
+    def validate_timezone(cls, value: str) -> str:
+        try:
+            ZoneInfo(value)
+        except (ZoneInfoNotFoundError, ValueError):
+            raise ValueError(f"Unknown timezone '{value}'") from None
+        return value
+
+    def ends_at(self, now: datetime | None = None) -> datetime | None:
+        """Return when the window ends if it is active at `now`, else None."""
+        tz = ZoneInfo(self.timezone)
+        local_now = (now or datetime.now(timezone.utc)).astimezone(tz)
+        start = time.fromisoformat(self.start)
+        end = time.fromisoformat(self.end)
+
+        # Check windows that started today and, for overnight windows, yesterday
+        for days_back in (0, 1):
+            start_date = local_now.date() - timedelta(days=days_back)
+            if WEEKDAYS[start_date.weekday()] not in self.days:
+                continue
+            window_start = datetime.combine(start_date, start, tzinfo=tz)
+            end_date = start_date if end > start else start_date + timedelta(days=1)
+            window_end = datetime.combine(end_date, end, tzinfo=tz)
+            if window_start <= local_now < window_end:
+                return window_end
+        return None
+
+    def next_start(self, now: datetime | None = None) -> datetime:
+        """Return the next time the window opens after `now`."""
+        tz = ZoneInfo(self.timezone)
+        local_now = (now or datetime.now(timezone.utc)).astimezone(tz)
+        start = time.fromisoformat(self.start)
+
+        # days has at least one entry, so a start always falls within the next eight days
+        starts = (datetime.combine(local_now.date() + timedelta(days=days_ahead), start, tzinfo=tz) for days_ahead in range(8))
+        return next(window_start for window_start in starts if WEEKDAYS[window_start.weekday()] in self.days and window_start > local_now)
+
+
+class MaintenanceWindow(RecurringWindow):
+    """Recurring supplier downtime during which jobs for the scraper are deferred."""
+
+
+class RunWindow(RecurringWindow):
+    """Recurring time during which the scraper may run, e.g. outside store hours."""
+
+
+class GoldenSampleConfig(BaseModel):
+    """Curated SKUs checked at the start of a full job to catch selector drift.
+
+    Only field presence is compared, not exact values, so routine price or
+    description changes on the supplier site don't trip the check.
+    """
+
+    skus: list[str] = Field(..., min_length=1, description="SKUs known to exist on the supplier site")
+    expected_fields: list[str] = Field(default_factory=lambda: ["Name"], min_length=1, description="Fields every golden SKU must return")
+    max_missing_ratio: float = Field(0.25, ge=0.0, le=1.0, description="Fraction of expected fields that may be empty or malformed before drift is suspected")
+    abort_on_drift: bool = Field(False, description="Skip the rest of the job for this scraper when drift is suspected")
+
+
 class AIConfig(BaseModel):
     """Configuration for AI-powered scrapers.
 
@@ -125,6 +283,124 @@ class ScraperConfig(BaseModel):
     fake_skus: list[str] | None = Field(None, description="List of fake SKUs for no-results validation")
     edge_case_skus: list[str] | None = Field(None, description="List of edge case SKUs for boundary testing")
     image_quality: int = Field(50, description="Quality score for images (0-100)", ge=0, le=100)
+    browser_revision: str | None = Field(None, description="Pinned Playwright browser revision (defaults to the bundled one)")
+    maintenance_windows: list[MaintenanceWindow] | None = Field(None, description="Supplier downtime windows during which jobs are deferred")
+    run_windows: list[RunWindow] | None = Field(None, description="Windows the scraper may run in; outside them jobs are deferred (default: any time)")
+    run_window_grace_minutes: int = Field(15, ge=0, description="How long a running job may continue after its run window closes")
+    adaptive_pacing: bool = Field(False, description="Let the desktop app adjust request delay mid-run from supplier response signals")
+    golden: GoldenSampleConfig | None = Field(None, description="Golden sample used to detect selector drift before a full job")
+    redact_fields: list[str] | None = Field(None, description="Product fields withheld from uploads; the full record stays in local results")
+    redact_mode: Literal["strip", "hash"] = Field(
+        "strip", description="Drop redacted fields from uploads, or replace them with an HMAC-SHA256 keyed with the runner's secret"
+    )
+    output_dir: str | None = Field(None, description="Absolute directory (or UNC share) for this scraper's local results instead of the default")
+    availability_mappings: list[AvailabilityMapping] | None = Field(
+        None, description="Supplier availability strings to normalized statuses, tried before the built-in heuristics"
+    )
+    dedup_policy: Literal["latest", "lowest_price", "flag"] | None = Field(
+        None, description="How conflicting duplicates of one product are resolved (default: latest wins)"
+    )
+    result_checks: ResultChecksConfig | None = Field(None, description="Result sanity check thresholds (defaults apply when unset)")
+    min_coverage_percent: float | None = Field(
+        None, ge=0, le=100, description="Share of attempted SKUs that must produce a record for a partial rather than failed outcome"
+    )
+    log_redaction_patterns: dict[str, str] | None = Field(
+        None, description="Extra log scrubbing rules (rule name -> regex), e.g. supplier account numbers"
+    )
+
+    _source: dict[str, Any] | None = PrivateAttr(default=None)
+
+    @field_validator("browser_revision")
+    @classmethod
+    def validate_browser_revision(cls, value: str | None) -> str | None:
+        if value is not None and not BROWSER_REVISION_PATTERN.match(value):
+            raise ValueError(f"Invalid browser_revision '{value}'. Use letters, digits, '.', '_' or '-'.")
+        return value
+
+    @field_validator("output_dir")
+    @classmethod
+    def validate_output_dir(cls, value: str | None) -> str | None:
+        if value is not None and not (PurePosixPath(value).is_absolute() or PureWindowsPath(value).is_absolute()):
+            raise ValueError(f"Invalid output_dir '{value}'. Use an absolute path or a UNC share like \\\\server\\share.")
+        return value
+
+    @field_validator("redact_fields")
+    @classmethod
+    def validate_redact_fields(cls, value: list[str] | None) -> list[str] | None:
+        unknown = sorted(set(value or []) - REDACTABLE_FIELDS.keys())
+        if unknown:
+            raise ValueError(f"Unknown redact_fields {', '.join(unknown)}. Redactable fields: {', '.join(REDACTABLE_FIELDS)}.")
+        return value
+
+    @field_validator("log_redaction_patterns")
+    @classmethod
+    def validate_log_redaction_patterns(cls, value: dict[str, str] | None) -> dict[str, str] | None:
+        for name, pattern in (value or {}).items():
+            if not LOG_REDACTION_RULE_PATTERN.match(name):
+                raise ValueError(f"Invalid log redaction rule name '{name}'. Use lowercase letters, digits or '_'.")
+            try:
+                re.compile(pattern)
+            except re.error as e:
+                raise ValueError(f"Invalid log redaction pattern for '{name}': {e}") from e
+        return value
+
+    def with_source(self, source: dict[str, Any]) -> ScraperConfig:
+        """Record the source YAML/options this config was built from, for config_hash."""
+        self._source = dict(source)
+        return self
+
+    def config_hash(self) -> str:
+        """Short stable hash of the source config, recorded in result provenance.
+
+        Falls back to the fields set on the model when no source was recorded.
+        """
+        source = self._source if self._source is not None else self.model_dump(mode="json", exclude_unset=True)
+        return source_config_hash(source)
+
+    def redacted_source_fields(self) -> set[str]:
+        """Extracted field names whose values must not be uploaded or logged."""
+        return {source for name in self.redact_fields or [] for source in REDACTABLE_FIELDS[name]}
+
+    def loggable(self, field_name: str, value: Any) -> Any:
+        """The value as it may appear in logs and events: masked if the field is redacted."""
+        return REDACTED if self.redact_fields and field_name in self.redacted_source_fields() else value
+
+    def redact_upload_record(self, record: dict[str, Any], key: bytes | None = None) -> dict[str, Any]:
+        """Copy of an upload record with redact_fields stripped or hashed and `redacted: true` set.
+
+        Hashes are keyed (see core.instance.load_redaction_key): a plain SHA-256 of
+        a price in cents could be reversed by hashing every plausible amount.
+        """
+        if not self.redact_fields:
+            return record
+        redacted = dict(record)
+        for name in self.redact_fields:
+            if name not in redacted:
+                continue
+            if self.redact_mode == "hash" and redacted[name] is not None:
+                if key is None:
+                    key = _redaction_key()
+                value = json.dumps(redacted[name], sort_keys=True, default=str).encode("utf-8")
+                redacted[name] = f"hmac-sha256:{hmac.new(key, value, hashlib.sha256).hexdigest()}"
+            else:
+                del redacted[name]
+        redacted["redacted"] = True
+        return redacted
+
+    def availability_rules(self) -> list[tuple[str, str]]:
+        """(pattern, status) pairs for normalize_availability."""
+        return [(mapping.pattern, mapping.status.value) for mapping in self.availability_mappings or []]
+
+    def active_maintenance_window_end(self, now: datetime | None = None) -> datetime | None:
+        """Return when the current maintenance window ends, or None if none is active."""
+        ends = [end for window in self.maintenance_windows or [] if (end := window.ends_at(now)) is not None]
+        return max(ends) if ends else None
+
+    def run_window_opens_at(self, now: datetime | None = None) -> datetime | None:
+        """Return when the next run window opens if none is open at `now`, else None."""
+        if not self.run_windows or any(window.ends_at(now) is not None for window in self.run_windows):
+            return None
+        return min(window.next_start(now) for window in self.run_windows)
 
     def requires_login(self) -> bool:
         """Check if this scraper requires authentication/login.
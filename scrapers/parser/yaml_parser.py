@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import copy
 from pathlib import Path
 
 import yaml  # type: ignore
@@ -53,10 +54,7 @@ class ScraperConfigParser:
         with open(file_path, encoding="utf-8") as f:
             config_dict = yaml.safe_load(f)
 
-        # Preprocess anti_detection field if present
-        config_dict = self._preprocess_config_dict(config_dict)
-
-        return validate_config_dict(config_dict)
+        return self.load_from_dict(config_dict)
 
     def load_from_string(self, yaml_string: str) -> ScraperConfig:
         """Load and parse a scraper configuration from a YAML string.
@@ -86,9 +84,10 @@ class ScraperConfigParser:
         Raises:
             ValidationError: If the configuration doesn't match the schema
         """
+        source = copy.deepcopy(config_dict)
         # Preprocess anti_detection field if present
         config_dict = self._preprocess_config_dict(config_dict)
-        return validate_config_dict(config_dict)
+        return validate_config_dict(config_dict).with_source(source)
 
     def save_to_file(self, config: ScraperConfig, file_path: str | Path) -> None:
         """Save a ScraperConfig to a YAML file.
@@ -3,16 +3,24 @@ Result Collector Module
 
 Collects scraper results in memory for later submission via API callback.
 No direct database access - all persistence goes through the coordinator.
+
+A local copy of each session is kept under data/scraper_sessions, or in a
+scraper's own output directory (e.g. a network share a POS imports from). If
+that directory can't be written, the scraper falls back to the default
+directory for the rest of the session so no results are lost.
 """
 
 from __future__ import annotations
 
 import json
 import logging
+import os
 from datetime import datetime
 from pathlib import Path
 from typing import TYPE_CHECKING, Any
 
+from scrapers.models.config import REDACTED
+
 if TYPE_CHECKING:
     from core.models import RawScrapedProduct
 
@@ -25,6 +33,14 @@ class ResultCollector:
     def __init__(self, output_dir: str | None = None, test_mode: bool = False) -> None:
         self.session_id = datetime.now().strftime("%Y%m%d_%H%M%S")
         self.results: dict[str, dict[str, Any]] = {}
+        # Field values that failed validation, with the original scraped string
+        self.rejected: list[dict[str, Any]] = []
+        # Extracted fields per scraper whose values must not leave the machine or reach the logs
+        self.redacted_fields: dict[str, set[str]] = {}
+        # Per-scraper local output directories overriding the default
+        self.output_dirs: dict[str, Path] = {}
+        # Scrapers whose output directory failed, with the error, after falling back to the default
+        self.output_fallbacks: dict[str, str] = {}
         self.test_mode = test_mode
 
         if output_dir:
@@ -36,27 +52,53 @@ class ResultCollector:
         self._output_dir.mkdir(parents=True, exist_ok=True)
         self._local_json_path: Path | None = None
 
-    def _save_result_to_local(self, sku: str, scraper_name: str, data: dict) -> None:
+    def _local_path(self, scraper_name: str) -> Path:
+        output_dir = self.output_dirs.get(scraper_name)
+        if output_dir is not None:
+            return output_dir / f"session_{self.session_id}_{scraper_name}.json"
         if self._local_json_path is None:
             self._local_json_path = self._output_dir / f"session_{self.session_id}.json"
+        return self._local_json_path
 
+    def _save_result_to_local(self, sku: str, scraper_name: str, data: dict) -> None:
+        path = self._local_path(scraper_name)
+        entry = {"data": data, "timestamp": datetime.now().isoformat()}
+        try:
+            self._write_local(path, scraper_name, {sku: entry})
+        except OSError as e:
+            if scraper_name not in self.output_dirs:
+                raise
+            del self.output_dirs[scraper_name]
+            self.output_fallbacks[scraper_name] = f"{type(e).__name__}: {e}"
+            logger.warning(f"Output directory for {scraper_name} is unreachable ({e}); writing to {self._output_dir} instead")
+            # Carry over everything written to the share so far, so the default copy is complete
+            entries = {
+                collected_sku: {"data": record["data"], "timestamp": record["timestamp"]}
+                for collected_sku, record in self.results.get(scraper_name, {}).items()
+            }
+            entries[sku] = entry
+            self._write_local(self._local_path(scraper_name), scraper_name, entries)
+
+    def _write_local(self, path: Path, scraper_name: str, entries: dict[str, dict[str, Any]]) -> None:
         existing_data: dict[str, Any] = {"session_id": self.session_id, "results": {}}
-        if self._local_json_path.exists():
+        if path.exists():
             try:
-                with open(self._local_json_path) as f:
+                with open(path) as f:
                     existing_data = json.load(f)
             except json.JSONDecodeError:
                 pass
 
-        if scraper_name not in existing_data["results"]:
-            existing_data["results"][scraper_name] = {}
-        existing_data["results"][scraper_name][sku] = {
-            "data": data,
-            "timestamp": datetime.now().isoformat(),
-        }
+        existing_data["results"].setdefault(scraper_name, {}).update(entries)
 
-        with open(self._local_json_path, "w") as f:
-            json.dump(existing_data, f, indent=2, default=str)
+        # Written beside the target and swapped in, so a failed write never truncates the file
+        tmp_path = path.with_name(f"{path.name}.{os.getpid()}.tmp")
+        try:
+            with open(tmp_path, "w") as f:
+                json.dump(existing_data, f, indent=2, default=str)
+            os.replace(tmp_path, path)
+        except OSError:
+            tmp_path.unlink(missing_ok=True)
+            raise
 
     def add_result(
         self,
@@ -65,13 +107,18 @@ class ResultCollector:
         result_data: dict[str, Any] | RawScrapedProduct,
         image_quality: int = 50,
     ) -> None:
-        from core.models import RawScrapedProduct
+        from core.models import PriceParseError, RawScrapedProduct, parse_price
 
         try:
             timestamp = datetime.now().isoformat()
 
             if isinstance(result_data, dict):
                 images = result_data.get("Images") or result_data.get("Image URLs") or result_data.get("Image_URLs") or []
+                try:
+                    price = parse_price(result_data.get("Price"))
+                except PriceParseError as e:
+                    self.reject(sku, scraper_name, "Price", e.raw, e.reason)
+                    price = None
                 product = RawScrapedProduct(
                     sku=sku,
                     source=scraper_name,
@@ -82,7 +129,7 @@ class ResultCollector:
                     images=images,
                     category=result_data.get("Category"),
                     product_type=result_data.get("ProductType"),
-                    scraped_price=result_data.get("Price"),
+                    scraped_price=price,
                     image_quality=image_quality,
                 )
                 data_for_db = product.to_db_dict()
@@ -113,6 +160,13 @@ class ResultCollector:
         except Exception as e:
             logger.error(f"Error processing result: {e}")
 
+    def reject(self, sku: str, scraper_name: str, field: str, value: Any, reason: str) -> None:
+        """Record a scraped value that could not be validated."""
+        if field in self.redacted_fields.get(scraper_name, set()):
+            value = REDACTED
+        logger.warning(f"Rejected {field} for {sku} from {scraper_name}: {reason} ({value!r})")
+        self.rejected.append({"sku": sku, "scraper": scraper_name, "field": field, "value": value, "reason": reason})
+
     def save_session(self, metadata: dict[str, Any] | None = None) -> str:
         if self.test_mode:
             logger.info("Test mode: Skipping session save to disk")
@@ -130,6 +184,7 @@ class ResultCollector:
             "timestamp": datetime.now().isoformat(),
             "metadata": metadata or {},
             "results": self.results,
+            "rejected": self.rejected,
         }
 
         try:
@@ -216,11 +216,12 @@ class ScraperValidator:
 
     def _validate_price(self, price: Any) -> bool:
         """Validate price format."""
-        if not price:
+        from core.models import PriceParseError, parse_price
+
+        try:
+            return parse_price(price) is not None
+        except PriceParseError:
             return False
-        price_str = str(price).strip()
-        price_pattern = r"^\$?\d+(\.\d{1,2})?$"
-        return bool(re.match(price_pattern, price_str))
 
     def _validate_images(self, images: Any) -> bool:
         """Validate images format (list of URLs)."""
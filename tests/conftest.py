@@ -17,3 +17,9 @@ def pytest_configure(config):
 
     if str(project_root) not in sys.path:
         sys.path.insert(0, str(project_root))
+
+
+@pytest.fixture(autouse=True)
+def _skip_runner_preflight(monkeypatch):
+    """run_job tests stub the browser, so don't require a real Chromium install."""
+    monkeypatch.setenv("SKIP_PREFLIGHT", "1")
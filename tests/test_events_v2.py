@@ -4,12 +4,14 @@ from __future__ import annotations
 
 import json
 from datetime import datetime
+from pathlib import Path
 from typing import Any
 from unittest.mock import MagicMock, patch
 
 import pytest
 
 from core.events import (
+    EVENT_SCHEMA_VERSION,
     EventBus,
     EventEmitter,
     EventSeverity,
@@ -435,6 +437,52 @@ class TestEventBusV2:
         assert len(dicts) == 1
         assert dicts[0]["version"] == "2.0"
 
+    def test_emit_assigns_increasing_seq(self):
+        """Every emitted event gets a monotonically increasing sequence number."""
+        bus = EventBus(buffer_size=50)
+
+        for job_id in ("job_a", "job_b", "job_a"):
+            bus.emit(ScraperEvent(event_type=EventType.SYSTEM_INFO, job_id=job_id))
+
+        seqs = [e.seq for e in bus.get_events()]
+        assert seqs == [1, 2, 3]
+        assert bus.last_seq == 3
+        assert bus.get_events_as_dicts()[0]["seq"] == 1
+
+    def test_get_events_since_seq_pages_forward_without_gaps(self):
+        """since_seq returns the oldest unseen events first, so paging has no gaps."""
+        bus = EventBus(buffer_size=50)
+        for i in range(10):
+            bus.emit(ScraperEvent(event_type=EventType.PROGRESS_UPDATE, job_id="test_job", data={"i": i}))
+
+        first_page = bus.get_events(since_seq=0, limit=4)
+        second_page = bus.get_events(since_seq=first_page[-1].seq, limit=4)
+
+        assert [e.seq for e in first_page] == [1, 2, 3, 4]
+        assert [e.seq for e in second_page] == [5, 6, 7, 8]
+        assert bus.get_events(since_seq=10) == []
+
+    def test_evicted_events_past_since_seq_are_reported_as_gap(self):
+        """A consumer that fell behind the buffer learns it missed events."""
+        bus = EventBus(buffer_size=3)
+        for i in range(6):
+            bus.emit(ScraperEvent(event_type=EventType.PROGRESS_UPDATE, job_id="test_job", data={"i": i}))
+
+        assert [e.seq for e in bus.get_events(since_seq=1)] == [4, 5, 6]
+        assert bus.oldest_seq() == 4
+        assert bus.has_gap(1) is True
+        assert bus.has_gap(1, job_id="test_job") is True
+        assert bus.has_gap(3) is False
+        assert bus.has_gap(3, job_id="test_job") is False
+
+    def test_no_gap_before_anything_is_evicted(self):
+        bus = EventBus(buffer_size=10)
+        assert bus.oldest_seq() is None
+        bus.emit(ScraperEvent(event_type=EventType.SYSTEM_INFO))
+
+        assert bus.oldest_seq() == 1
+        assert bus.has_gap(0) is False
+
 
 class TestEventTimingCalculation:
     """Test timing calculation in v2 events."""
@@ -656,3 +704,18 @@ class TestEventSchemaCompliance:
         assert "step" in event.data
         assert event.data["step"]["index"] == 0
         assert event.data["step"]["action"] == "navigate"
+
+    def test_schema_event_types_match_enum(self):
+        """docs/event-schema-v2.json must list exactly the EventType values."""
+        schema_path = Path(__file__).resolve().parent.parent / "docs" / "event-schema-v2.json"
+        schema = json.loads(schema_path.read_text())
+
+        documented = set(schema["properties"]["event_type"]["enum"])
+        assert documented == {e.value for e in EventType}
+
+    def test_schema_version_is_documented(self):
+        """The emitted schema version must be one the schema accepts."""
+        schema_path = Path(__file__).resolve().parent.parent / "docs" / "event-schema-v2.json"
+        schema = json.loads(schema_path.read_text())
+
+        assert EVENT_SCHEMA_VERSION in schema["properties"]["version"]["enum"]
@@ -49,7 +49,7 @@ class TestSensitiveDataFilter:
         filter_instance = SensitiveDataFilter()
         filter_instance.filter(record)
 
-        assert "[API_KEY_REDACTED]" in record.msg
+        assert "[REDACTED:api_key]" in record.msg
         assert "bsr_" not in record.msg
 
     def test_redacts_password_pattern(self):
@@ -67,7 +67,7 @@ class TestSensitiveDataFilter:
         filter_instance = SensitiveDataFilter()
         filter_instance.filter(record)
 
-        assert "[PASSWORD_REDACTED]" in record.msg
+        assert "[REDACTED:password]" in record.msg
         assert "secret123" not in record.msg
 
     def test_redacts_bearer_token(self):
@@ -85,7 +85,7 @@ class TestSensitiveDataFilter:
         filter_instance = SensitiveDataFilter()
         filter_instance.filter(record)
 
-        assert "[TOKEN_REDACTED]" in record.msg
+        assert "[REDACTED:token]" in record.msg
         assert "eyJ" not in record.msg
 
     def test_redacts_authorization_header(self):
@@ -103,7 +103,7 @@ class TestSensitiveDataFilter:
         filter_instance = SensitiveDataFilter()
         filter_instance.filter(record)
 
-        assert "[AUTH_REDACTED]" in record.msg
+        assert "[REDACTED:auth]" in record.msg
         assert "Basic" not in record.msg
 
 
@@ -0,0 +1,53 @@
+from scrapers.ai_metrics import AIMetricsCollector
+
+
+class TestMetricsPushSnapshot:
+    def setup_method(self):
+        self.collector = AIMetricsCollector()
+        self.collector.record_extraction("phillips", True, 0.01, 1.0)
+        self.collector.record_extraction("petfoodexperts", True, 0.02, 1.0)
+
+    def test_snapshot_matches_prometheus_export(self):
+        snapshot = self.collector.get_push_snapshot()
+        prometheus = self.collector.get_prometheus_metrics()
+
+        values = {s["n"]: s["v"] for s in snapshot["series"] if "l" not in s}
+        assert values["ai_extraction_count"] == 2
+        assert "ai_extraction_count {} 2" in prometheus
+        assert 'ai_site_extractions{site="phillips"} 1' in prometheus
+
+    def test_counters_carry_deltas_since_last_snapshot(self):
+        self.collector.get_push_snapshot()
+        self.collector.acknowledge_push()
+        self.collector.record_extraction("phillips", False, 0.0, 1.0)
+
+        series = {s["n"]: s for s in self.collector.get_push_snapshot()["series"] if "l" not in s}
+
+        assert series["ai_extraction_count"]["v"] == 3
+        assert series["ai_extraction_count"]["d"] == 1
+        assert "d" not in series["ai_success_rate"]
+
+    def test_undelivered_snapshot_keeps_the_baseline(self):
+        self.collector.get_push_snapshot()
+        self.collector.record_extraction("phillips", False, 0.0, 1.0)
+
+        series = {s["n"]: s for s in self.collector.get_push_snapshot()["series"] if "l" not in s}
+
+        assert series["ai_extraction_count"]["d"] == 3
+
+    def test_high_cardinality_series_are_dropped(self):
+        for i in range(20):
+            self.collector.record_extraction(f"site-{i}", True, 0.0, 1.0)
+
+        snapshot = self.collector.get_push_snapshot(max_series=10)
+
+        assert len(snapshot["series"]) == 10
+        assert snapshot["dropped"] > 0
+        assert any(s["n"] == "ai_extraction_count" for s in snapshot["series"])
+
+    def test_location_tag_is_exported_as_runner_info(self, monkeypatch):
+        monkeypatch.setenv("RUNNER_LOCATION_TAG", "lancaster")
+
+        prometheus = AIMetricsCollector().get_prometheus_metrics()
+
+        assert 'runner_info{location="lancaster"} 1' in prometheus
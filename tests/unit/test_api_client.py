@@ -1,3 +1,5 @@
+import gzip
+import json
 import os
 import time
 from unittest.mock import MagicMock, patch
@@ -9,8 +11,14 @@ from core.api_client import (
     AuthenticationError,
     ClaimedChunk,
     ConnectionError,
+    IncompatibleServerError,
     ScraperAPIClient,
+    StaleScraperConfigError,
+    _version_tuple,
 )
+from core.health import RunnerHealth
+from core.results_manifest import chunk_digest, verify_data
+from core.server_rejections import ServerRejections
 
 
 class TestScraperAPIClient:
@@ -164,6 +172,290 @@ class TestScraperAPIClient:
             payload = json.loads(call_args[1]["content"])
             assert payload["runner_name"] == "test-runner"
 
+    def test_submit_results_chunked_small_result_uses_single_callback(self):
+        with patch.object(self.client, "_make_request", return_value={"success": True}) as mock_request:
+            success = self.client.submit_results_chunked("job-123", {"data": {"SKU1": {}}}, chunk_rows=10)
+
+            assert success is True
+            mock_request.assert_called_once()
+            assert mock_request.call_args[0][1] == "/api/admin/scraping/callback"
+
+    def test_single_request_uploads_carry_a_manifest(self):
+        results = {"data": {"SKU1": {"phillips": {"Price": "10.99"}}}}
+
+        with patch.object(self.client, "_make_request", return_value={"success": True}) as mock_request:
+            assert self.client.submit_results("job-123", "completed", results=results) is True
+            assert self.client.submit_chunk_results("chunk-1", "completed", results={"data": {"SKU2": {}}}) is True
+
+        job_manifest = json.loads(mock_request.call_args_list[0][1]["payload"])["manifest"]
+        assert job_manifest["manifest"]["job_id"] == "job-123"
+        assert job_manifest["manifest"]["chunks"][0]["sha256"] == chunk_digest(results["data"])
+        assert results["results_manifest"] == job_manifest
+        chunk_manifest = json.loads(mock_request.call_args_list[1][1]["payload"])["manifest"]
+        assert chunk_manifest["manifest"]["chunk_id"] == "chunk-1"
+        assert chunk_manifest["manifest"]["total_rows"] == 1
+
+    def test_submit_results_chunked_uploads_gzipped_chunks_and_commits(self, tmp_path):
+        import gzip
+        import json
+
+        results = {"skus_processed": 5, "data": {f"SKU{i}": {"bradley": {"Name": str(i)}} for i in range(5)}}
+
+        def fake_request(method, endpoint, payload=None, extra_headers=None, max_retries=None):
+            if endpoint == "/api/scraper/v1/uploads":
+                return {"upload_id": "up-1"}
+            return {"success": True}
+
+        with patch("core.api_client.UPLOAD_STATE_DIR", tmp_path), patch.object(self.client, "_make_request", side_effect=fake_request) as mock_request:
+            progress = []
+            success = self.client.submit_results_chunked("job-123", results, chunk_rows=2, progress_callback=lambda done, total: progress.append((done, total)))
+
+        assert success is True
+        endpoints = [call[0][1] for call in mock_request.call_args_list]
+        assert endpoints == [
+            "/api/scraper/v1/uploads",
+            "/api/scraper/v1/uploads/up-1/chunks/0",
+            "/api/scraper/v1/uploads/up-1/chunks/1",
+            "/api/scraper/v1/uploads/up-1/chunks/2",
+            "/api/scraper/v1/uploads/up-1/commit",
+        ]
+        first_chunk = json.loads(gzip.decompress(mock_request.call_args_list[1][1]["payload"]))
+        assert list(first_chunk["rows"]) == ["SKU0", "SKU1"]
+        commit = json.loads(mock_request.call_args_list[-1][1]["payload"])
+        assert "data" not in commit["results"]
+        assert commit["upload_stats"]["chunks"] == 3
+        assert commit["manifest"]["manifest"]["total_rows"] == 5
+        assert len(commit["manifest"]["manifest"]["chunks"]) == 3
+        assert results["results_manifest"] == commit["manifest"]
+        assert progress == [(1, 3), (2, 3), (3, 3)]
+        assert not (tmp_path / "job-123.json").exists()
+
+    def test_submit_results_chunked_resumes_after_failed_chunk(self, tmp_path):
+        results = {"data": {f"SKU{i}": {} for i in range(4)}}
+        self.client.max_retries = 0
+        calls = []
+
+        def failing_second_chunk(method, endpoint, payload=None, extra_headers=None, max_retries=None):
+            calls.append(endpoint)
+            if endpoint == "/api/scraper/v1/uploads":
+                return {"upload_id": "up-1"}
+            if endpoint.endswith("/chunks/1"):
+                raise RuntimeError("connection reset")
+            return {"success": True}
+
+        with patch("core.api_client.UPLOAD_STATE_DIR", tmp_path), patch.object(self.client, "_make_request", side_effect=failing_second_chunk):
+            assert self.client.submit_results_chunked("job-123", results, chunk_rows=2) is False
+        assert (tmp_path / "job-123.json").exists()
+
+        calls.clear()
+        with patch("core.api_client.UPLOAD_STATE_DIR", tmp_path), patch.object(self.client, "_make_request", return_value={"success": True}) as mock_request:
+            assert self.client.submit_results_chunked("job-123", results, chunk_rows=2) is True
+
+        endpoints = [call[0][1] for call in mock_request.call_args_list]
+        assert endpoints == ["/api/scraper/v1/uploads/up-1/chunks/1", "/api/scraper/v1/uploads/up-1/commit"]
+
+    def test_upload_state_for_different_data_is_not_resumed(self, tmp_path):
+        self.client.max_retries = 0
+
+        def failing_second_chunk(method, endpoint, payload=None, extra_headers=None, max_retries=None):
+            if endpoint == "/api/scraper/v1/uploads":
+                return {"upload_id": "up-1"}
+            if endpoint.endswith("/chunks/1"):
+                raise RuntimeError("connection reset")
+            return {"success": True}
+
+        with patch("core.api_client.UPLOAD_STATE_DIR", tmp_path), patch.object(self.client, "_make_request", side_effect=failing_second_chunk):
+            assert self.client.submit_results_chunked("job-123", {"data": {f"SKU{i}": {} for i in range(4)}}, chunk_rows=2) is False
+
+        rerun = {"data": {f"OTHER{i}": {} for i in range(4)}}
+        with patch("core.api_client.UPLOAD_STATE_DIR", tmp_path), patch.object(self.client, "_make_request", return_value={"upload_id": "up-2"}) as mock_request:
+            assert self.client.submit_results_chunked("job-123", rerun, chunk_rows=2) is True
+
+        endpoints = [call[0][1] for call in mock_request.call_args_list]
+        assert endpoints[0] == "/api/scraper/v1/uploads"
+        assert "/api/scraper/v1/uploads/up-2/chunks/0" in endpoints
+
+    @pytest.mark.parametrize("expired_endpoint", ["/api/scraper/v1/uploads/up-1/chunks/1", "/api/scraper/v1/uploads/up-1/commit"])
+    def test_expired_upload_session_is_started_over(self, tmp_path, expired_endpoint):
+        results = {"data": {f"SKU{i}": {} for i in range(4)}}
+        sessions = iter(["up-1", "up-2"])
+        calls = []
+
+        def expire_session(method, endpoint, payload=None, extra_headers=None, max_retries=None):
+            calls.append(endpoint)
+            if endpoint == "/api/scraper/v1/uploads":
+                return {"upload_id": next(sessions)}
+            if endpoint == expired_endpoint:
+                raise httpx.HTTPStatusError("Gone", request=MagicMock(), response=MagicMock(status_code=410, text="upload expired"))
+            return {"success": True}
+
+        with patch("core.api_client.UPLOAD_STATE_DIR", tmp_path), patch.object(self.client, "_make_request", side_effect=expire_session):
+            assert self.client.submit_results_chunked("job-123", results, chunk_rows=2) is True
+
+        restart = calls.index("/api/scraper/v1/uploads", 1)
+        assert calls[restart + 1 :] == [
+            "/api/scraper/v1/uploads/up-2/chunks/0",
+            "/api/scraper/v1/uploads/up-2/chunks/1",
+            "/api/scraper/v1/uploads/up-2/commit",
+        ]
+        assert not (tmp_path / "job-123.json").exists()
+
+    def test_rows_named_in_validation_error_are_set_aside(self, tmp_path):
+        import gzip
+
+        results = {"data": {f"SKU{i}": {"bradley": {"Name": str(i)}} for i in range(4)}}
+        rejections = ServerRejections(tmp_path / "rejections")
+        chunk_payloads = []
+
+        def reject_sku1(method, endpoint, payload=None, extra_headers=None, max_retries=None):
+            if endpoint == "/api/scraper/v1/uploads":
+                return {"upload_id": "up-1"}
+            if endpoint.endswith("/chunks/0"):
+                rows = json.loads(gzip.decompress(payload))["rows"]
+                chunk_payloads.append(list(rows))
+                if "SKU1" in rows:
+                    response = MagicMock(status_code=422, json=MagicMock(return_value={"rejected_rows": [{"sku": "SKU1", "reason": "weight is not a number"}]}))
+                    raise httpx.HTTPStatusError("Unprocessable", request=MagicMock(), response=response)
+            return {"success": True}
+
+        with (
+            patch("core.api_client.UPLOAD_STATE_DIR", tmp_path),
+            patch("core.api_client.server_rejections", rejections),
+            patch.object(self.client, "_make_request", side_effect=reject_sku1) as mock_request,
+        ):
+            assert self.client.submit_results_chunked("job-123", results, chunk_rows=2) is True
+
+        assert chunk_payloads == [["SKU0", "SKU1"], ["SKU0"]]
+        assert [entry["sku"] for entry in rejections.get("job-123")] == ["SKU1"]
+        assert rejections.get("job-123")[0]["reason"] == "weight is not a number"
+        commit = json.loads(mock_request.call_args_list[-1][1]["payload"])
+        assert commit["results"]["server_rejections"]["rows"] == 1
+        # The manifest covers the rows the coordinator accepted, not the set-aside one
+        manifest = commit["manifest"]["manifest"]
+        assert manifest["total_rows"] == 3
+        assert manifest["chunks"][0]["sha256"] == chunk_digest({"SKU0": {"bradley": {"Name": "0"}}})
+        assert manifest["set_aside"] == ["SKU1"]
+        assert "SKU1" not in results["data"]
+        assert verify_data(manifest, results["data"]) == []
+
+    def test_rows_set_aside_before_an_interruption_stay_out_of_the_manifest(self, tmp_path):
+        results = {"data": {f"SKU{i}": {} for i in range(4)}}
+        rejections = ServerRejections(tmp_path / "rejections")
+        self.client.max_retries = 0
+
+        def reject_sku1_then_drop(method, endpoint, payload=None, extra_headers=None, max_retries=None):
+            if endpoint == "/api/scraper/v1/uploads":
+                return {"upload_id": "up-1"}
+            if endpoint.endswith("/chunks/0") and "SKU1" in json.loads(gzip.decompress(payload))["rows"]:
+                response = MagicMock(status_code=422, json=MagicMock(return_value={"rejected_rows": [{"sku": "SKU1"}]}))
+                raise httpx.HTTPStatusError("Unprocessable", request=MagicMock(), response=response)
+            if endpoint.endswith("/chunks/1"):
+                raise RuntimeError("connection reset")
+            return {"success": True}
+
+        with (
+            patch("core.api_client.UPLOAD_STATE_DIR", tmp_path),
+            patch("core.api_client.server_rejections", rejections),
+            patch.object(self.client, "_make_request", side_effect=reject_sku1_then_drop),
+        ):
+            assert self.client.submit_results_chunked("job-123", results, chunk_rows=2) is False
+        assert json.loads((tmp_path / "job-123.json").read_text())["set_aside"] == {"0": ["SKU1"]}
+
+        with (
+            patch("core.api_client.UPLOAD_STATE_DIR", tmp_path),
+            patch("core.api_client.server_rejections", rejections),
+            patch.object(self.client, "_make_request", return_value={"success": True}) as mock_request,
+        ):
+            assert self.client.submit_results_chunked("job-123", results, chunk_rows=2) is True
+
+        manifest = json.loads(mock_request.call_args_list[-1][1]["payload"])["manifest"]["manifest"]
+        assert [chunk["rows"] for chunk in manifest["chunks"]] == [1, 2]
+        assert manifest["set_aside"] == ["SKU1"]
+        assert verify_data(manifest, results["data"]) == []
+
+    def test_unnamed_validation_error_fails_the_chunk(self, tmp_path):
+        results = {"data": {f"SKU{i}": {} for i in range(4)}}
+        rejections = ServerRejections(tmp_path / "rejections")
+
+        def reject_second_chunk(method, endpoint, payload=None, extra_headers=None, max_retries=None):
+            if endpoint == "/api/scraper/v1/uploads":
+                return {"upload_id": "up-1"}
+            if endpoint.endswith("/chunks/1"):
+                response = MagicMock(status_code=400, json=MagicMock(return_value={"error": "malformed rows"}))
+                raise httpx.HTTPStatusError("Bad Request", request=MagicMock(), response=response)
+            return {"success": True}
+
+        with (
+            patch("core.api_client.UPLOAD_STATE_DIR", tmp_path),
+            patch("core.api_client.server_rejections", rejections),
+            patch.object(self.client, "_make_request", side_effect=reject_second_chunk) as mock_request,
+        ):
+            assert self.client.submit_results_chunked("job-123", results, chunk_rows=2) is False
+
+        assert rejections.get("job-123") == []
+        assert not any(call[0][1].endswith("/commit") for call in mock_request.call_args_list)
+        state = json.loads((tmp_path / "job-123.json").read_text())
+        assert state["upload_id"] == "up-1"
+        assert state["acked"] == [0]
+
+    def test_submit_results_retries_without_named_rows(self, tmp_path):
+        results = {"data": {"SKU1": {}, "SKU2": {}}}
+        rejections = ServerRejections(tmp_path)
+        response = MagicMock(status_code=422, json=MagicMock(return_value={"errors": [{"sku": "SKU2", "message": "missing title"}]}))
+        rejected = httpx.HTTPStatusError("Unprocessable", request=MagicMock(), response=response)
+
+        with patch("core.api_client.server_rejections", rejections), patch.object(
+            self.client, "_make_request", side_effect=[rejected, {"success": True}]
+        ) as mock_request:
+            assert self.client.submit_results("job-123", "completed", results=results) is True
+
+        retried = json.loads(mock_request.call_args_list[-1][1]["payload"])
+        assert list(retried["results"]["data"]) == ["SKU1"]
+        assert retried["results"]["server_rejections"]["skus"] == ["SKU2"]
+
+    def test_single_request_and_chunked_manifests_cover_the_same_rows(self, tmp_path):
+        def reject_sku1(method, endpoint, payload=None, extra_headers=None, max_retries=None):
+            if endpoint == "/api/scraper/v1/uploads":
+                return {"upload_id": "up-1"}
+            body = json.loads(gzip.decompress(payload) if extra_headers else payload)
+            rows = body.get("rows") or body.get("results", {}).get("data") or {}
+            if "SKU1" in rows:
+                response = MagicMock(status_code=422, json=MagicMock(return_value={"rejected_rows": [{"sku": "SKU1", "reason": "missing title"}]}))
+                raise httpx.HTTPStatusError("Unprocessable", request=MagicMock(), response=response)
+            return {"success": True}
+
+        outcomes = {}
+        for path, chunk_rows in (("single", 10), ("chunked", 2)):
+            results = {"data": {f"SKU{i}": {"bradley": {"Name": str(i)}} for i in range(4)}}
+            with (
+                patch("core.api_client.UPLOAD_STATE_DIR", tmp_path / path),
+                patch("core.api_client.server_rejections", ServerRejections(tmp_path / path / "rejections")),
+                patch.object(self.client, "_make_request", side_effect=reject_sku1),
+            ):
+                assert self.client.submit_results_chunked("job-123", results, chunk_rows=chunk_rows) is True
+            manifest = results["results_manifest"]["manifest"]
+            assert verify_data(manifest, results["data"]) == []
+            outcomes[path] = (results["data"], manifest["total_rows"], manifest.get("set_aside"))
+
+        assert len(results["results_manifest"]["manifest"]["chunks"]) == 2
+        assert outcomes["single"] == outcomes["chunked"]
+        assert outcomes["single"][1:] == (3, ["SKU1"])
+
+    def test_release_chunk_sends_reason_and_lease(self):
+        with patch.object(self.client, "_make_request", return_value={"success": True}) as mock_request:
+            assert self.client.release_chunk("chunk-1", "preflight: browser", lease_token="lease-1") is True
+
+        assert mock_request.call_args[0][1] == "/api/scraper/v1/release-chunk"
+        payload = json.loads(mock_request.call_args.kwargs["payload"])
+        assert payload["reason"] == "preflight: browser"
+        assert payload["lease_token"] == "lease-1"
+
+    def test_release_chunk_unsupported_returns_false(self):
+        not_found = httpx.HTTPStatusError("Not Found", request=MagicMock(), response=MagicMock(status_code=404, text=""))
+
+        with patch.object(self.client, "_make_request", side_effect=not_found):
+            assert self.client.release_chunk("chunk-1", "disk_full") is False
+
     def test_claim_chunk_returns_typed_claimed_chunk(self):
         mock_response = MagicMock()
         mock_response.status_code = 200
@@ -192,6 +484,33 @@ class TestScraperAPIClient:
             assert claimed.chunk_id == "chunk-1"
             assert claimed.job_id == "job-1"
             assert claimed.skus == ["SKU001"]
+            assert claimed.pending_chunks is None
+
+    def test_claim_chunk_reads_pending_chunk_count(self):
+        mock_response = MagicMock()
+        mock_response.status_code = 200
+        mock_response.json.return_value = {"chunk": {"chunk_id": "chunk-1", "job_id": "job-1", "skus": ["SKU001"]}, "pending_chunks": 7}
+
+        with patch("httpx.Client") as mock_client:
+            mock_client.return_value.__enter__.return_value.post.return_value = mock_response
+
+            claimed = self.client.claim_chunk("runner-1")
+
+        assert claimed is not None
+        assert claimed.pending_chunks == 7
+
+    def test_server_errors_do_not_count_as_reachable(self):
+        health = RunnerHealth(api_unreachable_minutes=5)
+        response = MagicMock(status_code=503, text="Service Unavailable")
+        response.raise_for_status.side_effect = httpx.HTTPStatusError("Service Unavailable", request=MagicMock(), response=response)
+
+        with patch("core.api_client.runner_health", health), patch("httpx.Client") as mock_client:
+            mock_client.return_value.__enter__.return_value.get.return_value = response
+            with pytest.raises(httpx.HTTPStatusError):
+                self.client._make_request("GET", "/api/scraper/v1/health", max_retries=0)
+
+        assert health.last_api_success is None
+        assert health.api_unreachable_since is not None
 
     def test_claim_chunk_returns_none_when_no_chunk(self):
         mock_response = MagicMock()
@@ -205,6 +524,67 @@ class TestScraperAPIClient:
             claimed = self.client.claim_chunk("runner-1")
             assert claimed is None
 
+    def test_heartbeat_omits_metrics_by_default(self):
+        with patch.object(self.client, "_make_request", return_value={}) as mock_request:
+            self.client.heartbeat()
+
+        payload = json.loads(mock_request.call_args.kwargs["payload"])
+        assert "metrics" not in payload
+
+    def test_heartbeat_includes_version_info(self):
+        with patch.object(self.client, "_make_request", return_value={}) as mock_request:
+            self.client.heartbeat()
+
+        payload = json.loads(mock_request.call_args.kwargs["payload"])
+        assert payload["version_info"]["python_version"]
+        assert "sidecar_version" in payload["version_info"]
+
+    def test_heartbeat_and_uploads_carry_runner_tags(self):
+        self.client.runner_tags = {"location_tag": "lancaster", "labels": ["retail"]}
+
+        with patch.object(self.client, "_make_request", return_value={}) as mock_request:
+            self.client.heartbeat()
+            heartbeat = json.loads(mock_request.call_args.kwargs["payload"])
+            self.client.submit_chunk_results("chunk-1", "completed", results={"data": {}})
+            chunk = json.loads(mock_request.call_args.kwargs["payload"])
+
+        for payload in (heartbeat, chunk):
+            assert payload["location_tag"] == "lancaster"
+            assert payload["labels"] == ["retail"]
+
+    def test_heartbeat_carries_failover_priority(self):
+        with patch.object(self.client, "_make_request", return_value={}) as mock_request:
+            self.client.heartbeat()
+            assert "failover_priority" not in json.loads(mock_request.call_args.kwargs["payload"])
+            self.client.failover_priority = 1
+            self.client.heartbeat()
+
+        assert json.loads(mock_request.call_args.kwargs["payload"])["failover_priority"] == 1
+
+    def test_heartbeat_pushes_metrics_at_interval(self):
+        self.client.metrics_push_enabled = True
+        self.client.metrics_push_interval = 300
+
+        with patch.object(self.client, "_make_request", return_value={}) as mock_request:
+            self.client.heartbeat()
+            first = json.loads(mock_request.call_args.kwargs["payload"])
+            self.client.heartbeat()
+            second = json.loads(mock_request.call_args.kwargs["payload"])
+
+        assert "series" in first["metrics"]
+        assert "metrics" not in second
+
+    def test_failed_heartbeat_resends_metrics(self):
+        self.client.metrics_push_enabled = True
+        self.client.metrics_push_interval = 300
+
+        with patch.object(self.client, "_make_request", side_effect=RuntimeError("connection reset")):
+            assert self.client.heartbeat() is False
+        with patch.object(self.client, "_make_request", return_value={}) as mock_request:
+            self.client.heartbeat()
+
+        assert "metrics" in json.loads(mock_request.call_args.kwargs["payload"])
+
 
 class TestRetryLogic:
     """Tests for retry logic with exponential backoff."""
@@ -424,6 +804,130 @@ class TestRetryLogic:
             assert mock_instance.get.call_count == 2
 
 
+class TestCapabilityNegotiation:
+    def setup_method(self):
+        self.client = ScraperAPIClient(
+            api_url="https://app.example.com",
+            api_key="test-api-key",
+            runner_name="test-runner",
+        )
+
+    def test_missing_endpoint_keeps_legacy_behavior(self):
+        not_found = httpx.HTTPStatusError("Not Found", request=MagicMock(), response=MagicMock(status_code=404))
+
+        with patch.object(self.client, "_make_request", side_effect=not_found):
+            assert self.client.negotiate_capabilities() is None
+
+        assert self.client.supports("uploads") is True
+        assert self.client._endpoint("heartbeat", "/api/scraper/v1/heartbeat") == "/api/scraper/v1/heartbeat"
+
+    def test_renamed_endpoints_are_used(self):
+        capabilities = {
+            "version": "2.0.0",
+            "upload_schema_versions": ["1", "2"],
+            "endpoints": {"heartbeat": "/api/scraper/v2/heartbeat", "poll": None},
+        }
+
+        with patch.object(self.client, "_make_request", return_value=capabilities):
+            self.client.negotiate_capabilities()
+
+        with patch.object(self.client, "_make_request", return_value={}) as mock_request:
+            self.client.heartbeat()
+
+        assert mock_request.call_args.args[1] == "/api/scraper/v2/heartbeat"
+        assert self.client._endpoint("poll", "/api/scraper/v1/poll") == "/api/scraper/v1/poll"
+        assert self.client.supports("uploads") is False
+
+    def test_unsupported_uploads_fall_back_to_single_request(self):
+        with patch.object(self.client, "_make_request", return_value={"endpoints": ["heartbeat", "callback"]}):
+            self.client.negotiate_capabilities()
+
+        results = {"data": {f"SKU{i}": {} for i in range(10)}}
+        with patch.object(self.client, "submit_results", return_value=True) as mock_submit:
+            assert self.client.submit_results_chunked("job-1", results, chunk_rows=2) is True
+
+        mock_submit.assert_called_once()
+
+    def test_rejects_server_requiring_newer_runner(self):
+        with patch("core.api_client.read_version", return_value="v1.4.0"):
+            with patch.object(self.client, "_make_request", return_value={"min_runner_version": "1.10.0"}):
+                with pytest.raises(IncompatibleServerError) as exc_info:
+                    self.client.negotiate_capabilities()
+
+        assert "requires runner >= 1.10.0" in str(exc_info.value)
+
+    @pytest.mark.parametrize(
+        "version, expected",
+        [
+            ("v1.2.3", (1, 2, 3)),
+            ("1.2.3-beta", (1, 2, 3)),
+            ("1.4.0-rc1", (1, 4, 0)),
+            ("2.0rc10", (2, 0)),
+            ("1.4.0-rc1.2", (1, 4, 0)),
+            ("2.0.0b3", (2, 0, 0)),
+        ],
+    )
+    def test_version_tuple_ignores_pre_release_digits(self, version, expected):
+        assert _version_tuple(version) == expected
+
+    def test_release_candidate_satisfies_older_minimum(self):
+        with patch("core.api_client.read_version", return_value="1.4.0-rc1"):
+            with patch.object(self.client, "_make_request", return_value={"min_runner_version": "1.3.9"}):
+                self.client.negotiate_capabilities()
+
+    def test_rejects_unsupported_upload_schema(self):
+        with patch.object(self.client, "_make_request", return_value={"upload_schema_versions": ["3"]}):
+            with pytest.raises(IncompatibleServerError):
+                self.client.negotiate_capabilities()
+
+
+class TestProvenanceCheck:
+    def setup_method(self):
+        self.client = ScraperAPIClient(api_url="https://app.example.com", api_key="test-api-key", runner_name="test-runner")
+        with patch.object(self.client, "_make_request", return_value={"config_hashes": {"phillips": ["abc123"]}}):
+            self.client.negotiate_capabilities()
+
+    def results(self, config_hash: str) -> dict:
+        return {"data": {"SKU1": {"phillips": {"title": "Dog Food"}}}, "provenance": {"scrapers": {"phillips": {"config_hash": config_hash}, "orgill": {"config_hash": "zzz"}}}}
+
+    def test_accepted_config_hash_passes(self):
+        self.client.check_provenance(self.results("abc123"))
+
+    def test_stale_config_hash_is_refused(self):
+        with pytest.raises(StaleScraperConfigError, match="Stale scraper config.*phillips \\(old999\\)"):
+            self.client.check_provenance(self.results("old999"))
+
+    def test_stale_results_are_reported_as_failure_without_data(self):
+        with patch.object(self.client, "_make_request", return_value={}) as mock_request:
+            assert self.client.submit_results("job-1", "completed", results=self.results("old999")) is False
+
+        payload = json.loads(mock_request.call_args.kwargs["payload"])
+        assert payload["status"] == "failed"
+        assert "results" not in payload
+        assert "Stale scraper config" in payload["error_message"]
+
+    def test_stale_chunk_results_are_refused(self):
+        with patch.object(self.client, "_make_request", return_value={}) as mock_request:
+            assert self.client.submit_chunk_results("chunk-1", "completed", results=self.results("old999")) is False
+
+        payload = json.loads(mock_request.call_args.kwargs["payload"])
+        assert payload["status"] == "failed"
+        assert "results" not in payload
+
+    def test_stale_chunk_progress_is_not_sent(self):
+        with patch.object(self.client, "_make_request", return_value={}) as mock_request:
+            assert self.client.submit_chunk_progress("chunk-1", "SKU1", "phillips", {"title": "Dog Food"}, config_hash="old999") is False
+            assert self.client.submit_chunk_progress("chunk-1", "SKU1", "phillips", {"title": "Dog Food"}, config_hash="abc123") is True
+
+        assert mock_request.call_count == 1
+
+    def test_stale_chunked_results_log_the_refusal_once(self):
+        with patch.object(self.client, "_make_request", return_value={}), patch("core.api_client.logger") as mock_logger:
+            assert self.client.submit_results_chunked("job-1", self.results("old999"), chunk_rows=1) is False
+
+        assert mock_logger.error.call_count == 1
+
+
 class TestHealthCheck:
     """Tests for health check functionality."""
 
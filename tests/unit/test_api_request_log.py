@@ -0,0 +1,100 @@
+from unittest.mock import MagicMock, patch
+
+import httpx
+import pytest
+
+from core.api_client import ConnectionError, ScraperAPIClient
+from core.api_request_log import ApiRequestLog
+from core.events import EventType
+
+
+class TestApiRequestLog:
+    def test_keeps_only_the_latest_entries(self):
+        log = ApiRequestLog(max_entries=3, stream=False)
+        for i in range(5):
+            log.record("GET", f"/api/poll/{i}", 200, 12.0)
+
+        assert [entry["path"] for entry in log.entries()] == ["/api/poll/2", "/api/poll/3", "/api/poll/4"]
+
+    def test_query_string_is_dropped(self):
+        log = ApiRequestLog(stream=False)
+
+        entry = log.record("get", "/api/credentials?token=secret", 200, 5.0)
+
+        assert entry["path"] == "/api/credentials"
+        assert entry["method"] == "GET"
+
+    def test_success_rate(self):
+        log = ApiRequestLog(stream=False)
+        assert log.success_rate() is None
+
+        log.record("POST", "/api/heartbeat", 200, 10.0)
+        log.record("POST", "/api/heartbeat", 503, 10.0, retries=3, error="HTTPStatusError")
+        log.record("POST", "/api/heartbeat", None, 10.0, error="ConnectError")
+        log.record("POST", "/api/heartbeat", 200, 10.0)
+
+        assert log.success_rate() == 0.5
+
+    def test_entries_are_streamed_when_enabled(self):
+        log = ApiRequestLog(stream=True)
+
+        with patch("core.api_request_log.event_bus") as bus:
+            log.record("POST", "/api/heartbeat", 200, 10.0)
+
+        event = bus.emit.call_args[0][0]
+        assert event.event_type == EventType.API_REQUEST
+        assert event.data["path"] == "/api/heartbeat"
+
+
+class TestClientRecordsRequests:
+    def setup_method(self):
+        self.client = ScraperAPIClient(api_url="https://app.example.com", api_key="test-api-key", runner_name="test-runner")
+        self.log = ApiRequestLog(stream=False)
+
+    def test_successful_request_is_recorded_without_bodies(self):
+        response = MagicMock(status_code=200)
+        response.json.return_value = {"ok": True}
+
+        with patch("core.api_client.api_request_log", self.log), patch("httpx.Client") as mock_client:
+            mock_client.return_value.__enter__.return_value.post.return_value = response
+            self.client._make_request("POST", "/api/scraper/v1/heartbeat", payload='{"secret": "x"}')
+
+        (entry,) = self.log.entries()
+        assert entry["status"] == 200
+        assert entry["retries"] == 0
+        assert entry["ok"] is True
+        assert "secret" not in str(entry)
+
+    def test_failed_request_records_error_class_and_retries(self):
+        self.client.max_retries = 1
+        error_response = MagicMock(status_code=503, text="unavailable")
+        error_response.raise_for_status.side_effect = httpx.HTTPStatusError("Unavailable", request=MagicMock(), response=error_response)
+
+        with (
+            patch("core.api_client.api_request_log", self.log),
+            patch("httpx.Client") as mock_client,
+            patch("time.sleep"),
+            pytest.raises(httpx.HTTPStatusError),
+        ):
+            mock_client.return_value.__enter__.return_value.post.return_value = error_response
+            self.client._make_request("POST", "/api/scraper/v1/heartbeat")
+
+        (entry,) = self.log.entries()
+        assert entry["status"] == 503
+        assert entry["retries"] == 1
+        assert entry["error"] == "HTTPStatusError"
+        assert entry["ok"] is False
+
+    def test_health_check_is_recorded(self):
+        with patch("core.api_client.api_request_log", self.log), patch("httpx.Client") as mock_client:
+            mock_get = mock_client.return_value.__enter__.return_value.get
+            mock_get.return_value = MagicMock(status_code=200)
+            self.client.health_check()
+
+            mock_get.side_effect = httpx.NetworkError("Connection refused")
+            with pytest.raises(ConnectionError):
+                self.client.health_check()
+
+        ok, failed = self.log.entries()
+        assert (ok["method"], ok["path"], ok["status"], ok["ok"]) == ("GET", "/api/health", 200, True)
+        assert (failed["status"], failed["error"], failed["ok"]) == (None, "NetworkError", False)
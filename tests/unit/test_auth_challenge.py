@@ -0,0 +1,127 @@
+import asyncio
+import json
+import threading
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+
+from core.auth_challenge import AuthChallenges
+from scrapers.actions.handlers.input import InputTextAction
+from scrapers.actions.handlers.login import LoginAction
+from scrapers.exceptions import WorkflowExecutionError
+from scrapers.models.config import ScraperConfig
+
+
+def respond_later(challenges: AuthChallenges, line: str) -> None:
+    threading.Timer(0.05, challenges.handle_line, args=(line,)).start()
+
+
+class TestAuthChallenges:
+    def setup_method(self):
+        self.challenges = AuthChallenges()
+
+    def test_response_reaches_waiting_login(self):
+        respond_later(self.challenges, json.dumps({"type": "auth_response", "scraper": "phillips", "value": " 123456 "}))
+
+        assert asyncio.run(self.challenges.wait_for_response("phillips", timeout=5)) == "123456"
+
+    def test_scraper_can_be_omitted_with_one_challenge_waiting(self):
+        respond_later(self.challenges, json.dumps({"type": "auth_response", "value": "654321"}))
+
+        assert asyncio.run(self.challenges.wait_for_response("phillips", timeout=5)) == "654321"
+
+    def test_times_out_without_response(self):
+        assert asyncio.run(self.challenges.wait_for_response("phillips", timeout=0.05)) is None
+
+    def test_stale_response_does_not_answer_a_new_challenge(self):
+        self.challenges.submit("phillips", "111111")
+
+        assert asyncio.run(self.challenges.wait_for_response("phillips", timeout=0.05)) is None
+
+    def test_ignores_other_and_malformed_lines(self):
+        assert self.challenges.handle_line(json.dumps({"type": "pause"})) is False
+        assert self.challenges.handle_line(json.dumps({"type": "auth_response", "value": "123456"})) is False  # nothing waiting
+        assert self.challenges.handle_line(json.dumps({"type": "auth_response", "scraper": "phillips", "value": ""})) is False
+
+    def test_needs_interactive_job_and_control_channel(self):
+        with patch("core.auth_challenge.pacing_control") as control:
+            control.listening = True
+            assert self.challenges.can_answer({"interactive_auth": True}) is True
+            assert self.challenges.can_answer({}) is False
+            control.listening = False
+            assert self.challenges.can_answer({"interactive_auth": True}) is False
+
+
+class TestLoginOtp:
+    def setup_method(self):
+        self.ctx = MagicMock()
+        self.ctx.config = ScraperConfig(name="phillips", base_url="https://example.com")
+        self.ctx.context = {"interactive_auth": True}
+        self.ctx.is_session_authenticated.return_value = False
+        self.ctx.record_login = AsyncMock()
+        self.ctx.browser.page.content = AsyncMock(return_value="<form></form>")
+        self.steps = []
+        self.hidden = set()
+        self.params = {
+            "url": "https://example.com/login",
+            "username": "buyer",
+            "password": "secret",
+            "username_field": "#user",
+            "password_field": "#pass",
+            "submit_button": "#login",
+            "otp_field": "#code",
+            "otp_submit_button": "#verify",
+            "otp_timeout_seconds": 60,
+        }
+
+    def run_login(self, otp_shown: bool = True, can_answer: bool = True, code: str | None = "123456"):
+        async def execute_step(step):
+            self.steps.append((step.action, step.params.get("selector"), step.params.get("text")))
+            if step.params.get("sensitive"):
+                self.hidden.add(step.params["selector"])
+            if step.action == "wait_for" and step.params.get("selector") == "#code" and not otp_shown:
+                raise TimeoutError("no code field")
+
+        self.ctx._execute_step = AsyncMock(side_effect=execute_step)
+        with patch("scrapers.actions.handlers.login.auth_challenges") as challenges, patch("scrapers.actions.handlers.login.portal_fingerprints"):
+            challenges.can_answer.return_value = can_answer
+            challenges.wait_for_response = AsyncMock(return_value=code)
+            asyncio.run(LoginAction(self.ctx).execute(self.params))
+        return challenges
+
+    def test_code_is_entered_and_submitted(self):
+        self.run_login()
+
+        assert ("input_text", "#code", "123456") in self.steps
+        assert ("click", "#verify", None) in self.steps
+        self.ctx.event_emitter.auth_challenge.assert_called_once_with(scraper="phillips", challenge_type="otp", timeout_seconds=60)
+        self.ctx.mark_session_authenticated.assert_called_once()
+
+    def test_code_and_password_are_kept_out_of_the_logs(self):
+        self.run_login()
+
+        assert self.hidden == {"#pass", "#code"}
+
+        element = MagicMock(fill=AsyncMock())
+        self.ctx.find_element_safe = AsyncMock(return_value=element)
+        with patch("scrapers.actions.handlers.input.logger") as logger:
+            asyncio.run(InputTextAction(self.ctx).execute({"selector": "#code", "text": "123456", "sensitive": True}))
+
+        element.fill.assert_awaited_once_with("123456")
+        assert "123456" not in logger.debug.call_args[0][0]
+
+    def test_no_challenge_logs_in_normally(self):
+        challenges = self.run_login(otp_shown=False)
+
+        challenges.wait_for_response.assert_not_called()
+        self.ctx.mark_session_authenticated.assert_called_once()
+
+    def test_fails_fast_when_nobody_can_answer(self):
+        with pytest.raises(WorkflowExecutionError, match="Interactive auth required"):
+            self.run_login(can_answer=False)
+
+    def test_fails_when_code_times_out(self):
+        with pytest.raises(WorkflowExecutionError, match="No one-time code"):
+            self.run_login(code=None)
+
+        self.ctx.mark_session_authenticated.assert_not_called()
@@ -0,0 +1,115 @@
+from datetime import date
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from core.models import AvailabilityStatus, normalize_availability, parse_restock_date
+from runner import availability_tester, run_job
+from scrapers.models.config import AvailabilityMapping, ScraperConfig
+
+TODAY = date(2026, 3, 2)
+
+
+class TestNormalizeAvailability:
+    @pytest.mark.parametrize(
+        "raw, status, quantity, restock_date",
+        [
+            # PetFoodExperts
+            ("In Stock", AvailabilityStatus.IN_STOCK, None, None),
+            ("Out of Stock", AvailabilityStatus.OUT_OF_STOCK, None, None),
+            ("Backordered til 3/15", AvailabilityStatus.BACKORDERED, None, date(2026, 3, 15)),
+            ("Discontinued", AvailabilityStatus.DISCONTINUED, None, None),
+            ("Call", AvailabilityStatus.CALL_FOR_AVAILABILITY, None, None),
+            ("Only 3 left", AvailabilityStatus.IN_STOCK, 3, None),
+            # Phillips
+            ("QTY 12+", AvailabilityStatus.IN_STOCK, 12, None),
+            ("Qty: 0", AvailabilityStatus.OUT_OF_STOCK, 0, None),
+            ("  Not  currently available ", AvailabilityStatus.OUT_OF_STOCK, None, None),
+            ("Back-order - expected Jan 5", AvailabilityStatus.BACKORDERED, None, date(2027, 1, 5)),
+            ("Backorder ETA 2026-04-01", AvailabilityStatus.BACKORDERED, None, date(2026, 4, 1)),
+            ("No longer carried", AvailabilityStatus.DISCONTINUED, None, None),
+            ("Call for availability", AvailabilityStatus.CALL_FOR_AVAILABILITY, None, None),
+        ],
+    )
+    def test_heuristics(self, raw, status, quantity, restock_date):
+        parsed = normalize_availability(raw, today=TODAY)
+
+        assert parsed.status == status
+        assert parsed.quantity == quantity
+        assert parsed.restock_date == restock_date
+        assert parsed.raw == raw
+        assert parsed.matched_rule is None
+
+    def test_empty_and_unrecognised_values(self):
+        assert normalize_availability(None) is None
+        assert normalize_availability("   ") is None
+        assert normalize_availability("See store") is None
+
+    def test_rules_take_priority_and_capture_groups(self):
+        rules = [
+            (r"^W(?P<quantity>\d+)$", "in_stock"),
+            (r"ships (?P<restock_date>\d{1,2}/\d{1,2})", "backordered"),
+            (r"in stock", "call_for_availability"),
+        ]
+
+        assert normalize_availability("W24", rules).quantity == 24
+        backorder = normalize_availability("Ships 4/20", rules, today=TODAY)
+        assert (backorder.status, backorder.restock_date, backorder.matched_rule) == (AvailabilityStatus.BACKORDERED, date(2026, 4, 20), 1)
+        assert normalize_availability("In Stock", rules).status == AvailabilityStatus.CALL_FOR_AVAILABILITY
+
+    def test_restock_date_without_year_is_the_next_occurrence(self):
+        assert parse_restock_date("til 3/15", today=TODAY) == date(2026, 3, 15)
+        assert parse_restock_date("til 2/15", today=TODAY) == date(2027, 2, 15)
+        assert parse_restock_date("til 2/15/27", today=TODAY) == date(2027, 2, 15)
+        assert parse_restock_date("til 13/45", today=TODAY) is None
+
+
+class TestAvailabilityMappingConfig:
+    def test_invalid_pattern_is_rejected(self):
+        with pytest.raises(ValueError, match="Invalid availability pattern"):
+            AvailabilityMapping(pattern="(", status="in_stock")
+
+    def test_tester_reports_matches_and_unmapped(self):
+        config = ScraperConfig(
+            name="phillips",
+            base_url="https://example.com",
+            availability_mappings=[AvailabilityMapping(pattern=r"^W(?P<quantity>\d+)$", status="in_stock")],
+        )
+
+        result = availability_tester.test_availability_mapping(config, ["W24", "In Stock", "See store"], today=TODAY)
+
+        assert [r["matched_by"] for r in result["results"][:2]] == ["rule 0", "heuristic"]
+        assert result["results"][0]["quantity"] == 24
+        assert result["unmapped"] == ["See store"]
+
+
+class TestRunJobAvailability:
+    def test_records_carry_status_and_unmapped_strings_are_reported(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        availability = {"SKU1": "QTY 12+", "SKU2": "See store"}
+        executor = MagicMock()
+        executor.initialize = AsyncMock()
+        executor.browser.quit = AsyncMock()
+        executor.execute_workflow = AsyncMock(
+            side_effect=lambda context, **_: {"success": True, "results": {"Name": "Dog Food", "Availability": availability[context["sku"]]}}
+        )
+        job = JobConfig(
+            job_id="job-1",
+            skus=list(availability),
+            scrapers=[
+                JobScraperConfig(
+                    name="phillips",
+                    base_url="https://example.com",
+                    options={"workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}]},
+                )
+            ],
+        )
+
+        with patch("runner.pacing_control"), patch("runner.WorkflowExecutor", return_value=executor):
+            results = run_job(job, runner_name="test-runner")
+
+        assert results["data"]["SKU1"]["phillips"]["availability_status"] == {"status": "in_stock", "quantity": 12, "restock_date": None}
+        assert "availability_status" not in results["data"]["SKU2"]["phillips"]
+        assert results["unmapped_availability_strings"] == {"phillips": ["See store"]}
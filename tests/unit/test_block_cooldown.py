@@ -0,0 +1,136 @@
+import json
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock, MagicMock, patch
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from core.block_cooldown import BlockCooldowns
+from runner import run_job
+
+NOW = datetime(2026, 3, 2, 9, 0, tzinfo=timezone.utc)
+
+
+class TestBlockCooldowns:
+    def test_consecutive_blocks_escalate(self, tmp_path):
+        cooldowns = BlockCooldowns(tmp_path / "cooldowns.json")
+
+        assert cooldowns.record_blocked("phillips", now=NOW) == NOW + timedelta(hours=2)
+        assert cooldowns.record_blocked("phillips", now=NOW) == NOW + timedelta(hours=8)
+        assert cooldowns.record_blocked("phillips", now=NOW) == NOW + timedelta(hours=24)
+        assert cooldowns.record_blocked("phillips", now=NOW) == NOW + timedelta(hours=24)
+        assert cooldowns.status(now=NOW)["phillips"]["consecutive_blocks"] == 4
+
+    def test_cooldown_expires(self, tmp_path):
+        cooldowns = BlockCooldowns(tmp_path / "cooldowns.json")
+        cooldowns.record_blocked("phillips", now=NOW)
+
+        assert cooldowns.cooldown_until("phillips", now=NOW + timedelta(hours=1)) == NOW + timedelta(hours=2)
+        assert cooldowns.cooldown_until("phillips", now=NOW + timedelta(hours=3)) is None
+        assert cooldowns.cooldown_until("orgill", now=NOW) is None
+
+    def test_success_resets_escalation(self, tmp_path):
+        cooldowns = BlockCooldowns(tmp_path / "cooldowns.json")
+        cooldowns.record_blocked("phillips", now=NOW)
+        cooldowns.record_success("phillips")
+
+        assert cooldowns.record_blocked("phillips", now=NOW) == NOW + timedelta(hours=2)
+
+    def test_clear_and_persistence(self, tmp_path):
+        path = tmp_path / "cooldowns.json"
+        BlockCooldowns(path).record_blocked("phillips", now=NOW)
+
+        reloaded = BlockCooldowns(path)
+        assert reloaded.status(now=NOW)["phillips"]["active"] is True
+        assert reloaded.clear("phillips") is True
+        assert reloaded.clear("phillips") is False
+        assert reloaded.status() == {}
+
+    def test_unreadable_file_is_ignored(self, tmp_path):
+        path = tmp_path / "cooldowns.json"
+        path.write_text("{not json")
+
+        assert BlockCooldowns(path).cooldown_until("phillips") is None
+
+    def test_malformed_entries_do_not_break_status_or_recording(self, tmp_path):
+        path = tmp_path / "cooldowns.json"
+        path.write_text(
+            json.dumps(
+                {
+                    "phillips": "blocked",
+                    "orgill": {"consecutive_blocks": 1, "until": "not-a-date"},
+                    "central": {"consecutive_blocks": "many", "until": None},
+                }
+            )
+        )
+        cooldowns = BlockCooldowns(path)
+
+        assert cooldowns.status(now=NOW) == {
+            "orgill": {"consecutive_blocks": 1, "until": "not-a-date", "active": False},
+            "central": {"consecutive_blocks": "many", "until": None, "active": False},
+        }
+        assert cooldowns.record_blocked("phillips", now=NOW) == NOW + timedelta(hours=2)
+        assert cooldowns.record_blocked("central", now=NOW) == NOW + timedelta(hours=2)
+        assert cooldowns.status(now=NOW)["phillips"]["active"] is True
+
+    def test_save_replaces_the_file_without_leaving_temp_files(self, tmp_path):
+        path = tmp_path / "cooldowns.json"
+        cooldowns = BlockCooldowns(path)
+        cooldowns.record_blocked("phillips", now=NOW)
+        cooldowns.record_blocked("orgill", now=NOW)
+
+        assert sorted(json.loads(path.read_text())) == ["orgill", "phillips"]
+        assert [p.name for p in tmp_path.iterdir()] == ["cooldowns.json"]
+
+    def test_failed_write_keeps_the_previous_file(self, tmp_path):
+        path = tmp_path / "cooldowns.json"
+        cooldowns = BlockCooldowns(path)
+        cooldowns.record_blocked("phillips", now=NOW)
+
+        with patch("core.block_cooldown.os.replace", side_effect=OSError("disk full")):
+            cooldowns.record_blocked("orgill", now=NOW)
+
+        assert list(json.loads(path.read_text())) == ["phillips"]
+        assert [p.name for p in tmp_path.iterdir()] == ["cooldowns.json"]
+
+
+def make_job() -> JobConfig:
+    return JobConfig(
+        job_id="job-1",
+        skus=["SKU1"],
+        scrapers=[
+            JobScraperConfig(
+                name="phillips",
+                base_url="https://example.com",
+                options={"workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}]},
+            )
+        ],
+    )
+
+
+class TestRunJobBlockCooldown:
+    def test_scraper_in_cooldown_is_deferred(self, tmp_path, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        cooldowns = BlockCooldowns(tmp_path / "cooldowns.json")
+        until = cooldowns.record_blocked("phillips")
+
+        with patch("runner.block_cooldowns", cooldowns), patch("runner.WorkflowExecutor") as executor_cls:
+            results = run_job(make_job(), runner_name="test-runner")
+
+        executor_cls.assert_not_called()
+        assert results["scrapers_run"] == []
+        assert results["deferred_scrapers"] == [{"scraper": "phillips", "reason": "block_cooldown", "resume_after": until.isoformat()}]
+
+    def test_blocked_run_starts_cooldown(self, tmp_path, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        cooldowns = BlockCooldowns(tmp_path / "cooldowns.json")
+        executor = MagicMock()
+        executor.initialize = AsyncMock()
+        executor.browser.quit = AsyncMock()
+        executor.execute_workflow = AsyncMock(side_effect=RuntimeError("captcha detected on page"))
+
+        with patch("runner.block_cooldowns", cooldowns), patch("runner.WorkflowExecutor", return_value=executor):
+            results = run_job(make_job(), runner_name="test-runner")
+
+        assert results["failed_skus"][0]["category"] == "captcha_detected"
+        assert results["block_cooldowns"][0]["scraper"] == "phillips"
+        assert cooldowns.cooldown_until("phillips") is not None
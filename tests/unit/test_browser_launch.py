@@ -0,0 +1,90 @@
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from runner import run_job
+from runner.browser_launch import classify_launch_failure
+from runner.local_failures import BrowserLaunchFailed, check_browser_launches, local_failure_reason
+
+MISSING_DEPS = """BrowserType.launch:
+╔══════════════════════════════════════════════════════╗
+║ Host system is missing dependencies to run browsers. ║
+║ Please install them with the following command:      ║
+║                                                      ║
+║     sudo playwright install-deps                     ║
+║                                                      ║
+║ Alternatively, use apt:                              ║
+║     sudo apt-get install libnss3\\                    ║
+║         libatk1.0-0\\                                 ║
+║         libgbm1                                      ║
+║                                                      ║
+║ <3 Playwright Team                                   ║
+╚══════════════════════════════════════════════════════╝"""
+
+SHARED_LIBRARY = (
+    "BrowserType.launch: Target page, context or browser has been closed\n"
+    "/root/.cache/ms-playwright/chromium-1148/chrome-linux/chrome: error while loading shared libraries: "
+    "libnss3.so: cannot open shared object file: No such file or directory"
+)
+
+
+class TestClassifyLaunchFailure:
+    def test_missing_dependencies_lists_apt_packages(self):
+        failure = classify_launch_failure(RuntimeError(MISSING_DEPS))
+
+        assert failure["category"] == "missing_libraries"
+        assert failure["packages"] == ["libnss3", "libatk1.0-0", "libgbm1"]
+        assert failure["remedy"] == "playwright install-deps chromium"
+
+    def test_shared_library_error_names_the_library(self):
+        failure = classify_launch_failure(RuntimeError(SHARED_LIBRARY))
+
+        assert failure["category"] == "missing_libraries"
+        assert failure["missing_libraries"] == ["libnss3.so"]
+        assert failure["packages"] == []
+
+    def test_missing_executable(self):
+        failure = classify_launch_failure("BrowserType.launch: Executable doesn't exist at /ms-playwright/chromium-1148/chrome-linux/chrome")
+
+        assert failure["category"] == "browser_missing"
+        assert failure["remedy"] == "playwright install chromium"
+
+    def test_other_launch_error(self):
+        assert classify_launch_failure("BrowserType.launch: Browser closed unexpectedly")["category"] == "launch_failed"
+
+    @pytest.mark.parametrize("error", ["Timeout 30000ms exceeded", "captcha detected on page", "net::ERR_NAME_NOT_RESOLVED"])
+    def test_scrape_errors_are_not_launch_failures(self, error):
+        assert classify_launch_failure(RuntimeError(error)) is None
+
+
+class TestRunJobLaunchFailures:
+    def test_launch_failure_is_reported_separately(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        executor = MagicMock()
+        executor.initialize = AsyncMock(side_effect=RuntimeError(f"Failed to initialize browser: {SHARED_LIBRARY}"))
+        executor.browser = None
+        job = JobConfig(
+            job_id="job-1",
+            skus=["SKU1"],
+            scrapers=[
+                JobScraperConfig(
+                    name="phillips",
+                    base_url="https://example.com",
+                    options={"workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}]},
+                )
+            ],
+        )
+
+        with patch("runner.WorkflowExecutor", return_value=executor):
+            results = run_job(job, runner_name="test-runner")
+
+        [failure] = results["browser_launch_failures"]
+        assert failure["scraper"] == "phillips"
+        assert failure["category"] == "missing_libraries"
+        assert failure["error"].startswith("RuntimeError: Failed to initialize browser")
+
+        with pytest.raises(BrowserLaunchFailed) as exc_info:
+            check_browser_launches(results)
+        assert local_failure_reason(exc_info.value) == "missing_libraries"
@@ -0,0 +1,78 @@
+import asyncio
+import os
+from unittest.mock import AsyncMock, MagicMock, patch
+
+from core.version_info import BrowsersDir
+from utils.scraping.playwright_browser import PlaywrightScraperBrowser, chromium_executable, resolve_browsers_path
+
+
+def install_chromium(root, revision: str):
+    binary = root / f"chromium-{revision}" / "chrome-linux" / "chrome"
+    binary.parent.mkdir(parents=True)
+    binary.write_text("")
+    return binary
+
+
+class TestChromiumExecutable:
+    def test_picks_newest_revision(self, tmp_path):
+        install_chromium(tmp_path, "999")
+        newest = install_chromium(tmp_path, "1148")
+        (tmp_path / "chromium_headless_shell-1200").mkdir()
+
+        assert chromium_executable(str(tmp_path)) == str(newest)
+
+    def test_none_without_chromium(self, tmp_path):
+        (tmp_path / "chromium-1148").mkdir()
+
+        assert chromium_executable(str(tmp_path)) is None
+        assert chromium_executable(str(tmp_path / "missing")) is None
+
+
+class TestLaunchFromBrowsersPath:
+    def launch(self, browsers_path):
+        playwright = MagicMock()
+        playwright.chromium.launch = AsyncMock(side_effect=RuntimeError("stop after launch"))
+        playwright.stop = AsyncMock()
+        starter = MagicMock()
+        starter.start = AsyncMock(return_value=playwright)
+        browser = PlaywrightScraperBrowser("phillips", browsers_path=browsers_path)
+
+        with patch("utils.scraping.playwright_browser.async_playwright", return_value=starter):
+            try:
+                asyncio.run(browser.initialize())
+            except RuntimeError:
+                pass
+        return playwright.chromium.launch.call_args.kwargs
+
+    def test_pinned_revision_launches_its_binary_without_touching_the_environment(self, tmp_path, monkeypatch):
+        monkeypatch.setenv("PLAYWRIGHT_BROWSERS_PATH", "/elsewhere")
+        binary = install_chromium(tmp_path, "1148")
+
+        assert self.launch(str(tmp_path))["executable_path"] == str(binary)
+        assert os.environ["PLAYWRIGHT_BROWSERS_PATH"] == "/elsewhere"
+
+    def test_unpinned_launch_lets_playwright_resolve_its_build(self, tmp_path, monkeypatch):
+        monkeypatch.setenv("PLAYWRIGHT_BROWSERS_PATH", "/elsewhere")
+        install_chromium(tmp_path, "1148")
+
+        with patch("core.version_info.resolve_browsers_dir", return_value=BrowsersDir(tmp_path, "default")):
+            assert self.launch(None)["executable_path"] is None
+
+        assert os.environ["PLAYWRIGHT_BROWSERS_PATH"] == str(tmp_path)
+
+    def test_falls_back_to_playwright_default(self, tmp_path, monkeypatch):
+        monkeypatch.setenv("PLAYWRIGHT_BROWSERS_PATH", "/elsewhere")
+
+        assert self.launch(str(tmp_path))["executable_path"] is None
+
+
+class TestResolveBrowsersPath:
+    def test_no_pin_leaves_resolution_to_playwright(self):
+        assert resolve_browsers_path(None) is None
+
+    def test_pinned_revision_directory(self, tmp_path, monkeypatch):
+        (tmp_path / "1148").mkdir()
+        monkeypatch.setenv("BROWSER_REVISIONS_DIR", str(tmp_path))
+
+        assert resolve_browsers_path("1148") == str(tmp_path / "1148")
+        assert resolve_browsers_path("999") is None
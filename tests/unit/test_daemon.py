@@ -0,0 +1,80 @@
+import asyncio
+from unittest.mock import MagicMock, patch
+
+import daemon
+from core.api_client import ClaimedChunk
+
+CHUNK = ClaimedChunk(chunk_id="chunk-1", job_id="job-1", chunk_index=0, skus=["SKU1"], scrapers=["phillips", "orgill"], lease_token="lease-1")
+
+
+def launch_failure(scraper, category="browser_missing"):
+    return {"scraper": scraper, "category": category, "missing_libraries": [], "packages": [], "remedy": "playwright install chromium", "error": "..."}
+
+
+class TestChunkOutcome:
+    def setup_method(self):
+        self.client = MagicMock(api_url="https://app.example.com", api_key="key", runner_name="runner-1")
+        self.client.get_supabase_config.return_value = None
+        self.client.release_chunk.return_value = True
+
+        def claim_once(runner_name=None):
+            daemon._shutdown_requested = True
+            return CHUNK
+
+        self.client.claim_chunk.side_effect = claim_once
+        self.patches = [
+            patch.object(daemon, "ScraperAPIClient", return_value=self.client),
+            patch.object(daemon, "load_instance_id", return_value="instance-1"),
+            patch.object(daemon, "runner_health", MagicMock(defer_work_reason=MagicMock(return_value=None))),
+            patch.object(daemon, "sleep_inhibitor", MagicMock()),
+        ]
+        for p in self.patches:
+            p.start()
+        daemon._shutdown_requested = False
+
+    def teardown_method(self):
+        for p in self.patches:
+            p.stop()
+        daemon._shutdown_requested = False
+
+    def run_chunk(self, results):
+        with patch.object(daemon, "run_claimed_chunk", return_value=results):
+            asyncio.run(daemon.main_async())
+        return [call[0][1] for call in self.client.submit_chunk_results.call_args_list]
+
+    def test_chunk_is_released_when_no_browser_launched(self):
+        results = {"scrapers_run": ["phillips", "orgill"], "browser_launch_failures": [launch_failure("phillips"), launch_failure("orgill")]}
+
+        assert self.run_chunk(results) == []
+        self.client.release_chunk.assert_called_once_with("chunk-1", "browser_missing", lease_token="lease-1")
+
+    def test_chunk_is_released_when_libraries_are_missing(self):
+        failures = [launch_failure("phillips", "missing_libraries"), launch_failure("orgill", "missing_libraries")]
+        results = {"scrapers_run": ["phillips", "orgill"], "browser_launch_failures": failures}
+
+        assert self.run_chunk(results) == []
+        self.client.release_chunk.assert_called_once_with("chunk-1", "missing_libraries", lease_token="lease-1")
+
+    def test_chunk_fails_when_the_release_is_refused(self):
+        self.client.release_chunk.return_value = False
+        results = {"scrapers_run": ["phillips"], "browser_launch_failures": [launch_failure("phillips")]}
+
+        assert self.run_chunk(results) == ["failed"]
+
+    def test_chunk_fails_when_the_launch_failure_is_not_local(self):
+        results = {"scrapers_run": ["phillips"], "browser_launch_failures": [launch_failure("phillips", "launch_failed")]}
+
+        assert self.run_chunk(results) == ["failed"]
+        self.client.release_chunk.assert_not_called()
+
+    def test_chunk_completes_when_one_scraper_launched(self):
+        results = {"scrapers_run": ["phillips", "orgill"], "browser_launch_failures": [launch_failure("phillips")], "data": {"SKU1": {"orgill": {}}}}
+
+        assert self.run_chunk(results) == ["completed"]
+        self.client.release_chunk.assert_not_called()
+
+    def test_chunk_is_released_when_disk_space_ran_out(self):
+        results = {"scrapers_run": ["phillips"], "deferred_scrapers": [{"scraper": "phillips", "reason": "disk_full", "skus": ["SKU1"]}]}
+
+        assert self.run_chunk(results) == []
+        self.client.release_chunk.assert_called_once_with("chunk-1", "disk_full", lease_token="lease-1")
@@ -0,0 +1,106 @@
+import asyncio
+import threading
+import time
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from runner import DEBUG_RUN_MAX_SKUS, DebugRunOptions, _wait_at_breakpoint, run_job
+from runner.cli import _debug_options_from_args, parse_args
+
+
+def parse(*argv: str):
+    with patch("sys.argv", ["runner", "--job-id", "job-1", *argv]):
+        return parse_args()
+
+
+class TestDebugRunArgs:
+    def test_plain_run_has_no_debug_options(self):
+        assert _debug_options_from_args(parse()) is None
+
+    def test_debug_flags_build_options(self):
+        options = _debug_options_from_args(parse("--headful", "--slow-mo-ms", "250", "--pause-on-error", "--debug-max-skus", "3"))
+
+        assert options == DebugRunOptions(headful=True, slow_mo_ms=250, pause_on_error=True, max_skus=3)
+
+    def test_default_cap(self):
+        assert _debug_options_from_args(parse("--headful")).max_skus == DEBUG_RUN_MAX_SKUS
+
+    @pytest.mark.parametrize(
+        "argv",
+        [
+            ("--headful", "--debug-max-skus", str(DEBUG_RUN_MAX_SKUS + 1)),
+            ("--headful", "--debug-max-skus", "0"),
+            ("--slow-mo-ms", "-1"),
+            ("--headful", "--mode", "realtime"),
+        ],
+    )
+    def test_rejects_invalid_debug_args(self, argv):
+        with pytest.raises(SystemExit):
+            parse(*argv)
+
+
+class TestDebugRunJob:
+    def setup_method(self):
+        self.job = JobConfig(
+            job_id="job-1",
+            skus=[f"SKU{i}" for i in range(15)],
+            scrapers=[
+                JobScraperConfig(
+                    name="phillips",
+                    base_url="https://example.com",
+                    options={"workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}]},
+                )
+            ],
+        )
+        self.executor = MagicMock()
+        self.executor.initialize = AsyncMock()
+        self.executor.browser.quit = AsyncMock()
+        self.executor.browser.current_url = "https://example.com/p"
+        self.executor.execute_workflow = AsyncMock(return_value={"success": True, "results": {"Name": "Dog Food"}})
+
+    def test_caps_skus_and_tags_results(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+
+        with patch("runner.WorkflowExecutor", return_value=self.executor) as executor_cls:
+            results = run_job(self.job, runner_name="test-runner", debug_options=DebugRunOptions(slow_mo_ms=100, max_skus=4))
+
+        assert self.executor.execute_workflow.await_count == 4
+        assert results["debug_run"] is True
+        assert executor_cls.call_args.kwargs["headless"] is False
+        assert executor_cls.call_args.kwargs["slow_mo_ms"] == 100
+        assert any("limiting 15 SKUs to the first 4" in entry["message"] for entry in results["logs"])
+
+    def test_regular_run_is_not_capped(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+
+        with patch("runner.WorkflowExecutor", return_value=self.executor):
+            results = run_job(self.job, runner_name="test-runner")
+
+        assert self.executor.execute_workflow.await_count == 15
+        assert "debug_run" not in results
+
+
+class TestBreakpoint:
+    def test_cancelling_a_breakpoint_returns_without_a_line(self):
+        never = threading.Event()
+
+        async def cancel_at_breakpoint():
+            task = asyncio.create_task(_wait_at_breakpoint("phillips", "SKU1", RuntimeError("boom")))
+            await asyncio.sleep(0.05)
+            task.cancel()
+            with pytest.raises(asyncio.CancelledError):
+                await task
+
+        started = time.monotonic()
+        with patch("runner.sys.stdin", MagicMock(readline=lambda: never.wait(5) and "")):
+            asyncio.run(cancel_at_breakpoint())
+        never.set()
+
+        assert time.monotonic() - started < 2
+
+    def test_enter_releases_the_breakpoint(self):
+        with patch("runner.sys.stdin", MagicMock(readline=lambda: "\n")):
+            asyncio.run(asyncio.wait_for(_wait_at_breakpoint("phillips", "SKU1", RuntimeError("boom")), timeout=5))
@@ -0,0 +1,118 @@
+from unittest.mock import AsyncMock, MagicMock, patch
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from runner import run_job
+from runner.dedup import dedupe_records
+
+
+def record(price: int | None = 1299, scraped_at: str = "2026-03-02T09:00:00", **fields) -> dict:
+    data = {"title": "Dog Food 30lb", "url": "https://example.com/p/1", "scraped_at": scraped_at, **fields}
+    if price is not None:
+        data["scraped_price"] = {"amount_cents": price, "currency": "USD"}
+    return data
+
+
+class TestDedupeRecords:
+    def test_distinct_skus_are_untouched(self):
+        records = {"SKU1": record(), "SKU2": record(title="Cat Food")}
+
+        result = dedupe_records(records)
+
+        assert result.records == records
+        assert result.duplicates_merged == 0
+        assert result.conflicts == []
+
+    def test_identical_records_collapse_silently(self):
+        records = {"SKU1": record(), " sku1": record(url="https://example.com/c/dogs", scraped_at="2026-03-02T09:05:00")}
+
+        result = dedupe_records(records)
+
+        assert list(result.records) == ["SKU1"]
+        assert result.duplicates_merged == 1
+        assert result.conflicts == []
+
+    def test_latest_wins(self):
+        records = {"SKU1": record(1299), "sku1": record(999, scraped_at="2026-03-02T09:05:00")}
+
+        result = dedupe_records(records, "latest")
+
+        assert result.records == {"sku1": records["sku1"]}
+        assert result.conflicts[0]["kept"] == "sku1"
+        assert result.conflicts[0]["values"] == [records["SKU1"], records["sku1"]]
+
+    def test_lowest_price_wins(self):
+        records = {"SKU1": record(999), "sku1": record(1299, scraped_at="2026-03-02T09:05:00"), "SKU1 ": record(None)}
+
+        result = dedupe_records(records, "lowest_price")
+
+        assert result.records == {"SKU1": records["SKU1"]}
+        assert result.duplicates_merged == 2
+        assert len(result.conflicts) == 2
+
+    def test_flag_holds_product_back(self):
+        records = {"SKU1": record(999), "sku1": record(1299), "SKU2": record()}
+
+        result = dedupe_records(records, "flag")
+
+        assert list(result.records) == ["SKU2"]
+        assert result.conflicts[0]["kept"] is None
+
+
+class TestRunJobDedup:
+    def test_duplicates_are_merged_and_conflicts_reported(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        names = {"SKU1": "Dog Food", "sku1": "Dog Food 30lb", "SKU2": "Cat Food"}
+        executor = MagicMock()
+        executor.initialize = AsyncMock()
+        executor.browser.quit = AsyncMock()
+        executor.execute_workflow = AsyncMock(side_effect=lambda context, **_: {"success": True, "results": {"Name": names[context["sku"]]}})
+        job = JobConfig(
+            job_id="job-1",
+            skus=list(names),
+            scrapers=[
+                JobScraperConfig(
+                    name="phillips",
+                    base_url="https://example.com",
+                    options={"workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}], "dedup_policy": "flag"},
+                )
+            ],
+        )
+
+        streamed = []
+
+        with patch("runner.pacing_control"), patch("runner.WorkflowExecutor", return_value=executor):
+            results = run_job(job, runner_name="test-runner", progress_callback=lambda sku, scraper, data: streamed.append(sku) or True)
+
+        assert set(results["data"]) == {"SKU2"}
+        # Flagged duplicates are held back from per-SKU progress too
+        assert streamed == ["SKU2"]
+        assert results["duplicates_merged"] == 1
+        assert results["conflicts_flagged"] == 1
+        assert results["rejected"][0]["reason"] == "conflicting duplicate of sku1, held for review"
+
+    def test_each_scraper_streams_as_soon_as_it_finishes(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        order = []
+
+        def make_executor(config, **_):
+            executor = MagicMock()
+            executor.initialize = AsyncMock(side_effect=lambda: order.append(f"start {config.name}"))
+            executor.browser.quit = AsyncMock()
+            executor.execute_workflow = AsyncMock(return_value={"success": True, "results": {"Name": "Dog Food"}})
+            return executor
+
+        workflows = [{"action": "navigate", "params": {"url": "https://example.com"}}]
+        job = JobConfig(
+            job_id="job-1",
+            skus=["SKU1"],
+            scrapers=[
+                JobScraperConfig(name="phillips", base_url="https://example.com", options={"workflows": workflows}),
+                JobScraperConfig(name="orgill", base_url="https://example.com", options={"workflows": workflows}),
+            ],
+        )
+
+        with patch("runner.pacing_control"), patch("runner.WorkflowExecutor", side_effect=make_executor):
+            run_job(job, runner_name="test-runner", progress_callback=lambda sku, scraper, data: order.append(f"stream {scraper}/{sku}") or True)
+
+        assert order == ["start phillips", "stream phillips/SKU1", "start orgill", "stream orgill/SKU1"]
@@ -0,0 +1,121 @@
+import asyncio
+from unittest.mock import AsyncMock, MagicMock, patch
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from core.disk_guard import DiskGuard
+from core.health import RunnerHealth
+from core.job_pause import JobPause
+from runner import run_job
+
+
+class TestDiskGuard:
+    def setup_method(self):
+        self.health = RunnerHealth(version="v1", min_free_disk_mb=500, warn_free_disk_mb=2000)
+        self.pause = JobPause()
+        self.guard = DiskGuard(self.health, check_interval=0, poll_interval=0, pause=self.pause)
+        self.notices: list[tuple[str, str]] = []
+
+    def notify(self, level: str, message: str, free_mb: float | None) -> None:
+        self.notices.append((level, message))
+
+    def run_guard(self, free_mb: list[float]) -> float:
+        with patch.object(self.health, "free_disk_mb", side_effect=free_mb):
+            return asyncio.run(self.guard.guard("phillips/SKU1", self.notify))
+
+    def test_plenty_of_space_does_nothing(self):
+        assert self.run_guard([10_000]) == 0.0
+        assert self.notices == []
+
+    def test_low_space_warns_once_per_job(self):
+        self.run_guard([1500])
+        self.run_guard([1200])
+
+        assert [level for level, _ in self.notices] == ["warning"]
+
+    def test_critical_space_pauses_until_freed(self):
+        paused_for = self.run_guard([100, 200, 450, 3000])
+
+        assert paused_for >= 0.0
+        assert [level for level, _ in self.notices] == ["error", "info"]
+        assert "pausing before phillips/SKU1" in self.notices[0][1]
+
+    def test_pause_gives_up_after_max_pause(self):
+        guard = DiskGuard(self.health, check_interval=0, poll_interval=0, max_pause=0, pause=self.pause)
+
+        with patch.object(self.health, "free_disk_mb", return_value=100):
+            asyncio.run(guard.guard("phillips/SKU1", self.notify))
+            assert guard.gave_up is True
+            assert asyncio.run(guard.guard("phillips/SKU2", self.notify)) == 0.0
+
+        assert [level for level, _ in self.notices] == ["error", "error"]
+        assert "still only 100MB free" in self.notices[1][1]
+
+    def test_stop_request_ends_the_pause(self):
+        self.pause.request_stop()
+
+        self.run_guard([100])
+
+        assert self.guard.gave_up is True
+        assert "stop requested" in self.notices[-1][1]
+
+    def test_checks_are_rate_limited(self):
+        guard = DiskGuard(self.health, check_interval=60, poll_interval=0)
+
+        with patch.object(self.health, "free_disk_mb", return_value=1500) as free_disk_mb:
+            asyncio.run(guard.guard("phillips/SKU1", self.notify))
+            free_disk_mb.side_effect = AssertionError("checked again within the interval")
+            assert asyncio.run(guard.guard("phillips/SKU2", self.notify)) == 0.0
+
+    def test_job_holds_back_skus_when_the_guard_gives_up(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        guard = DiskGuard(self.health, check_interval=0, poll_interval=0, max_pause=0, pause=self.pause)
+        executor = MagicMock()
+        executor.initialize = AsyncMock()
+        executor.browser.quit = AsyncMock()
+        executor.execute_workflow = AsyncMock(return_value={"success": True, "results": {"Name": "Dog Food"}})
+        job = JobConfig(
+            job_id="job-1",
+            skus=["SKU1", "SKU2"],
+            scrapers=[
+                JobScraperConfig(
+                    name="phillips",
+                    base_url="https://example.com",
+                    options={"workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}]},
+                )
+            ],
+        )
+
+        with (
+            patch.object(self.health, "free_disk_mb", return_value=100),
+            patch("runner.DiskGuard", return_value=guard),
+            patch("runner.pacing_control"),
+            patch("runner.WorkflowExecutor", return_value=executor),
+        ):
+            results = run_job(job, runner_name="test-runner")
+
+        executor.execute_workflow.assert_not_awaited()
+        assert results["deferred_scrapers"][0]["reason"] == "disk_full"
+        assert results["deferred_scrapers"][0]["skus"] == ["SKU1", "SKU2"]
+
+
+class TestDiskHealth:
+    def setup_method(self):
+        self.health = RunnerHealth(version="v1", min_free_disk_mb=500, warn_free_disk_mb=2000)
+
+    def test_low_disk_is_degraded(self):
+        with patch.object(self.health, "free_disk_mb", return_value=1000):
+            snapshot = self.health.snapshot()
+
+        assert snapshot["status"] == "degraded"
+        assert snapshot["reasons"] == ["disk_low: 1000MB free"]
+        assert snapshot["disk"] == {"free_mb": 1000, "warn_below_mb": 2000, "critical_below_mb": 500}
+
+    def test_critical_disk_defers_work_until_freed(self):
+        with patch.object(self.health, "free_disk_mb", return_value=100):
+            assert self.health.defer_work_reason() == "disk_full: 100MB free, need 500MB"
+        with patch.object(self.health, "free_disk_mb", return_value=600):
+            assert self.health.defer_work_reason() is None
+
+    def test_warning_threshold_never_below_critical(self):
+        assert RunnerHealth(version="v1", min_free_disk_mb=500, warn_free_disk_mb=100).warn_free_disk_mb == 500
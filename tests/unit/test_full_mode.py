@@ -0,0 +1,24 @@
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from runner.full_mode import run_full_mode
+from runner.github_summary import EXIT_UPLOAD_FAILED
+
+
+class TestRunFullMode:
+    def run(self, uploaded: bool):
+        client = MagicMock()
+        client.submit_results_chunked.return_value = uploaded
+        with patch("runner.full_mode.run_job", return_value={"data": {"SKU1": {}}, "skus_processed": 1}), patch("builtins.print") as printed:
+            run_full_mode(client, "job-1", "runner-1")
+        return printed
+
+    def test_successful_upload_prints_results(self):
+        assert self.run(uploaded=True).called
+
+    def test_failed_upload_exits_with_upload_failure_code(self):
+        with pytest.raises(SystemExit) as exc_info:
+            self.run(uploaded=False)
+
+        assert exc_info.value.code == EXIT_UPLOAD_FAILED
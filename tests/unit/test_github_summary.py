@@ -0,0 +1,91 @@
+import pytest
+
+from runner.github_summary import (
+    EXIT_BLOCKED,
+    EXIT_PARTIAL_FAILURE,
+    EXIT_SUCCESS,
+    format_annotations,
+    format_step_summary,
+    outcome_exit_code,
+    report_github_outcome,
+)
+
+
+def make_results(*failures: tuple[str, str, str]) -> dict:
+    return {
+        "skus_processed": 3,
+        "scrapers_run": ["phillips", "petfoodexperts"],
+        "data": {"SKU1": {"phillips": {}, "petfoodexperts": {}}, "SKU2": {"phillips": {}}},
+        "failed_skus": [{"scraper": scraper, "sku": sku, "category": category, "error": "boom"} for scraper, sku, category in failures],
+    }
+
+
+class TestOutcomeExitCode:
+    @pytest.mark.parametrize(
+        "failures, expected",
+        [
+            ((), EXIT_SUCCESS),
+            ((("phillips", "SKU3", "timeout"),), EXIT_PARTIAL_FAILURE),
+            ((("phillips", "SKU3", "timeout"), ("petfoodexperts", "SKU3", "captcha_detected")), EXIT_BLOCKED),
+            ((("phillips", "SKU3", "access_denied"),), EXIT_BLOCKED),
+        ],
+    )
+    def test_exit_codes(self, failures, expected):
+        assert outcome_exit_code(make_results(*failures)) == expected
+
+    def test_aborted_selector_drift_is_partial_failure(self):
+        results = make_results()
+        results["selector_drift"] = [{"scraper": "phillips", "missing": [{}], "expected_fields": 4, "aborted": True}]
+
+        assert outcome_exit_code(results) == EXIT_PARTIAL_FAILURE
+        assert "### Selector drift suspected" in format_step_summary("job-1", results, 10)
+
+
+    def test_failed_coverage_is_partial_failure(self):
+        results = make_results()
+        results["coverage"] = {"phillips": {"attempted": 10, "found": 4, "percent": 40.0, "outcome": "failed"}}
+        results["outcome"] = "failed"
+
+        assert outcome_exit_code(results) == EXIT_PARTIAL_FAILURE
+        assert "| Coverage (phillips) | 4/10 (40.0%), failed |" in format_step_summary("job-1", results, 10)
+
+
+class TestGitHubAnnotations:
+    def test_notice_summarizes_run(self):
+        lines = format_annotations("job-1", make_results(("phillips", "SKU3", "timeout")), 42.4)
+
+        assert lines[0] == "::notice title=Scrape job job-1::3 products found across 3 SKUs, 1 failed, in 42s"
+        assert lines[1] == "::error title=Failed SKU::phillips/SKU3: timeout - boom"
+
+    def test_blocked_run_gets_error(self):
+        lines = format_annotations("job-1", make_results(("petfoodexperts", "SKU3", "captcha_detected")), 1)
+
+        assert "::error title=Blocked::Blocked by petfoodexperts" in lines
+
+    def test_messages_are_escaped(self):
+        results = make_results()
+        results["failed_skus"] = [{"scraper": "phillips", "sku": "SKU3", "category": "timeout", "error": "line1\nline2 100%"}]
+
+        lines = format_annotations("job-1", results, 1)
+
+        assert lines[1].endswith("line1%0Aline2 100%25")
+
+    def test_step_summary_has_category_table(self):
+        summary = format_step_summary(
+            "job-1",
+            make_results(("phillips", "SKU3", "timeout"), ("phillips", "SKU4", "timeout"), ("petfoodexperts", "SKU3", "no_results")),
+            90,
+        )
+
+        assert "## Scrape job `job-1`: Partial failure" in summary
+        assert "| phillips | timeout | 2 |" in summary
+        assert "| petfoodexperts | no_results | 1 |" in summary
+        assert "| Duration | 90s |" in summary
+
+    def test_writes_step_summary_file(self, tmp_path, monkeypatch):
+        summary_file = tmp_path / "summary.md"
+        monkeypatch.setenv("GITHUB_STEP_SUMMARY", str(summary_file))
+
+        report_github_outcome("job-1", make_results(), 5)
+
+        assert "Success" in summary_file.read_text()
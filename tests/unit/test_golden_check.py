@@ -0,0 +1,147 @@
+import asyncio
+from unittest.mock import AsyncMock, MagicMock
+
+import pytest
+import yaml
+
+from runner.golden_check import check_golden_sample, evaluate_golden_results, field_problem, job_config_for_chunk, set_golden_skus
+from scrapers.models.config import GoldenSampleConfig, ScraperConfig
+
+
+def make_golden(**overrides) -> GoldenSampleConfig:
+    data = {"skus": ["A1", "B2"], "expected_fields": ["Name", "Price"], "max_missing_ratio": 0.25}
+    data.update(overrides)
+    return GoldenSampleConfig(**data)
+
+
+class TestFieldProblem:
+    @pytest.mark.parametrize(
+        "name, value, expected",
+        [
+            ("Name", "Dog Food", None),
+            ("Name", None, "empty"),
+            ("Name", "   ", "empty"),
+            ("Name", "N/A", "placeholder"),
+            ("Price", "$12.99", None),
+            ("Price", "twelve", "malformed"),
+            ("Images", ["https://example.com/a.jpg"], None),
+            ("Images", ["placeholder.gif"], "malformed"),
+            ("Images", [], "empty"),
+        ],
+    )
+    def test_field_problem(self, name, value, expected):
+        assert field_problem(name, value) == expected
+
+
+class TestEvaluateGoldenResults:
+    def test_all_fields_present_passes(self):
+        check = evaluate_golden_results(
+            "phillips",
+            make_golden(),
+            {"A1": {"Name": "Dog Food", "Price": "$12.99"}, "B2": {"Name": "Cat Food", "Price": "4.50"}},
+        )
+
+        assert check.missing == []
+        assert check.drift_suspected is False
+
+    def test_threshold_is_exclusive(self):
+        check = evaluate_golden_results(
+            "phillips",
+            make_golden(),
+            {"A1": {"Name": "", "Price": "$12.99"}, "B2": {"Name": "Cat Food", "Price": "4.50"}},
+        )
+
+        assert check.missing_ratio == 0.25
+        assert check.drift_suspected is False
+
+    def test_failed_workflows_count_every_field(self):
+        check = evaluate_golden_results("phillips", make_golden(), {"A1": None, "B2": {"Name": "Cat Food", "Price": "4.50"}})
+
+        assert check.drift_suspected is True
+        assert check.missing == [
+            {"sku": "A1", "field": "Name", "problem": "workflow_failed"},
+            {"sku": "A1", "field": "Price", "problem": "workflow_failed"},
+        ]
+        assert check.to_dict()["missing_ratio"] == 0.5
+
+
+class TestCheckGoldenSample:
+    def test_scrapes_each_golden_sku(self):
+        executor = MagicMock()
+        executor.config = ScraperConfig(name="phillips", base_url="https://example.com", golden=make_golden())
+        executor.execute_workflow = AsyncMock(
+            side_effect=[{"success": True, "results": {"Name": "", "Price": "bad"}}, RuntimeError("timeout")]
+        )
+
+        check = asyncio.run(check_golden_sample(executor))
+
+        assert executor.execute_workflow.await_count == 2
+        assert check.drift_suspected is True
+        assert len(check.missing) == 4
+
+    def test_scrapers_without_golden_sample_are_skipped(self):
+        executor = MagicMock()
+        executor.config = ScraperConfig(name="phillips", base_url="https://example.com")
+
+        assert asyncio.run(check_golden_sample(executor)) is None
+
+
+class TestSetGoldenSkus:
+    def test_updates_skus_and_keeps_settings(self, tmp_path):
+        path = tmp_path / "phillips.yaml"
+        path.write_text(
+            yaml.safe_dump({"name": "phillips", "base_url": "https://example.com", "golden": {"skus": ["OLD"], "expected_fields": ["Name", "Brand"]}})
+        )
+
+        golden = set_golden_skus(path, ["A1", " B2 ", ""])
+
+        assert golden.skus == ["A1", "B2"]
+        saved = yaml.safe_load(path.read_text())
+        assert saved["golden"] == {"skus": ["A1", "B2"], "expected_fields": ["Name", "Brand"]}
+
+    def test_keeps_comments_outside_the_golden_block(self, tmp_path):
+        path = tmp_path / "phillips.yaml"
+        path.write_text(
+            "# Phillips Pet Food & Supplies\n"
+            "name: phillips  # matches the coordinator\n"
+            "golden:\n"
+            "  skus: [OLD]\n"
+            "  expected_fields: [Name]\n"
+            "\n"
+            "# Login is needed for pricing\n"
+            "base_url: https://example.com\n"
+        )
+
+        set_golden_skus(path, ["A1"])
+
+        text = path.read_text()
+        assert text.startswith("# Phillips Pet Food & Supplies\nname: phillips  # matches the coordinator\n")
+        assert text.endswith("\n# Login is needed for pricing\nbase_url: https://example.com\n")
+        assert yaml.safe_load(text)["golden"] == {"skus": ["A1"], "expected_fields": ["Name"]}
+
+    def test_adds_a_missing_golden_block(self, tmp_path):
+        path = tmp_path / "phillips.yaml"
+        path.write_text("name: phillips\nbase_url: https://example.com  # storefront")
+
+        set_golden_skus(path, ["A1"])
+
+        saved = path.read_text()
+        assert "https://example.com  # storefront\n" in saved
+        assert yaml.safe_load(saved)["golden"]["skus"] == ["A1"]
+
+    def test_invalid_update_leaves_file_untouched(self, tmp_path):
+        path = tmp_path / "phillips.yaml"
+        original = yaml.safe_dump({"name": "phillips", "base_url": "https://example.com"})
+        path.write_text(original)
+
+        with pytest.raises(ValueError):
+            set_golden_skus(path, [])
+
+        assert path.read_text() == original
+
+
+class TestJobConfigForChunk:
+    def test_only_the_first_chunk_checks(self):
+        assert job_config_for_chunk({"interactive_auth": True}, 0) == {"interactive_auth": True}
+        assert job_config_for_chunk({"interactive_auth": True}, 3) == {"interactive_auth": True, "skip_golden_check": True}
+        assert job_config_for_chunk(None, 1) == {"skip_golden_check": True}
@@ -0,0 +1,121 @@
+import signal
+import time
+from unittest.mock import patch
+
+from core.health import HEALTH_DEGRADED, HEALTH_FAILED, HEALTH_HEALTHY, RunnerHealth, register_state_dump_signal
+
+
+class TestRunnerHealth:
+    def setup_method(self):
+        self.health = RunnerHealth(version="v1.2.3", api_unreachable_minutes=5, min_free_disk_mb=100)
+
+    def test_healthy_by_default(self):
+        with patch.object(self.health, "free_disk_mb", return_value=10_000):
+            snapshot = self.health.snapshot()
+
+        assert snapshot["status"] == HEALTH_HEALTHY
+        assert snapshot["version"] == "v1.2.3"
+        assert snapshot["api_reachable"] is True
+        assert snapshot["current_job"] is None
+        assert snapshot["last_successful_upload"] is None
+
+    def test_brief_api_outage_is_degraded(self):
+        self.health.record_api_failure()
+
+        with patch.object(self.health, "free_disk_mb", return_value=10_000):
+            snapshot = self.health.snapshot()
+
+        assert snapshot["status"] == HEALTH_DEGRADED
+        assert snapshot["api_reachable"] is False
+
+    def test_long_api_outage_is_failed(self):
+        self.health.record_api_failure()
+
+        with patch.object(self.health, "free_disk_mb", return_value=10_000):
+            snapshot = self.health.snapshot(now=time.time() + 6 * 60)
+
+        assert snapshot["status"] == HEALTH_FAILED
+
+    def test_api_success_clears_outage(self):
+        self.health.record_api_failure()
+        self.health.record_api_success()
+
+        with patch.object(self.health, "free_disk_mb", return_value=10_000):
+            snapshot = self.health.snapshot(now=time.time() + 6 * 60)
+
+        assert snapshot["status"] == HEALTH_HEALTHY
+        assert snapshot["api_reachable"] is True
+
+    def test_low_disk_is_failed(self):
+        with patch.object(self.health, "free_disk_mb", return_value=50):
+            snapshot = self.health.snapshot()
+
+        assert snapshot["status"] == HEALTH_FAILED
+        assert any(reason.startswith("disk_full") for reason in snapshot["reasons"])
+
+    def test_tracks_job_and_upload(self):
+        self.health.set_current_job("job-1")
+        self.health.set_queue_depth(3)
+        self.health.record_upload()
+
+        with patch.object(self.health, "free_disk_mb", return_value=10_000):
+            snapshot = self.health.snapshot()
+
+        assert snapshot["current_job"] == "job-1"
+        assert snapshot["queue_depth"] == 3
+        assert snapshot["last_successful_upload"] is not None
+
+
+class TestBatteryDeferral:
+    def make_health(self, pause_on_battery: bool) -> RunnerHealth:
+        return RunnerHealth(version="v1", min_free_disk_mb=100, pause_on_battery=pause_on_battery, min_battery_percent=40)
+
+    def test_defers_on_low_battery(self):
+        health = self.make_health(pause_on_battery=True)
+
+        assert health.defer_work_reason(battery=(25.0, True)) == "on_battery: 25% (below 40%)"
+        assert health.defer_work_reason(battery=(80.0, True)) is None
+        assert health.defer_work_reason(battery=(10.0, False)) is None
+
+    def test_warns_when_battery_cannot_be_read(self):
+        health = RunnerHealth(pause_on_battery=True)
+
+        with patch("core.health.HAS_PSUTIL", False):
+            assert health.check_power_monitoring() is False
+        with patch("core.health.HAS_PSUTIL", True):
+            assert health.check_power_monitoring() is True
+        assert RunnerHealth(pause_on_battery=False).check_power_monitoring() is True
+
+    def test_disabled_by_default(self):
+        health = self.make_health(pause_on_battery=False)
+
+        assert health.defer_work_reason(battery=(5.0, True)) is None
+
+    def test_snapshot_reports_power_without_failing(self):
+        health = self.make_health(pause_on_battery=True)
+
+        with patch.object(health, "free_disk_mb", return_value=10_000), patch.object(health, "battery", return_value=(25.0, True)):
+            snapshot = health.snapshot()
+
+        assert snapshot["status"] == HEALTH_HEALTHY
+        assert snapshot["power"] == {
+            "on_battery": True,
+            "battery_percent": 25.0,
+            "pause_on_battery": True,
+            "deferring_work": "on_battery: 25% (below 40%)",
+            "sleep_inhibited": False,
+        }
+
+
+class TestStateDumpSignal:
+    def test_registers_all_thread_dump_on_sigusr1(self):
+        with patch("core.health.faulthandler.register") as register:
+            registered = register_state_dump_signal()
+
+        if hasattr(signal, "SIGUSR1"):
+            assert registered is True
+            assert register.call_args.args[0] == signal.SIGUSR1
+            assert register.call_args.kwargs["all_threads"] is True
+        else:
+            assert registered is False
+            register.assert_not_called()
@@ -0,0 +1,165 @@
+import json
+import os
+import threading
+
+import pytest
+
+from core.instance import MAX_LABELS, AlreadyRunningError, InstanceLock, _windows_pid_alive, load_failover_priority, load_instance_id, load_redaction_key, load_runner_tags
+
+
+class TestLoadInstanceId:
+    def test_env_override(self, tmp_path, monkeypatch):
+        monkeypatch.setenv("RUNNER_INSTANCE_ID", "from-env")
+        assert load_instance_id(tmp_path) == "from-env"
+
+    def test_created_once_and_reused(self, tmp_path, monkeypatch):
+        monkeypatch.delenv("RUNNER_INSTANCE_ID", raising=False)
+        first = load_instance_id(tmp_path)
+        assert load_instance_id(tmp_path) == first
+
+    def test_other_users_file_is_not_adopted(self, tmp_path, monkeypatch):
+        monkeypatch.delenv("RUNNER_INSTANCE_ID", raising=False)
+        monkeypatch.setattr("core.instance.current_os_user", lambda: "alice")
+        (tmp_path / "instance-alice.json").write_text(json.dumps({"instance_id": "bobs-id", "os_user": "bob"}))
+
+        assert load_instance_id(tmp_path) != "bobs-id"
+
+
+class TestLoadRunnerTags:
+    def test_unset_by_default(self, monkeypatch):
+        monkeypatch.delenv("RUNNER_LOCATION_TAG", raising=False)
+        monkeypatch.delenv("RUNNER_LABELS", raising=False)
+
+        assert load_runner_tags() == {"location_tag": None, "labels": []}
+
+    def test_reads_tag_and_labels(self, monkeypatch):
+        monkeypatch.setenv("RUNNER_LOCATION_TAG", "lancaster")
+        monkeypatch.setenv("RUNNER_LABELS", "retail, warehouse,,retail")
+
+        assert load_runner_tags() == {"location_tag": "lancaster", "labels": ["retail", "warehouse"]}
+
+    def test_invalid_values_are_dropped(self, monkeypatch):
+        monkeypatch.setenv("RUNNER_LOCATION_TAG", "Main Street")
+        monkeypatch.setenv("RUNNER_LABELS", "ok,Not OK," + ",".join(f"l{i}" for i in range(MAX_LABELS + 2)))
+
+        tags = load_runner_tags()
+
+        assert tags["location_tag"] is None
+        assert tags["labels"][0] == "ok"
+        assert "Not OK" not in tags["labels"]
+        assert len(tags["labels"]) == MAX_LABELS
+
+
+class TestLoadFailoverPriority:
+    def test_unset_by_default(self, monkeypatch):
+        monkeypatch.delenv("RUNNER_FAILOVER_PRIORITY", raising=False)
+
+        assert load_failover_priority() is None
+
+    def test_reads_priority(self, monkeypatch):
+        monkeypatch.setenv("RUNNER_FAILOVER_PRIORITY", " 1 ")
+
+        assert load_failover_priority() == 1
+
+    def test_invalid_values_are_dropped(self, monkeypatch):
+        for raw in ("backup", "-1"):
+            monkeypatch.setenv("RUNNER_FAILOVER_PRIORITY", raw)
+            assert load_failover_priority() is None
+
+
+class TestInstanceLock:
+    def test_second_lock_is_rejected(self, tmp_path):
+        first = InstanceLock("daemon", tmp_path)
+        first.acquire()
+
+        with pytest.raises(AlreadyRunningError, match=f"pid {os.getpid()}"):
+            InstanceLock("daemon", tmp_path).acquire()
+
+        first.release()
+        InstanceLock("daemon", tmp_path).acquire()
+
+    def test_leftover_lock_file_is_reused(self, tmp_path):
+        lock = InstanceLock("daemon", tmp_path)
+        lock.path.write_text("999999999")
+
+        lock.acquire()
+        assert lock.path.read_text() == str(os.getpid())
+
+        lock.release()
+        assert lock.path.read_text() == ""
+
+    def test_only_one_of_many_concurrent_acquires_wins(self, tmp_path):
+        locks = [InstanceLock("daemon", tmp_path) for _ in range(8)]
+        won = []
+        barrier = threading.Barrier(len(locks))
+
+        def race(lock):
+            barrier.wait()
+            try:
+                lock.acquire()
+                won.append(lock)
+            except AlreadyRunningError:
+                pass
+
+        threads = [threading.Thread(target=race, args=(lock,)) for lock in locks]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+
+        assert len(won) == 1
+
+
+class TestLoadRedactionKey:
+    def test_created_once_and_reused(self, tmp_path, monkeypatch):
+        monkeypatch.delenv("REDACTION_HASH_KEY", raising=False)
+
+        key = load_redaction_key(tmp_path)
+
+        assert len(key) == 64
+        assert load_redaction_key(tmp_path) == key
+
+    def test_env_override(self, tmp_path, monkeypatch):
+        monkeypatch.setenv("REDACTION_HASH_KEY", "shared-store-key")
+
+        assert load_redaction_key(tmp_path) == b"shared-store-key"
+
+
+class FakeKernel32:
+    """Stand-in for the Windows kernel32 calls _windows_pid_alive makes."""
+
+    def __init__(self, handle: int, exit_code: int = 259, last_error: int = 0) -> None:
+        self.handle = handle
+        self.exit_code = exit_code
+        self.last_error = last_error
+        self.closed = False
+
+    def OpenProcess(self, access, inherit, pid):
+        return self.handle
+
+    def GetLastError(self):
+        return self.last_error
+
+    def GetExitCodeProcess(self, handle, exit_code_ref):
+        exit_code_ref._obj.value = self.exit_code
+        return 1
+
+    def CloseHandle(self, handle):
+        self.closed = True
+
+
+class TestWindowsPidAlive:
+    def test_running_process(self):
+        kernel32 = FakeKernel32(handle=42)
+
+        assert _windows_pid_alive(1234, kernel32) is True
+        assert kernel32.closed
+
+    def test_exited_process(self):
+        assert _windows_pid_alive(1234, FakeKernel32(handle=42, exit_code=0)) is False
+
+    def test_no_such_process(self):
+        assert _windows_pid_alive(1234, FakeKernel32(handle=0, last_error=87)) is False
+
+    def test_other_users_process(self):
+        assert _windows_pid_alive(1234, FakeKernel32(handle=0, last_error=5)) is True
@@ -0,0 +1,135 @@
+import asyncio
+import io
+import json
+import threading
+from unittest.mock import AsyncMock, MagicMock, patch
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from core.job_pause import JobPause
+from core.pacing import PacingControl
+from runner import run_job
+
+
+class TestJobPause:
+    def setup_method(self):
+        self.pause = JobPause()
+
+    def test_pause_and_resume_commands(self):
+        assert self.pause.handle_line(json.dumps({"type": "pause", "reason": "bandwidth needed"})) is True
+        assert self.pause.paused is True
+
+        assert self.pause.handle_line(json.dumps({"type": "resume"})) is True
+        assert self.pause.paused is False
+
+    def test_ignores_other_lines(self):
+        assert self.pause.handle_line("not json") is False
+        assert self.pause.handle_line(json.dumps({"type": "pacing", "scraper": "phillips", "delay_ms": 10})) is False
+        assert self.pause.paused is False
+
+    def test_wait_returns_immediately_when_running(self):
+        assert asyncio.run(self.pause.wait_while_paused("phillips/SKU1")) == 0.0
+
+    def test_wait_blocks_until_resumed(self):
+        self.pause.pause()
+        timer = threading.Timer(0.2, self.pause.resume)
+        timer.start()
+
+        paused_for = asyncio.run(self.pause.wait_while_paused("phillips/SKU1"))
+
+        assert paused_for >= 0.15
+        assert self.pause.paused is False
+
+    def test_stop_request_ends_the_wait_without_resuming(self):
+        pause = JobPause(poll_interval=0.05)
+        pause.pause()
+        threading.Timer(0.1, pause.request_stop).start()
+
+        asyncio.run(asyncio.wait_for(pause.wait_while_paused("phillips/SKU1"), timeout=5))
+
+        assert pause.paused is True
+
+    def test_wait_is_cancellable(self):
+        pause = JobPause(poll_interval=0.05)
+        pause.pause()
+
+        async def cancel_wait() -> None:
+            task = asyncio.ensure_future(pause.wait_while_paused("phillips/SKU1"))
+            await asyncio.sleep(0.1)
+            task.cancel()
+            try:
+                await task
+            except asyncio.CancelledError:
+                return
+            raise AssertionError("wait was not cancelled")
+
+        asyncio.run(asyncio.wait_for(cancel_wait(), timeout=5))
+
+    def test_listener_dispatches_to_registered_handler(self):
+        pacing = PacingControl()
+        pacing.add_handler(self.pause.handle_line)
+        stream = io.StringIO(json.dumps({"type": "pacing", "scraper": "phillips", "delay_ms": 500}) + "\n" + json.dumps({"type": "pause"}) + "\n")
+
+        pacing.start_listener(stream)
+        pacing._listener.join(timeout=5)
+
+        assert pacing.delay_ms("phillips") == 500
+        assert self.pause.paused is True
+
+
+class TestRunJobPause:
+    def test_paused_time_is_reported(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        pause = JobPause()
+        pause.pause("bandwidth needed")
+        threading.Timer(0.2, pause.resume).start()
+        executor = MagicMock()
+        executor.initialize = AsyncMock()
+        executor.browser.quit = AsyncMock()
+        executor.execute_workflow = AsyncMock(return_value={"success": True, "results": {"Name": "Dog Food"}})
+        job = JobConfig(
+            job_id="job-1",
+            skus=["SKU1", "SKU2"],
+            scrapers=[
+                JobScraperConfig(
+                    name="phillips",
+                    base_url="https://example.com",
+                    options={"workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}]},
+                )
+            ],
+        )
+
+        with patch("runner.job_pause", pause), patch("runner.pacing_control"), patch("runner.WorkflowExecutor", return_value=executor):
+            results = run_job(job, runner_name="test-runner")
+
+        assert results["skus_processed"] == 2
+        assert results["paused_seconds"] >= 0.1
+
+    def test_stop_while_paused_holds_back_remaining_skus(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        pause = JobPause(poll_interval=0.05)
+        pause.pause("bandwidth needed")
+        pause.request_stop()
+        executor = MagicMock()
+        executor.initialize = AsyncMock()
+        executor.browser.quit = AsyncMock()
+        executor.execute_workflow = AsyncMock(return_value={"success": True, "results": {"Name": "Dog Food"}})
+        job = JobConfig(
+            job_id="job-1",
+            skus=["SKU1", "SKU2"],
+            scrapers=[
+                JobScraperConfig(
+                    name="phillips",
+                    base_url="https://example.com",
+                    options={"workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}]},
+                )
+            ],
+        )
+
+        with patch("runner.job_pause", pause), patch("runner.pacing_control"), patch("runner.WorkflowExecutor", return_value=executor):
+            results = run_job(job, runner_name="test-runner")
+
+        executor.execute_workflow.assert_not_awaited()
+        assert any("stopped while paused, holding back 2 SKU(s)" in entry["message"] for entry in results["logs"])
+        assert results["deferred_scrapers"][0]["reason"] == "stopped_while_paused"
+        assert results["deferred_scrapers"][0]["skus"] == ["SKU1", "SKU2"]
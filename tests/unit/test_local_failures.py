@@ -0,0 +1,76 @@
+import errno
+
+import pytest
+
+from runner import ConfigurationError
+from runner.local_failures import BrowserLaunchFailed, DiskSpaceExhausted, check_browser_launches, check_disk_space, local_failure_reason
+from runner.preflight import ACTION_ADD_CREDENTIALS, ACTION_FREE_DISK, ACTION_INSTALL_BROWSER, PreflightFailed, PreflightIssue
+
+BROWSER = PreflightIssue(requirement="browser", message="Chromium is not installed", action=ACTION_INSTALL_BROWSER, scraper="phillips")
+DISK = PreflightIssue(requirement="disk_space", message="Only 10MB free", action=ACTION_FREE_DISK)
+CREDENTIALS = PreflightIssue(requirement="credentials", message="No credentials", action=ACTION_ADD_CREDENTIALS, scraper="phillips")
+NO_BROWSER = {"scraper": "phillips", "category": "browser_missing"}
+NO_LIBRARIES = {"scraper": "orgill", "category": "missing_libraries"}
+CRASHED = {"scraper": "orgill", "category": "launch_failed"}
+
+
+class TestLocalFailureReason:
+    @pytest.mark.parametrize(
+        "error, expected",
+        [
+            (PreflightFailed([BROWSER]), "preflight: browser"),
+            (PreflightFailed([BROWSER, DISK]), "preflight: browser, disk_space"),
+            (OSError(errno.ENOSPC, "No space left on device"), "disk_full"),
+            (BrowserLaunchFailed([NO_BROWSER, NO_LIBRARIES]), "browser_missing, missing_libraries"),
+            (DiskSpaceExhausted(["phillips"]), "disk_full"),
+            (RuntimeError("browserType.launch: Executable doesn't exist at /root/.cache/ms-playwright/chromium-1148/chrome"), "browser_missing"),
+            (RuntimeError("chrome: error while loading shared libraries: libgbm.so.1: cannot open shared object file"), "missing_libraries"),
+        ],
+    )
+    def test_local_failures(self, error, expected):
+        assert local_failure_reason(error) == expected
+
+    @pytest.mark.parametrize(
+        "error",
+        [
+            # Missing credentials come from the job, so every runner would fail the same way
+            PreflightFailed([CREDENTIALS]),
+            PreflightFailed([BROWSER, CREDENTIALS]),
+            PreflightFailed([]),
+            BrowserLaunchFailed([NO_BROWSER, CRASHED]),
+            OSError(errno.ECONNREFUSED, "Connection refused"),
+            ConfigurationError("Configuration parsing failed for 1 scraper(s)"),
+            RuntimeError("captcha detected on page"),
+            TimeoutError("Timeout 30000ms exceeded"),
+        ],
+    )
+    def test_scraper_and_site_failures_are_not_local(self, error):
+        assert local_failure_reason(error) is None
+
+
+class TestCheckBrowserLaunches:
+    def test_raises_when_no_scraper_launched(self):
+        with pytest.raises(BrowserLaunchFailed) as exc_info:
+            check_browser_launches({"scrapers_run": ["phillips", "orgill"], "browser_launch_failures": [NO_BROWSER, NO_LIBRARIES]})
+
+        assert exc_info.value.categories == {"browser_missing", "missing_libraries"}
+
+    @pytest.mark.parametrize(
+        "results",
+        [
+            {"scrapers_run": ["phillips", "orgill"], "browser_launch_failures": [NO_BROWSER]},
+            {"scrapers_run": ["phillips"]},
+            {"scrapers_run": [], "browser_launch_failures": []},
+        ],
+    )
+    def test_passes_when_a_scraper_launched_or_none_ran(self, results):
+        check_browser_launches(results)
+
+
+class TestCheckDiskSpace:
+    def test_raises_when_skus_were_held_back_for_disk_space(self):
+        with pytest.raises(DiskSpaceExhausted):
+            check_disk_space({"deferred_scrapers": [{"scraper": "phillips", "reason": "disk_full", "skus": ["SKU2"]}]})
+
+    def test_other_deferrals_pass(self):
+        check_disk_space({"deferred_scrapers": [{"scraper": "phillips", "reason": "stopped_while_paused", "skus": ["SKU2"]}]})
@@ -0,0 +1,89 @@
+import tracemalloc
+
+from runner import create_log_entry
+from utils.structured_logging import MAX_LOG_MESSAGE_CHARS, LogBuffer, scrub_log_message, truncate_log_message
+
+
+class TestTruncateLogMessage:
+    def test_short_messages_are_untouched(self):
+        assert truncate_log_message("Job started") == ("Job started", 0)
+
+    def test_long_messages_are_cut_with_a_marker(self):
+        message, cut = truncate_log_message("x" * 150, limit=100)
+
+        assert cut == 50
+        assert message == "x" * 100 + "... [truncated 50 chars]"
+
+    def test_log_entries_record_truncation(self):
+        entry = create_log_entry("error", "Traceback " * 5_000)
+
+        assert entry["truncated_chars"] == 50_000 - MAX_LOG_MESSAGE_CHARS
+        assert len(entry["message"]) < MAX_LOG_MESSAGE_CHARS + 50
+        assert "truncated_chars" not in create_log_entry("info", "Job started")
+
+
+    def test_secret_at_the_cut_is_scrubbed_not_split(self):
+        message = "x" * (MAX_LOG_MESSAGE_CHARS - 10) + " buyer@baystatepet.com"
+
+        entry = create_log_entry("error", message)
+
+        assert "buyer@" not in entry["message"]
+        assert entry["redactions"] == {"email": 1}
+
+    def test_huge_messages_are_scrubbed_within_the_kept_part(self):
+        entry = create_log_entry("error", "buyer@baystatepet.com " + "x" * (5 * MAX_LOG_MESSAGE_CHARS))
+
+        assert entry["message"].startswith("[REDACTED:email] x")
+        assert entry["truncated_chars"] == 5 * MAX_LOG_MESSAGE_CHARS + 22 - MAX_LOG_MESSAGE_CHARS
+
+    def test_token_straddling_the_scrub_window_is_scrubbed(self):
+        # With a small limit the token runs past 2 * limit, so scrubbing only that much would split it
+        token = "bsr_" + "a" * 40
+        message = "x" * 5 + token + " " + "y" * 5_000
+
+        scrubbed, cut = scrub_log_message(message, limit=10)
+
+        assert scrubbed == f"xxxxx[REDA... [truncated {cut} chars]"
+        assert cut == len(message) - 10
+
+
+class TestLogBuffer:
+    def test_keeps_the_most_recent_entries(self):
+        buffer = LogBuffer(max_entries=10)
+        for i in range(25):
+            buffer.append({"level": "info", "message": str(i)})
+
+        assert buffer.stats() == {"dropped_lines": 15, "truncated_lines": 0}
+        assert [entry["message"] for entry in buffer] == [str(i) for i in range(15, 25)]
+
+    def test_counts_truncated_entries(self):
+        buffer = LogBuffer()
+        buffer.append(create_log_entry("error", "x" * (MAX_LOG_MESSAGE_CHARS + 1)))
+        buffer.append(create_log_entry("info", "ok"))
+
+        assert buffer.stats() == {"dropped_lines": 0, "truncated_lines": 1}
+
+    def test_memory_stays_bounded_for_a_huge_stream(self):
+        # 2,000 lines of 1 MB each: a 2 GB stream from a scraper stuck printing a stack trace
+        line = "Traceback (most recent call last): " * 30_000
+        buffer = LogBuffer(max_entries=100)
+
+        tracemalloc.start()
+        for _ in range(2_000):
+            buffer.append(create_log_entry("error", line))
+        _, peak = tracemalloc.get_traced_memory()
+        tracemalloc.stop()
+
+        assert buffer.stats() == {"dropped_lines": 1_900, "truncated_lines": 2_000}
+        assert len(buffer) == 100
+        assert peak < 10 * 1024 * 1024
+
+    def test_every_way_of_adding_entries_is_capped_and_counted(self):
+        buffer = LogBuffer(max_entries=10)
+        buffer.extend({"level": "info", "message": str(i)} for i in range(10))
+        buffer += [{"level": "info", "message": str(i)} for i in range(10, 20)]
+        buffer.insert(len(buffer), create_log_entry("error", "x" * (MAX_LOG_MESSAGE_CHARS + 1)))
+
+        assert buffer.stats() == {"dropped_lines": 11, "truncated_lines": 1}
+        assert len(buffer) == 10
+        assert isinstance(buffer, LogBuffer)
@@ -0,0 +1,161 @@
+import logging
+from collections import Counter
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from runner import create_log_entry, run_job
+from scrapers.models.config import ScraperConfig
+from utils.logger import reset_logging, setup_logging
+from utils.structured_logging import LogBuffer, LogScrubber, SensitiveDataFilter, log_scrubber
+
+
+class TestLogScrubber:
+    def setup_method(self):
+        self.scrubber = LogScrubber()
+
+    @pytest.mark.parametrize(
+        "text, expected",
+        [
+            ("key=bsr_abc123def456ghi789jkl012mno345pqr", "key=[REDACTED:api_key]"),
+            ("Ordered by buyer@baystatepet.com today", "Ordered by [REDACTED:email] today"),
+            ("card 4111 1111 1111 1111 declined", "card [REDACTED:card] declined"),
+            ("card 4111-1111-1111-1111", "card [REDACTED:card]"),
+            ("amex 378282246310005", "amex [REDACTED:card]"),
+        ],
+    )
+    def test_redacts_defaults(self, text, expected):
+        assert self.scrubber.scrub(text) == expected
+
+    @pytest.mark.parametrize(
+        "text",
+        [
+            "UPC 012345678905 found",  # 12 digits
+            "EAN 4006381333931",  # 13 digits
+            "GTIN 10012345678902",  # 14 digits
+            "ref 4111111111111112",  # 16 digits failing the Luhn check
+            "bsr_short",  # too short to be a key
+            "price 1,234.56 at 2024-01-15",
+            "user@localhost",
+        ],
+    )
+    def test_leaves_product_data_alone(self, text):
+        assert self.scrubber.scrub(text) == text
+
+    def test_card_inside_longer_number_is_not_matched(self):
+        text = "barcode 41111111111111110"
+
+        assert self.scrubber.scrub(text) == text
+
+    def test_scraper_rules_apply_and_can_be_cleared(self):
+        self.scrubber.set_scraper_rules("phillips", {"account_number": r"\bACCT-\d{6}\b"})
+
+        assert self.scrubber.scrub("Logged in as ACCT-123456") == "Logged in as [REDACTED:account_number]"
+
+        self.scrubber.set_scraper_rules("phillips", {})
+        assert self.scrubber.scrub("Logged in as ACCT-123456") == "Logged in as ACCT-123456"
+
+    def test_counts_redactions_per_rule(self):
+        hits = Counter()
+        self.scrubber.scrub("a@example.com b@example.com", hits)
+        self.scrubber.scrub("4111 1111 1111 1111 and 012345678905", hits)
+
+        assert hits == {"email": 2, "card": 1}
+
+
+class TestLogRedactionConfig:
+    def make_config(self, patterns):
+        return ScraperConfig(name="phillips", base_url="https://example.com", log_redaction_patterns=patterns)
+
+    def test_valid_patterns_are_accepted(self):
+        assert self.make_config({"account_number": r"\d{8}"}).log_redaction_patterns == {"account_number": r"\d{8}"}
+
+    def test_invalid_pattern_is_rejected(self):
+        with pytest.raises(ValueError, match="Invalid log redaction pattern for 'account_number'"):
+            self.make_config({"account_number": "(unclosed"})
+
+    def test_invalid_rule_name_is_rejected(self):
+        with pytest.raises(ValueError, match="Invalid log redaction rule name"):
+            self.make_config({"Account Number": r"\d{8}"})
+
+
+def test_job_log_entries_are_scrubbed():
+    entry = create_log_entry("info", "Contact orders@supplier.com")
+
+    assert entry["message"] == "Contact [REDACTED:email]"
+
+
+def test_job_log_entries_carry_their_redactions():
+    entry = create_log_entry("info", "Contact orders@supplier.com or sales@supplier.com")
+
+    assert entry["redactions"] == {"email": 2}
+    assert "redactions" not in create_log_entry("info", "Nothing to hide")
+
+
+def test_log_buffer_tallies_redactions_of_dropped_entries():
+    buffer = LogBuffer(max_entries=1)
+    for _ in range(3):
+        buffer.append(create_log_entry("info", "Contact orders@supplier.com"))
+    buffer.stats()
+
+    assert len(buffer) == 1
+    assert buffer.redactions == {"email": 3}
+
+
+def test_logged_lines_are_not_counted_as_job_redactions():
+    buffer = LogBuffer()
+    message = "Contact orders@supplier.com"
+    buffer.append(create_log_entry("info", message))
+    record = logging.LogRecord("runner", logging.INFO, __file__, 1, message, None, None)
+    SensitiveDataFilter().filter(record)
+
+    assert record.msg == "Contact [REDACTED:email]"
+    assert buffer.redactions == {"email": 1}
+
+
+def test_setup_logging_scrubs_on_every_handler(tmp_path, monkeypatch):
+    monkeypatch.setattr("utils.logger.PROJECT_ROOT", str(tmp_path))
+    reset_logging()
+    try:
+        setup_logging(debug_mode=True, json_output=True, use_file_handler=True)
+        handlers = logging.getLogger().handlers
+
+        assert len(handlers) == 2
+        assert all(any(isinstance(f, SensitiveDataFilter) for f in handler.filters) for handler in handlers)
+    finally:
+        reset_logging()
+
+
+class TestJobRedactions:
+    def setup_method(self):
+        self.job = JobConfig(
+            job_id="job-1",
+            skus=["SKU1"],
+            scrapers=[
+                JobScraperConfig(
+                    name="phillips",
+                    base_url="https://example.com",
+                    options={
+                        "workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}],
+                        "log_redaction_patterns": {"account_number": r"\bACCT-\d{6}\b"},
+                    },
+                )
+            ],
+        )
+        self.executor = MagicMock()
+        self.executor.initialize = AsyncMock()
+        self.executor.browser.quit = AsyncMock()
+        self.executor.execute_workflow = AsyncMock(side_effect=RuntimeError("Account ACCT-123456 is locked"))
+
+    def test_counts_job_log_redactions_once_and_removes_scraper_rules(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+
+        with patch("runner.WorkflowExecutor", return_value=self.executor):
+            results = run_job(self.job, runner_name="test-runner")
+
+        logged = sum(entry["message"].count("[REDACTED:account_number]") for entry in results["logs"])
+        assert logged > 0
+        assert results["log_redactions"] == {"account_number": logged}
+        assert log_scrubber.scrub("ACCT-123456") == "ACCT-123456"
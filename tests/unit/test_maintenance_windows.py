@@ -0,0 +1,65 @@
+from datetime import datetime, timezone
+
+import pytest
+
+from scrapers.models.config import MaintenanceWindow, ScraperConfig
+
+
+def utc(*args: int) -> datetime:
+    return datetime(*args, tzinfo=timezone.utc)
+
+
+class TestMaintenanceWindow:
+    def setup_method(self):
+        # Sundays 1-5am Eastern
+        self.window = MaintenanceWindow(days=["sun"], start="01:00", end="05:00", timezone="America/New_York")
+
+    @pytest.mark.parametrize(
+        "now, active",
+        [
+            (utc(2026, 1, 4, 6, 30), True),  # Sun 01:30 EST
+            (utc(2026, 1, 4, 9, 59), True),  # Sun 04:59 EST
+            (utc(2026, 1, 4, 10, 0), False),  # Sun 05:00 EST
+            (utc(2026, 1, 5, 6, 30), False),  # Mon 01:30 EST
+            (utc(2026, 7, 5, 5, 30), True),  # Sun 01:30 EDT
+            (utc(2026, 7, 5, 6, 30), True),  # Sun 02:30 EDT
+            (utc(2026, 7, 5, 9, 30), False),  # Sun 05:30 EDT
+        ],
+    )
+    def test_active_respects_timezone_and_dst(self, now, active):
+        assert (self.window.ends_at(now) is not None) is active
+
+    def test_returns_window_end(self):
+        end = self.window.ends_at(utc(2026, 7, 5, 6, 30))
+        assert end == utc(2026, 7, 5, 9, 0)
+
+    def test_overnight_window_spans_midnight(self):
+        window = MaintenanceWindow(days=["sat"], start="23:00", end="02:00", timezone="UTC")
+
+        assert window.ends_at(utc(2026, 1, 3, 23, 30)) == utc(2026, 1, 4, 2, 0)
+        assert window.ends_at(utc(2026, 1, 4, 1, 0)) == utc(2026, 1, 4, 2, 0)
+        assert window.ends_at(utc(2026, 1, 4, 23, 30)) is None
+
+    def test_rejects_bad_time_and_timezone(self):
+        with pytest.raises(Exception):  # pydantic ValidationError
+            MaintenanceWindow(days=["sun"], start="25:00", end="05:00")
+        with pytest.raises(Exception):  # pydantic ValidationError
+            MaintenanceWindow(days=["sun"], start="01:00", end="05:00", timezone="Mars/Base")
+
+
+class TestScraperConfigMaintenance:
+    def test_latest_end_of_overlapping_windows(self):
+        config = ScraperConfig(
+            name="petfoodex",
+            base_url="https://example.com",
+            maintenance_windows=[
+                {"days": ["sun"], "start": "01:00", "end": "03:00", "timezone": "UTC"},
+                {"days": ["sun"], "start": "02:00", "end": "05:00", "timezone": "UTC"},
+            ],
+        )
+
+        assert config.active_maintenance_window_end(utc(2026, 1, 4, 2, 30)) == utc(2026, 1, 4, 5, 0)
+
+    def test_no_windows(self):
+        config = ScraperConfig(name="phillips", base_url="https://example.com")
+        assert config.active_maintenance_window_end(utc(2026, 1, 4, 2, 30)) is None
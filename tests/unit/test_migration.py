@@ -257,6 +257,16 @@ class TestScraperConfigValidation:
                 selectors=[SelectorConfig(name="test", selector="h1")],
             )
 
+    def test_validate_browser_revision(self) -> None:
+        """Test browser_revision accepts path-safe names and rejects traversal."""
+        from scrapers.models.config import ScraperConfig
+
+        config = ScraperConfig(name="pinned", base_url="https://example.com", browser_revision="chromium-1148")
+        assert config.browser_revision == "chromium-1148"
+
+        with pytest.raises(Exception):  # pydantic ValidationError
+            ScraperConfig(name="pinned", base_url="https://example.com", browser_revision="../outside")
+
 
 class TestMigrationResult:
     """Tests for MigrationResult class."""
@@ -0,0 +1,112 @@
+import json
+import os
+from pathlib import Path
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from runner import run_job
+from scrapers.models.config import ScraperConfig
+from scrapers.result_collector import ResultCollector
+
+
+class TestOutputDirConfig:
+    @pytest.mark.parametrize("output_dir", ["/mnt/pos/imports", "C:\\Exports\\phillips", "\\\\pos-server\\imports"])
+    def test_absolute_and_unc_paths_are_accepted(self, output_dir):
+        assert ScraperConfig(name="phillips", base_url="https://example.com", output_dir=output_dir).output_dir == output_dir
+
+    def test_relative_path_is_rejected(self):
+        with pytest.raises(ValueError, match="Invalid output_dir"):
+            ScraperConfig(name="phillips", base_url="https://example.com", output_dir="exports/phillips")
+
+
+class TestResultCollectorOutputDir:
+    def test_results_are_written_to_scraper_output_dir(self, tmp_path):
+        share = tmp_path / "share"
+        share.mkdir()
+        collector = ResultCollector(output_dir=str(tmp_path / "default"))
+        collector.output_dirs["phillips"] = share
+
+        collector.add_result("SKU1", "phillips", {"Name": "Dog Food"})
+        collector.add_result("SKU1", "orgill", {"Name": "Dog Food"})
+
+        saved = json.loads((share / f"session_{collector.session_id}_phillips.json").read_text())
+        assert list(saved["results"]) == ["phillips"]
+        default = json.loads((tmp_path / "default" / f"session_{collector.session_id}.json").read_text())
+        assert list(default["results"]) == ["orgill"]
+
+    def test_unreachable_share_falls_back_to_default_dir(self, tmp_path):
+        collector = ResultCollector(output_dir=str(tmp_path / "default"))
+        collector.output_dirs["phillips"] = tmp_path / "unmounted"
+
+        collector.add_result("SKU1", "phillips", {"Name": "Dog Food"})
+        collector.add_result("SKU2", "phillips", {"Name": "Cat Food"})
+
+        default = json.loads((tmp_path / "default" / f"session_{collector.session_id}.json").read_text())
+        assert set(default["results"]["phillips"]) == {"SKU1", "SKU2"}
+        assert "phillips" in collector.output_fallbacks
+        assert "phillips" not in collector.output_dirs
+
+
+    def test_fallback_carries_over_results_written_to_the_share(self, tmp_path):
+        share = tmp_path / "share"
+        share.mkdir()
+        collector = ResultCollector(output_dir=str(tmp_path / "default"))
+        collector.output_dirs["phillips"] = share
+        collector.add_result("SKU1", "phillips", {"Name": "Dog Food"})
+
+        real_replace = os.replace
+
+        def replace(src, dst):
+            if Path(dst).parent == share:
+                raise OSError("share went away")
+            real_replace(src, dst)
+
+        with patch("scrapers.result_collector.os.replace", side_effect=replace):
+            collector.add_result("SKU2", "phillips", {"Name": "Cat Food"})
+
+        default = json.loads((tmp_path / "default" / f"session_{collector.session_id}.json").read_text())
+        assert set(default["results"]["phillips"]) == {"SKU1", "SKU2"}
+
+    def test_failed_write_keeps_the_previous_file(self, tmp_path):
+        collector = ResultCollector(output_dir=str(tmp_path))
+        collector.add_result("SKU1", "phillips", {"Name": "Dog Food"})
+        path = tmp_path / f"session_{collector.session_id}.json"
+
+        with patch("scrapers.result_collector.json.dump", side_effect=OSError("disk full")):
+            collector.add_result("SKU2", "phillips", {"Name": "Cat Food"})
+
+        assert set(json.loads(path.read_text())["results"]["phillips"]) == {"SKU1"}
+        assert list(tmp_path.glob("*.tmp")) == []
+
+
+class TestRunJobOutputDir:
+    def test_missing_output_dir_is_reported(self, tmp_path, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        executor = MagicMock()
+        executor.initialize = AsyncMock()
+        executor.browser.quit = AsyncMock()
+        executor.execute_workflow = AsyncMock(return_value={"success": True, "results": {"Name": "Dog Food"}})
+        job = JobConfig(
+            job_id="job-1",
+            skus=["SKU1"],
+            test_mode=True,
+            scrapers=[
+                JobScraperConfig(
+                    name="phillips",
+                    base_url="https://example.com",
+                    options={
+                        "workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}],
+                        "output_dir": str(tmp_path / "unmounted"),
+                    },
+                )
+            ],
+        )
+
+        with patch("runner.pacing_control"), patch("runner.WorkflowExecutor", return_value=executor):
+            results = run_job(job, runner_name="test-runner")
+
+        assert results["output_fallbacks"][0]["scraper"] == "phillips"
+        assert "SKU1" in results["data"]
@@ -0,0 +1,52 @@
+import io
+import json
+
+from core.pacing import MAX_DELAY_MS, PacingControl
+
+
+class TestPacingControl:
+    def setup_method(self):
+        self.pacing = PacingControl()
+
+    def test_defaults_to_no_delay(self):
+        assert self.pacing.delay_ms("phillips") == 0
+        assert self.pacing.reason("phillips") is None
+
+    def test_pacing_command_sets_delay(self):
+        line = json.dumps({"type": "pacing", "scraper": "phillips", "delay_ms": 2500, "reason": "429 from supplier"})
+
+        assert self.pacing.handle_line(line) is True
+        assert self.pacing.delay_ms("phillips") == 2500
+        assert self.pacing.reason("phillips") == "429 from supplier"
+        assert self.pacing.delay_ms("orgill") == 0
+
+    def test_ignores_other_lines(self):
+        assert self.pacing.handle_line("not json") is False
+        assert self.pacing.handle_line(json.dumps({"type": "resume"})) is False
+        assert self.pacing.handle_line(json.dumps({"type": "pacing", "scraper": "phillips"})) is False
+        assert self.pacing.handle_line(json.dumps({"type": "pacing", "scraper": "phillips", "delay_ms": True})) is False
+        assert self.pacing.delay_ms("phillips") == 0
+
+    def test_delay_is_clamped(self):
+        self.pacing.set_delay("phillips", 10_000_000)
+        assert self.pacing.delay_ms("phillips") == MAX_DELAY_MS
+
+        self.pacing.set_delay("phillips", -5)
+        assert self.pacing.delay_ms("phillips") == 0
+
+    def test_apply_reports_previous_delay(self):
+        self.pacing.set_delay("phillips", 1000)
+
+        assert self.pacing.apply("phillips") == (1000, 0)
+        assert self.pacing.apply("phillips") == (1000, 1000)
+
+        self.pacing.set_delay("phillips", 250)
+        assert self.pacing.apply("phillips") == (250, 1000)
+
+    def test_listener_reads_stream(self):
+        stream = io.StringIO(json.dumps({"type": "pacing", "scraper": "phillips", "delay_ms": 750}) + "\n\n")
+
+        self.pacing.start_listener(stream)
+        self.pacing._listener.join(timeout=5)
+
+        assert self.pacing.delay_ms("phillips") == 750
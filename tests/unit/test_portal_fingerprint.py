@@ -0,0 +1,122 @@
+import asyncio
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+
+from core.portal_fingerprint import PortalFingerprints, fingerprint_page
+from scrapers.actions.handlers.login import LoginAction
+from scrapers.exceptions import WorkflowExecutionError
+from scrapers.models.config import ScraperConfig
+
+LOGIN_PAGE = """
+<html><head><title>Dealer Login</title><meta name="generator" content="Magento 2"></head>
+<body><form action="/login" method="post">
+  <input type="hidden" name="csrf_token" value="{token}">
+  <input type="hidden" name="{random}" value="1">
+  <input type="text" name="{user_field}" id="user">
+  <input type="password" name="password" id="pass">
+  <button type="submit" id="login-btn">Sign in</button>
+</form></body></html>
+"""
+
+
+def page(token: str = "a1b2", random: str = "d41d8cd98f00b204e980", user_field: str = "username") -> str:
+    return LOGIN_PAGE.format(token=token, random=random, user_field=user_field)
+
+
+class TestFingerprintPage:
+    def test_captures_structure(self):
+        fingerprint = fingerprint_page(page())
+
+        assert fingerprint["title"] == "Dealer Login"
+        assert fingerprint["meta"] == {"generator": "Magento 2"}
+        assert "input[type=password][name=password][id=pass]" in fingerprint["fields"]
+        assert not any("csrf" in field for field in fingerprint["fields"])
+
+    def test_tokens_and_values_do_not_change_the_hash(self):
+        assert fingerprint_page(page())["hash"] == fingerprint_page(page(token="zz99", random="0123456789abcdef0123"))["hash"]
+
+    def test_renamed_field_changes_the_hash(self):
+        assert fingerprint_page(page())["hash"] != fingerprint_page(page(user_field="email"))["hash"]
+
+
+class TestPortalFingerprints:
+    def test_first_fingerprint_is_the_baseline(self, tmp_path):
+        fingerprints = PortalFingerprints(tmp_path / "fingerprints.json")
+
+        assert fingerprints.record("phillips", fingerprint_page(page())) is None
+        assert fingerprints.record("phillips", fingerprint_page(page(token="new"))) is None
+        assert fingerprints.pending_change("phillips") is None
+
+    def test_change_is_flagged_until_acknowledged(self, tmp_path):
+        path = tmp_path / "fingerprints.json"
+        fingerprints = PortalFingerprints(path)
+        fingerprints.record("phillips", fingerprint_page(page()))
+
+        change = fingerprints.record("phillips", fingerprint_page(page(user_field="email")))
+
+        assert change["added_fields"] == ["input[type=text][name=email][id=user]"]
+        assert change["removed_fields"] == ["input[type=text][name=username][id=user]"]
+        assert PortalFingerprints(path).status()["phillips"]["changed"] is True
+
+        assert fingerprints.acknowledge("phillips") is True
+        assert fingerprints.acknowledge("phillips") is False
+        assert fingerprints.record("phillips", fingerprint_page(page(user_field="email"))) is None
+
+    def test_unreadable_file_is_ignored(self, tmp_path):
+        path = tmp_path / "fingerprints.json"
+        path.write_text("{not json")
+
+        assert PortalFingerprints(path).pending_change("phillips") is None
+
+
+class TestLoginActionFingerprint:
+    def test_login_form_is_fingerprinted(self, tmp_path):
+        fingerprints = PortalFingerprints(tmp_path / "fingerprints.json")
+        ctx = MagicMock()
+        ctx.config = ScraperConfig(name="phillips", base_url="https://example.com")
+        ctx.context = {}
+        ctx.is_session_authenticated.return_value = False
+        ctx.record_login = AsyncMock()
+        ctx.browser.page.content = AsyncMock(return_value=page(user_field="email"))
+
+        async def execute_step(step):
+            if step.action == "wait_for" and step.params.get("timeout") == 5:
+                raise TimeoutError("indicator not found")
+
+        ctx._execute_step = AsyncMock(side_effect=execute_step)
+        fingerprints.record("phillips", fingerprint_page(page()))
+        params = {
+            "url": "https://example.com/login",
+            "username": "buyer",
+            "password": "secret",
+            "username_field": "#user",
+            "password_field": "#pass",
+            "success_indicator": ".account",
+        }
+
+        with patch("scrapers.actions.handlers.login.portal_fingerprints", fingerprints):
+            asyncio.run(LoginAction(ctx).execute(params))
+
+        assert fingerprints.pending_change("phillips")["added_fields"] == ["input[type=text][name=email][id=user]"]
+
+    def test_redesign_is_caught_when_the_username_field_is_gone(self, tmp_path):
+        fingerprints = PortalFingerprints(tmp_path / "fingerprints.json")
+        fingerprints.record("phillips", fingerprint_page(page()))
+        ctx = MagicMock()
+        ctx.config = ScraperConfig(name="phillips", base_url="https://example.com")
+        ctx.context = {}
+        ctx.is_session_authenticated.return_value = False
+        ctx.browser.page.content = AsyncMock(return_value=page(user_field="email"))
+
+        async def execute_step(step):
+            if step.action == "wait_for":
+                raise TimeoutError(f"{step.params['selector']} not found")
+
+        ctx._execute_step = AsyncMock(side_effect=execute_step)
+        params = {"url": "https://example.com/login", "username": "buyer", "password": "secret", "username_field": "#username"}
+
+        with patch("scrapers.actions.handlers.login.portal_fingerprints", fingerprints), pytest.raises(WorkflowExecutionError):
+            asyncio.run(LoginAction(ctx).execute(params))
+
+        assert fingerprints.pending_change("phillips") is not None
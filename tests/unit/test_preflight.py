@@ -0,0 +1,62 @@
+from unittest.mock import patch
+
+import pytest
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from runner.preflight import (
+    ACTION_ADD_CREDENTIALS,
+    ACTION_FREE_DISK,
+    ACTION_INSTALL_BROWSER,
+    MESSAGE_CODES,
+    PreflightFailed,
+    preflight_skipped,
+    run_preflight,
+)
+from scrapers.models.config import ScraperConfig, WorkflowStep
+
+
+def _job(*names: str, options: dict | None = None) -> JobConfig:
+    return JobConfig(job_id="job-1", skus=["SKU1"], scrapers=[JobScraperConfig(name=n, options=options) for n in names])
+
+
+def _login_config(name: str = "phillips") -> ScraperConfig:
+    return ScraperConfig(name=name, base_url="https://example.com", workflows=[WorkflowStep(action="login")])
+
+
+class TestRunPreflight:
+    @pytest.fixture(autouse=True)
+    def _browsers(self, tmp_path, monkeypatch):
+        (tmp_path / "chromium-1148").mkdir()
+        monkeypatch.setenv("BROWSERS_DIR_OVERRIDE", str(tmp_path))
+        self.browsers_path = tmp_path
+
+    def test_passes_when_requirements_met(self):
+        config = ScraperConfig(name="bradley", base_url="https://example.com")
+        with patch("runner.preflight.runner_health.free_disk_mb", return_value=10_000):
+            run_preflight(_job("bradley"), [config])
+
+    def test_collects_every_issue(self):
+        (self.browsers_path / "chromium-1148").rmdir()
+
+        with patch("runner.preflight.runner_health.free_disk_mb", return_value=1), pytest.raises(PreflightFailed) as exc_info:
+            run_preflight(_job("phillips"), [_login_config()])
+
+        actions = {issue.action for issue in exc_info.value.issues}
+        assert actions == {ACTION_INSTALL_BROWSER, ACTION_ADD_CREDENTIALS, ACTION_FREE_DISK}
+        assert all(issue.code in MESSAGE_CODES for issue in exc_info.value.issues)
+        assert exc_info.value.to_dict()["issues"][0]["params"] == {"browser": "chromium", "path": str(self.browsers_path)}
+        assert exc_info.value.to_dict()["error"] == "preflight_failed"
+
+    def test_injected_credentials_satisfy_login(self):
+        job = _job("phillips", options={"_credentials": {"username": "u", "password": "p"}})
+        with patch("runner.preflight.runner_health.free_disk_mb", return_value=10_000):
+            run_preflight(job, [_login_config()])
+
+    def test_skip_preflight_flag(self, monkeypatch):
+        monkeypatch.delenv("SKIP_PREFLIGHT", raising=False)
+        job = _job("bradley")
+        assert preflight_skipped(job) is False
+
+        job.job_config = {"skip_preflight": True}
+        assert preflight_skipped(job) is True
@@ -0,0 +1,124 @@
+import pytest
+
+from core.models import ParsedPrice, PriceKind, PriceParseError, RawScrapedProduct, parse_price
+
+
+class TestParsePrice:
+    @pytest.mark.parametrize(
+        "raw, cents, currency",
+        [
+            # PetFoodExperts
+            ("$24.99", 2499, "USD"),
+            ("$1,234.56", 123456, "USD"),
+            ("Price: $8.50 /ea", 850, "USD"),
+            ("USD 42.00", 4200, "USD"),
+            ("$5", 500, "USD"),
+            # Phillips, including import lines priced in euros
+            ("1.234,56 €", 123456, "EUR"),
+            ("12,50 €", 1250, "EUR"),
+            ("EUR 9,9", 990, "EUR"),
+            ("1 234,56 €", 123456, "EUR"),
+            ("1\u00a0234,56\u00a0€", 123456, "EUR"),
+            ("$ 1,299", 129900, "USD"),
+            ("C$14.25", 1425, "CAD"),
+            ("£3.49", 349, "GBP"),
+            ("19.99", 1999, "USD"),
+            ("$.99", 99, "USD"),
+            (",5 €", 50, "EUR"),
+            # Codes we don't convert are reported as shown, not as USD
+            ("CHF 12.00", 1200, "CHF"),
+            ("12,00 SEK", 1200, "SEK"),
+        ],
+    )
+    def test_single_prices(self, raw, cents, currency):
+        parsed = parse_price(raw)
+
+        assert parsed.kind == PriceKind.PRICED
+        assert parsed.amount_cents == cents
+        assert parsed.max_cents is None
+        assert parsed.currency == currency
+        assert parsed.raw == raw
+
+    @pytest.mark.parametrize(
+        "raw, low, high, currency",
+        [
+            ("$10.99 - $12.99", 1099, 1299, "USD"),
+            ("$10.99–$12.99", 1099, 1299, "USD"),
+            ("10,99 € - 12,99 €", 1099, 1299, "EUR"),
+            ("$12.99 to $10.99", 1099, 1299, "USD"),
+        ],
+    )
+    def test_ranges(self, raw, low, high, currency):
+        parsed = parse_price(raw)
+
+        assert parsed.amount_cents == low
+        assert parsed.max_cents == high
+        assert parsed.currency == currency
+
+    @pytest.mark.parametrize(
+        "raw, kind",
+        [
+            ("Call for price", PriceKind.CALL_FOR_PRICE),
+            ("CALL FOR PRICING", PriceKind.CALL_FOR_PRICE),
+            ("Contact us for pricing", PriceKind.CALL_FOR_PRICE),
+            ("Call 800-555-1234 for price", PriceKind.CALL_FOR_PRICE),
+            ("MAP", PriceKind.MAP),
+            ("See price in cart", PriceKind.MAP),
+            ("Add to cart to see price", PriceKind.MAP),
+        ],
+    )
+    def test_sentinels_are_not_zero(self, raw, kind):
+        parsed = parse_price(raw)
+
+        assert parsed.kind == kind
+        assert parsed.amount_cents is None
+
+    @pytest.mark.parametrize(
+        "raw",
+        [
+            "N/A",
+            "Was $12.99 Now $10.99",
+            "1234.5678",
+            "12.34.5",
+        ],
+    )
+    def test_unparseable_preserves_original(self, raw):
+        with pytest.raises(PriceParseError) as exc_info:
+            parse_price(raw)
+
+        assert exc_info.value.raw == raw
+
+    @pytest.mark.parametrize("raw", [None, "", "   "])
+    def test_empty_is_none(self, raw):
+        assert parse_price(raw) is None
+
+    def test_numeric_input(self):
+        assert parse_price(12.5).amount_cents == 1250
+        assert parse_price(3).amount_cents == 300
+
+
+class TestRawScrapedProductPrice:
+    def test_db_dict_stores_cents_and_currency(self):
+        product = RawScrapedProduct(sku="123", source="phillips", scraped_price="1.234,56 €")
+
+        assert isinstance(product.scraped_price, ParsedPrice)
+        assert product.to_db_dict()["ScrapedPrice"] == {
+            "kind": "priced",
+            "amount_cents": 123456,
+            "max_cents": None,
+            "currency": "EUR",
+            "raw": "1.234,56 €",
+        }
+
+
+class TestResultCollectorPriceRejection:
+    def test_unparseable_price_is_rejected_with_original(self, tmp_path):
+        from scrapers.result_collector import ResultCollector
+
+        collector = ResultCollector(output_dir=str(tmp_path), test_mode=True)
+        collector.add_result("123", "petfoodexperts", {"Name": "Dog Food", "Price": "See store"})
+
+        assert collector.results["petfoodexperts"]["123"]["data"]["ScrapedPrice"] is None
+        assert collector.rejected == [
+            {"sku": "123", "scraper": "petfoodexperts", "field": "Price", "value": "See store", "reason": "no number found"}
+        ]
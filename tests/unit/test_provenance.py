@@ -0,0 +1,36 @@
+from core.api_client import ScraperConfig as ApiScraperConfig
+from scrapers.models.config import ScraperConfig, source_config_hash
+from scrapers.parser.yaml_parser import ScraperConfigParser
+
+
+def make_config(**overrides) -> ScraperConfig:
+    data = {"name": "phillips", "base_url": "https://example.com", "selectors": [{"name": "Name", "selector": "h1"}]}
+    data.update(overrides)
+    return ScraperConfig(**data)
+
+
+class TestConfigHash:
+    def test_hash_is_stable(self):
+        assert make_config().config_hash() == make_config().config_hash()
+        assert len(make_config().config_hash()) == 16
+
+    def test_hash_changes_with_config(self):
+        assert make_config().config_hash() != make_config(selectors=[{"name": "Name", "selector": "h1.title"}]).config_hash()
+
+    def test_hash_is_of_the_source_not_the_model(self):
+        source = {"name": "phillips", "base_url": "https://example.com", "selectors": [{"name": "Name", "selector": "h1"}]}
+        config = ScraperConfigParser().load_from_dict(dict(source))
+
+        assert config.config_hash() == source_config_hash(source)
+        assert config.config_hash() == source_config_hash(dict(reversed(list(source.items()))))
+
+    def test_recorded_source_overrides_the_built_dict(self):
+        config = make_config(timeout=45).with_source({"name": "phillips", "options": {"timeout": 30}})
+
+        assert config.config_hash() == source_config_hash({"name": "phillips", "options": {"timeout": 30}})
+
+    def test_injected_credentials_do_not_change_the_source_hash(self):
+        scraper = ApiScraperConfig(name="phillips", options={"timeout": 30})
+        with_credentials = ApiScraperConfig(name="phillips", options={"timeout": 30, "_credentials": {"username": "buyer"}})
+
+        assert with_credentials.source() == scraper.source()
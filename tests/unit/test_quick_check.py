@@ -0,0 +1,80 @@
+import asyncio
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+
+from core.block_cooldown import BlockCooldowns
+from runner.quick_check import QuickCheckRefused, quick_check
+from scrapers.models.config import ScraperConfig
+
+
+def make_config() -> ScraperConfig:
+    return ScraperConfig(name="phillips", base_url="https://example.com", workflows=[{"action": "navigate", "params": {"url": "https://example.com"}}])
+
+
+def make_executor(execute_workflow: AsyncMock) -> MagicMock:
+    executor = MagicMock()
+    executor.initialize = AsyncMock()
+    executor.browser.quit = AsyncMock()
+    executor.execute_workflow = execute_workflow
+    return executor
+
+
+class TestQuickCheck:
+    def test_more_than_five_skus_is_refused(self):
+        with pytest.raises(QuickCheckRefused) as exc:
+            asyncio.run(quick_check(make_config(), [f"SKU{i}" for i in range(6)]))
+
+        assert exc.value.to_dict()["error"] == "too_many_skus"
+
+    def test_scraper_in_block_cooldown_is_refused(self, tmp_path):
+        cooldowns = BlockCooldowns(tmp_path / "cooldowns.json")
+        cooldowns.record_blocked("phillips")
+
+        with patch("runner.quick_check.block_cooldowns", cooldowns), patch("scrapers.executor.workflow_executor.WorkflowExecutor") as executor_cls:
+            with pytest.raises(QuickCheckRefused) as exc:
+                asyncio.run(quick_check(make_config(), ["SKU1"]))
+
+        executor_cls.assert_not_called()
+        assert exc.value.error == "block_cooldown"
+        assert "resume_after" in exc.value.to_dict()
+
+    def test_records_are_returned_inline_with_timing(self, tmp_path):
+        execute = AsyncMock(
+            side_effect=[
+                {"success": True, "results": {"Name": "Dog Food", "Price": "$12.99", "Availability": "In Stock"}},
+                {"success": False, "error": "no_results"},
+            ]
+        )
+        executor = make_executor(execute)
+
+        with (
+            patch("runner.quick_check.block_cooldowns", BlockCooldowns(tmp_path / "cooldowns.json")),
+            patch("scrapers.executor.workflow_executor.WorkflowExecutor", return_value=executor),
+        ):
+            result = asyncio.run(quick_check(make_config(), ["SKU1", "SKU2"]))
+
+        first, second = result["results"]
+        assert first["success"] is True
+        assert first["record"]["title"] == "Dog Food"
+        assert first["record"]["scraped_price"]["amount_cents"] == 1299
+        assert first["record"]["availability_status"]["status"] == "in_stock"
+        assert "seconds" in first
+        assert second == {"sku": "SKU2", "success": False, "error": "no_results", "seconds": second["seconds"]}
+        assert result["success"] is False
+        executor.browser.quit.assert_awaited_once()
+
+    def test_timeout_marks_remaining_skus(self, tmp_path):
+        async def slow(**kwargs):
+            await asyncio.sleep(5)
+
+        executor = make_executor(AsyncMock(side_effect=slow))
+
+        with (
+            patch("runner.quick_check.block_cooldowns", BlockCooldowns(tmp_path / "cooldowns.json")),
+            patch("scrapers.executor.workflow_executor.WorkflowExecutor", return_value=executor),
+        ):
+            result = asyncio.run(quick_check(make_config(), ["SKU1", "SKU2"], timeout=0.05))
+
+        assert result["success"] is False
+        assert [entry["error"] for entry in result["results"]] == ["timed out", "timed out"]
@@ -0,0 +1,115 @@
+import hashlib
+import json
+import logging
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from runner import run_job
+from scrapers.models.config import REDACTED, ScraperConfig
+
+NET_PRICE = "$17.43"
+NET_DESCRIPTION = "Contract net pricing for Bay State only"
+
+
+def make_config(**overrides) -> ScraperConfig:
+    return ScraperConfig(name="phillips", base_url="https://example.com", **overrides)
+
+
+class TestRedactionConfig:
+    def test_unknown_fields_are_rejected(self):
+        with pytest.raises(ValueError, match="Unknown redact_fields net_price"):
+            make_config(redact_fields=["scraped_price", "net_price"])
+
+    def test_strip_removes_fields_and_flags_record(self):
+        config = make_config(redact_fields=["scraped_price", "description"])
+
+        record = config.redact_upload_record({"title": "Dog Food", "description": NET_DESCRIPTION, "scraped_price": {"amount_cents": 1743}})
+
+        assert record == {"title": "Dog Food", "redacted": True}
+
+    def test_hash_replaces_values(self):
+        config = make_config(redact_fields=["description"], redact_mode="hash")
+
+        first = config.redact_upload_record({"description": NET_DESCRIPTION}, key=b"runner-secret")
+        second = config.redact_upload_record({"description": NET_DESCRIPTION}, key=b"runner-secret")
+
+        assert first["description"].startswith("hmac-sha256:")
+        assert first == second
+        assert NET_DESCRIPTION not in json.dumps(first)
+
+    def test_hashed_price_is_not_a_plain_digest(self):
+        config = make_config(redact_fields=["scraped_price"], redact_mode="hash")
+        price = {"amount_cents": 1743}
+
+        record = config.redact_upload_record({"scraped_price": price}, key=b"runner-secret")
+        other_runner = config.redact_upload_record({"scraped_price": price}, key=b"another-secret")
+
+        plain = hashlib.sha256(json.dumps(price, sort_keys=True).encode("utf-8")).hexdigest()
+        assert plain not in record["scraped_price"]
+        assert record != other_runner
+
+    def test_unredacted_records_are_unchanged(self):
+        record = {"title": "Dog Food"}
+
+        assert make_config().redact_upload_record(record) is record
+        assert make_config().loggable("Price", NET_PRICE) == NET_PRICE
+        assert make_config(redact_fields=["scraped_price"]).loggable("Price", NET_PRICE) == REDACTED
+
+
+class TestRunJobRedaction:
+    def test_redacted_values_never_reach_upload_or_logs(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        job_config = JobConfig(
+            job_id="job-1",
+            skus=["SKU1"],
+            scrapers=[
+                JobScraperConfig(
+                    name="phillips",
+                    base_url="https://example.com",
+                    options={"redact_fields": ["scraped_price", "description"], "workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}]},
+                )
+            ],
+        )
+        executor = MagicMock()
+        executor.initialize = AsyncMock()
+        executor.browser.quit = AsyncMock()
+        executor.browser.current_url = "https://example.com/p/1"
+        executor.execute_workflow = AsyncMock(
+            return_value={"success": True, "results": {"Name": "Dog Food", "Brand": "Acme", "Price": NET_PRICE, "Description": NET_DESCRIPTION}}
+        )
+
+        records = []
+        handler = logging.Handler(level=logging.DEBUG)
+        handler.emit = lambda record: records.append(record.getMessage())
+        root = logging.getLogger()
+        previous_level = root.level
+        root.addHandler(handler)
+        root.setLevel(logging.DEBUG)
+        try:
+            with (
+                patch("runner.WorkflowExecutor", return_value=executor),
+                patch("scrapers.result_collector.ResultCollector._save_result_to_local") as save_local,
+            ):
+                results = run_job(job_config, runner_name="test-runner")
+        finally:
+            root.removeHandler(handler)
+            root.setLevel(previous_level)
+
+        uploaded = results["data"]["SKU1"]["phillips"]
+        assert uploaded["redacted"] is True
+        assert uploaded["title"] == "Dog Food"
+        assert "scraped_price" not in uploaded
+
+        body = json.dumps(results, default=str)
+        logs = "\n".join(records)
+        for secret in (NET_PRICE, "17.43", "1743", NET_DESCRIPTION):
+            assert secret not in body
+            assert secret not in logs
+
+        # The full record is still kept locally
+        local_data = save_local.call_args.args[2]
+        assert local_data["ScrapedPrice"]["amount_cents"] == 1743
+        assert local_data["Description"] == NET_DESCRIPTION
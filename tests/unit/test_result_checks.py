@@ -0,0 +1,148 @@
+from unittest.mock import AsyncMock, MagicMock, patch
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from runner import run_job
+from runner.github_summary import format_annotations, format_step_summary
+from runner.result_checks import check_results, compute_coverage, worst_outcome
+from scrapers.models.config import ResultChecksConfig
+
+
+def make_records(count: int, **overrides) -> dict[str, dict]:
+    records = {}
+    for i in range(count):
+        record = {
+            "title": f"Product {i}",
+            "scraped_price": {"amount_cents": 1000 + i, "currency": "USD"},
+            "availability": "In Stock" if i % 2 else "Out of Stock",
+        }
+        record.update(overrides)
+        records[f"SKU{i}"] = record
+    return records
+
+
+def checks_tripped(findings: list[dict]) -> set[str]:
+    return {f["check"] for f in findings}
+
+
+class TestCheckResults:
+    def test_plausible_results_have_no_findings(self):
+        assert check_results("phillips", make_records(20)) == []
+
+    def test_too_few_products_are_not_checked(self):
+        assert check_results("phillips", make_records(5, title=None, availability="In Stock")) == []
+
+    def test_empty_names(self):
+        records = make_records(20)
+        for sku in list(records)[:5]:
+            records[sku]["title"] = "  "
+
+        findings = check_results("phillips", records)
+
+        assert checks_tripped(findings) == {"empty_names"}
+        assert findings[0]["ratio"] == 0.25
+
+    def test_identical_prices(self):
+        findings = check_results("phillips", make_records(20, scraped_price={"amount_cents": 999, "currency": "USD"}))
+
+        assert checks_tripped(findings) == {"identical_prices"}
+        assert "999" in findings[0]["message"]
+
+    def test_uniform_availability_is_opt_in(self):
+        records = make_records(20, availability="In Stock")
+
+        assert check_results("phillips", records) == []
+        assert checks_tripped(check_results("phillips", records, ResultChecksConfig(check_availability=True))) == {"uniform_availability"}
+
+    def test_thresholds_are_configurable(self):
+        records = make_records(20, scraped_price={"amount_cents": 999, "currency": "USD"})
+
+        assert check_results("phillips", records, ResultChecksConfig(max_identical_price_ratio=1.0)) == []
+        assert check_results("phillips", make_records(20), ResultChecksConfig(min_products=50)) == []
+
+
+class TestResultWarningsSummary:
+    def test_warnings_are_annotated_without_failing_the_job(self):
+        results = {
+            "skus_processed": 20,
+            "scrapers_run": ["phillips"],
+            "data": {},
+            "result_warnings": [{"scraper": "phillips", "check": "empty_names", "message": "8/20 products have no name"}],
+        }
+
+        annotations = format_annotations("job-1", results, 10.0)
+        summary = format_step_summary("job-1", results, 10.0)
+
+        assert "::warning title=Result check::phillips: 8/20 products have no name" in annotations
+        assert "Completed with warnings" in summary
+        assert "### Result checks" in summary
+
+
+class TestCoverage:
+    SKUS = {f"SKU{i}" for i in range(100)}
+
+    def test_full_coverage_is_success(self):
+        coverage = compute_coverage(self.SKUS, set(self.SKUS), set(), min_coverage_percent=95)
+
+        assert coverage["outcome"] == "success"
+        assert coverage["percent"] == 100.0
+
+    def test_coverage_above_threshold_is_partial(self):
+        coverage = compute_coverage(self.SKUS, {f"SKU{i}" for i in range(98)}, set(), min_coverage_percent=95)
+
+        assert coverage["outcome"] == "partial"
+        assert coverage["percent"] == 98.0
+
+    def test_coverage_below_threshold_is_failed(self):
+        coverage = compute_coverage(self.SKUS, {f"SKU{i}" for i in range(60)}, set(), min_coverage_percent=95)
+
+        assert coverage["outcome"] == "failed"
+
+    def test_nothing_found_is_failed_without_threshold(self):
+        assert compute_coverage(self.SKUS, set(), set())["outcome"] == "failed"
+
+    def test_quarantined_skus_are_excluded(self):
+        found = {f"SKU{i}" for i in range(98)}
+        coverage = compute_coverage(self.SKUS, found, {"SKU98", "SKU99"}, min_coverage_percent=95)
+
+        assert coverage["attempted"] == 98
+        assert coverage["outcome"] == "success"
+
+    def test_job_outcome_is_the_worst_scraper(self):
+        assert worst_outcome(["success", "partial"]) == "partial"
+        assert worst_outcome(["partial", "failed", "success"]) == "failed"
+        assert worst_outcome([]) == "success"
+
+
+class TestRunJobCoverage:
+    def test_missing_skus_make_the_run_partial(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        executor = MagicMock()
+        executor.initialize = AsyncMock()
+        executor.browser.quit = AsyncMock()
+        executor.browser.current_url = "https://example.com/p"
+        executor.execute_workflow = AsyncMock(
+            side_effect=[
+                {"success": True, "results": {"Name": "Dog Food"}},
+                {"success": True, "results": {"Name": "Cat Food"}},
+                {"success": True, "results": {}},
+            ]
+        )
+        job = JobConfig(
+            job_id="job-1",
+            skus=["SKU1", "SKU2", "SKU3"],
+            scrapers=[
+                JobScraperConfig(
+                    name="phillips",
+                    base_url="https://example.com",
+                    options={"workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}], "min_coverage_percent": 50},
+                )
+            ],
+        )
+
+        with patch("runner.WorkflowExecutor", return_value=executor):
+            results = run_job(job, runner_name="test-runner")
+
+        assert results["coverage"]["phillips"]["found"] == 2
+        assert results["coverage"]["phillips"]["outcome"] == "partial"
+        assert results["outcome"] == "partial"
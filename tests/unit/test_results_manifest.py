@@ -0,0 +1,108 @@
+import base64
+import json
+import os
+
+from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PrivateKey
+from cryptography.hazmat.primitives.serialization import Encoding, NoEncryption, PrivateFormat, PublicFormat
+
+from core import results_manifest
+from core.results_manifest import build_manifest, chunk_digest, public_key, sign_manifest, verify_data, verify_results_file, verify_signature
+
+
+def make_key() -> str:
+    raw = Ed25519PrivateKey.generate().private_bytes(Encoding.Raw, PrivateFormat.Raw, NoEncryption())
+    return base64.b64encode(raw).decode("ascii")
+
+
+def make_data(count: int) -> dict:
+    return {f"SKU{i}": {"phillips": {"Name": f"Product {i}", "Price": f"{10 + i}.99"}} for i in range(count)}
+
+
+def chunked(data: dict, size: int) -> list[dict]:
+    rows = list(data.items())
+    return [dict(rows[i : i + size]) for i in range(0, len(rows), size)]
+
+
+class TestManifest:
+    def test_chunk_digest_ignores_key_order(self):
+        assert chunk_digest({"a": {"x": 1, "y": 2}}) == chunk_digest({"a": {"y": 2, "x": 1}})
+
+    def test_manifest_lists_each_chunk(self):
+        manifest = build_manifest("job-1", chunked(make_data(5), 2), 2, {"sidecar_version": "1.2.3"})
+
+        assert manifest["total_rows"] == 5
+        assert [(chunk["index"], chunk["rows"], chunk["first_sku"]) for chunk in manifest["chunks"]] == [(0, 2, "SKU0"), (1, 2, "SKU2"), (2, 1, "SKU4")]
+        assert manifest["provenance"] == {"sidecar_version": "1.2.3"}
+
+    def test_verify_data_pinpoints_the_tampered_chunk(self):
+        data = make_data(5)
+        manifest = build_manifest("job-1", chunked(data, 2), 2)
+        assert verify_data(manifest, data) == []
+
+        data["SKU3"]["phillips"]["Price"] = "0.01"
+
+        assert verify_data(manifest, data) == ["chunk 1 (from SKU SKU2) hash mismatch"]
+
+
+class TestSigning:
+    def setup_method(self):
+        results_manifest._signing_key = None
+
+    def test_unsigned_without_key(self, monkeypatch):
+        monkeypatch.delenv("RESULTS_SIGNING_KEY", raising=False)
+
+        signed = sign_manifest(build_manifest("job-1", [], 500))
+
+        assert signed["signature"] is None
+        assert public_key() is None
+
+    def test_signature_verifies_and_detects_changes(self, monkeypatch):
+        monkeypatch.setenv("RESULTS_SIGNING_KEY", make_key())
+        signed = sign_manifest(build_manifest("job-1", chunked(make_data(3), 2), 2))
+
+        assert signed["public_key"] == public_key()
+        assert verify_signature(signed["manifest"], signed["signature"], signed["public_key"]) is True
+        signed["manifest"]["total_rows"] = 2
+        assert verify_signature(signed["manifest"], signed["signature"], signed["public_key"]) is False
+
+    def test_key_is_read_once_and_dropped_from_the_environment(self, monkeypatch):
+        monkeypatch.setenv("RESULTS_SIGNING_KEY", make_key())
+
+        first = sign_manifest(build_manifest("job-1", [], 500))
+
+        assert "RESULTS_SIGNING_KEY" not in os.environ
+        assert sign_manifest(build_manifest("job-2", [], 500))["public_key"] == first["public_key"]
+
+    def test_invalid_key_is_ignored(self, monkeypatch):
+        monkeypatch.setenv("RESULTS_SIGNING_KEY", "not base64!")
+
+        assert sign_manifest(build_manifest("job-1", [], 500))["signature"] is None
+
+
+class TestVerifyResultsFile:
+    def setup_method(self):
+        self.data = make_data(5)
+
+    def write(self, tmp_path, monkeypatch):
+        monkeypatch.setenv("RESULTS_SIGNING_KEY", make_key())
+        signed = sign_manifest(build_manifest("job-1", chunked(self.data, 2), 2))
+        path = tmp_path / "results.json"
+        path.write_text(json.dumps({"data": self.data, "results_manifest": signed}))
+        return path
+
+    def test_intact_file_verifies(self, tmp_path, monkeypatch):
+        assert verify_results_file(self.write(tmp_path, monkeypatch)) == []
+
+    def test_changed_row_is_reported(self, tmp_path, monkeypatch):
+        path = self.write(tmp_path, monkeypatch)
+        content = json.loads(path.read_text())
+        content["data"]["SKU0"]["phillips"]["Price"] = "0.01"
+        path.write_text(json.dumps(content))
+
+        assert verify_results_file(path) == ["chunk 0 (from SKU SKU0) hash mismatch"]
+
+    def test_wrong_public_key_fails_signature(self, tmp_path, monkeypatch):
+        path = self.write(tmp_path, monkeypatch)
+        other = base64.b64encode(Ed25519PrivateKey.generate().public_key().public_bytes(Encoding.Raw, PublicFormat.Raw))
+
+        assert verify_results_file(path, other.decode("ascii")) == ["signature does not match the manifest"]
@@ -0,0 +1,84 @@
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+from pydantic import ValidationError
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from runner import ConfigurationError, run_job
+from runner.run_options import RunOptions, merge_options
+
+SETTINGS = {"headless": True, "timeout": 30, "browser_revision": None}
+
+
+class TestMergeOptions:
+    def test_run_options_beat_scraper_override_and_settings(self):
+        merged = merge_options(SETTINGS, {"timeout": 60, "browser_revision": "chromium-1140"}, {"timeout": 90})
+
+        assert merged == {"headless": True, "timeout": 90, "browser_revision": "chromium-1140"}
+
+    def test_unset_values_fall_through(self):
+        merged = merge_options(SETTINGS, {"timeout": None, "headless": False}, {"headless": None})
+
+        assert merged == {"headless": False, "timeout": 30, "browser_revision": None}
+
+    def test_settings_are_not_modified(self):
+        settings = dict(SETTINGS)
+        merge_options(settings, None, {"timeout": 90})
+
+        assert settings == SETTINGS
+
+
+class TestRunOptions:
+    def test_missing_run_options_are_empty(self):
+        assert RunOptions.from_job_config(None).model_dump(exclude_none=True) == {}
+        assert RunOptions.from_job_config({"skip_preflight": True}).model_dump(exclude_none=True) == {}
+
+    def test_invalid_timeout_is_rejected(self):
+        with pytest.raises(ValidationError):
+            RunOptions.from_job_config({"run_options": {"timeout": 0}})
+
+    def test_desktop_options_pass_through(self):
+        options = RunOptions.from_job_config({"run_options": {"headless": False, "chunk_size": 25}})
+
+        assert options.model_dump(exclude_none=True) == {"headless": False, "chunk_size": 25}
+
+
+def make_job(run_options: dict, scraper_options: dict | None = None) -> JobConfig:
+    return JobConfig(
+        job_id="job-1",
+        skus=["SKU1"],
+        job_config={"run_options": run_options},
+        scrapers=[
+            JobScraperConfig(
+                name="phillips",
+                base_url="https://example.com",
+                options={"workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}], **(scraper_options or {})},
+            )
+        ],
+    )
+
+
+class TestRunJobRunOptions:
+    def test_run_options_apply_to_the_job_and_provenance(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        executor = MagicMock()
+        executor.initialize = AsyncMock()
+        executor.browser.quit = AsyncMock()
+        executor.execute_workflow = AsyncMock(return_value={"success": True, "results": {"Name": "Dog Food"}})
+
+        with patch("runner.WorkflowExecutor", return_value=executor) as executor_cls:
+            results = run_job(make_job({"headless": False, "timeout": 90}, {"timeout": 60}), runner_name="test-runner")
+
+        kwargs = executor_cls.call_args.kwargs
+        assert kwargs["headless"] is False
+        assert kwargs["timeout"] == 90
+        assert results["provenance"]["run_options"] == {"headless": False, "timeout": 90}
+
+    def test_invalid_run_options_fail_the_job(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+
+        with patch("runner.WorkflowExecutor") as executor_cls, pytest.raises(ConfigurationError):
+            run_job(make_job({"timeout": "forever"}), runner_name="test-runner")
+
+        executor_cls.assert_not_called()
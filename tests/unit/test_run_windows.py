@@ -0,0 +1,122 @@
+from datetime import datetime, timezone
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+
+from core.api_client import JobConfig
+from core.api_client import ScraperConfig as JobScraperConfig
+from runner import _run_window_overrun, run_job
+from scrapers.models.config import RunWindow, ScraperConfig
+
+
+def utc(*args: int) -> datetime:
+    return datetime(*args, tzinfo=timezone.utc)
+
+
+def make_config(**overrides) -> ScraperConfig:
+    # Overnight outside store hours, every day, Eastern
+    windows = [{"days": ["mon", "tue", "wed", "thu", "fri", "sat", "sun"], "start": "18:00", "end": "08:00", "timezone": "America/New_York"}]
+    return ScraperConfig(name="phillips", base_url="https://example.com", run_windows=windows, **overrides)
+
+
+class TestRunWindowNextStart:
+    def test_overnight_window_opens_the_same_evening(self):
+        window = RunWindow(days=["mon", "tue", "wed", "thu", "fri"], start="22:00", end="06:00", timezone="UTC")
+
+        assert window.next_start(utc(2026, 1, 5, 12, 0)) == utc(2026, 1, 5, 22, 0)  # Mon noon
+        assert window.next_start(utc(2026, 1, 5, 22, 0)) == utc(2026, 1, 6, 22, 0)  # exactly at the start
+        assert window.next_start(utc(2026, 1, 9, 23, 0)) == utc(2026, 1, 12, 22, 0)  # Fri night -> Mon
+
+    def test_same_weekday_next_week(self):
+        window = RunWindow(days=["sun"], start="01:00", end="05:00", timezone="UTC")
+
+        assert window.next_start(utc(2026, 1, 4, 2, 0)) == utc(2026, 1, 11, 1, 0)
+
+    @pytest.mark.parametrize(
+        "now, expected",
+        [
+            (utc(2026, 3, 7, 12, 0), utc(2026, 3, 7, 23, 0)),  # Sat before spring forward, 18:00 EST
+            (utc(2026, 3, 8, 12, 0), utc(2026, 3, 8, 22, 0)),  # Sun after spring forward, 18:00 EDT
+            (utc(2026, 11, 1, 12, 0), utc(2026, 11, 1, 23, 0)),  # Sun after fall back, 18:00 EST
+        ],
+    )
+    def test_start_follows_dst(self, now, expected):
+        window = RunWindow(days=["sat", "sun"], start="18:00", end="08:00", timezone="America/New_York")
+
+        assert window.next_start(now) == expected
+
+
+class TestScraperConfigRunWindows:
+    @pytest.mark.parametrize(
+        "now, opens_at",
+        [
+            (utc(2026, 1, 6, 0, 0), None),  # Mon 19:00 EST, open
+            (utc(2026, 1, 6, 12, 59), None),  # Tue 07:59 EST, still open from Monday night
+            (utc(2026, 1, 6, 13, 0), utc(2026, 1, 6, 23, 0)),  # Tue 08:00 EST, store hours
+            (utc(2026, 3, 8, 11, 30), None),  # Sun 07:30 EDT, overnight window spanning spring forward
+            (utc(2026, 3, 8, 12, 0), utc(2026, 3, 8, 22, 0)),  # Sun 08:00 EDT
+        ],
+    )
+    def test_opens_at(self, now, opens_at):
+        assert make_config().run_window_opens_at(now) == opens_at
+
+    def test_no_windows_means_any_time(self):
+        config = ScraperConfig(name="phillips", base_url="https://example.com")
+
+        assert config.run_window_opens_at(utc(2026, 1, 6, 15, 0)) is None
+
+    def test_overrun_waits_for_grace_period(self):
+        config = make_config(run_window_grace_minutes=30)
+
+        assert _run_window_overrun(config, utc(2026, 1, 6, 13, 20)) is None  # 20 minutes after close
+        assert _run_window_overrun(config, utc(2026, 1, 6, 13, 31)) == utc(2026, 1, 6, 23, 0)
+
+
+class TestRunJobRunWindows:
+    def setup_method(self):
+        self.job = JobConfig(
+            job_id="job-1",
+            skus=["SKU1", "SKU2", "SKU3"],
+            scrapers=[
+                JobScraperConfig(
+                    name="phillips",
+                    base_url="https://example.com",
+                    options={
+                        "workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}],
+                        "run_windows": [{"days": ["sun"], "start": "01:00", "end": "05:00"}],
+                    },
+                )
+            ],
+        )
+
+    def test_deferred_outside_run_window(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        opens_at = utc(2026, 1, 11, 6, 0)
+
+        with patch.object(ScraperConfig, "run_window_opens_at", return_value=opens_at), patch("runner.WorkflowExecutor") as executor_cls:
+            results = run_job(self.job, runner_name="test-runner")
+
+        executor_cls.assert_not_called()
+        assert results["deferred_scrapers"] == [{"scraper": "phillips", "reason": "outside_run_window", "resume_after": opens_at.isoformat()}]
+
+    def test_holds_back_remaining_skus_when_window_closes(self, monkeypatch):
+        monkeypatch.setenv("SKIP_PREFLIGHT", "1")
+        executor = MagicMock()
+        executor.initialize = AsyncMock()
+        executor.browser.quit = AsyncMock()
+        executor.browser.current_url = "https://example.com/p"
+        executor.execute_workflow = AsyncMock(return_value={"success": True, "results": {"Name": "Dog Food"}})
+        reopens_at = utc(2026, 1, 11, 6, 0)
+
+        with (
+            patch.object(ScraperConfig, "run_window_opens_at", return_value=None),
+            patch("runner._run_window_overrun", side_effect=[None, reopens_at]),
+            patch("runner.WorkflowExecutor", return_value=executor),
+        ):
+            results = run_job(self.job, runner_name="test-runner")
+
+        assert executor.execute_workflow.await_count == 1
+        assert results["deferred_scrapers"][0]["reason"] == "run_window_closed"
+        assert len(results["deferred_scrapers"][0]["skus"]) == 2
+        assert results["coverage"]["phillips"]["attempted"] == 1
+        assert results["outcome"] == "success"
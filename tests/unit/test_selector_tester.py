@@ -0,0 +1,76 @@
+import asyncio
+from unittest.mock import AsyncMock, MagicMock, patch
+
+from runner.selector_tester import MAX_SAMPLE_MATCHES, run_selector_test
+from scrapers.models.config import ScraperConfig
+
+
+def make_config(**overrides) -> ScraperConfig:
+    data = {"name": "phillips", "base_url": "https://example.com", "workflows": [{"action": "navigate", "params": {"url": "https://example.com"}}]}
+    data.update(overrides)
+    return ScraperConfig(**data)
+
+
+def make_executor(match_count: int) -> MagicMock:
+    locator = MagicMock()
+    locator.count = AsyncMock(return_value=match_count)
+    locator.nth.return_value.evaluate = AsyncMock(return_value={"tag": "span", "text": "Dog Food", "attributes": {}})
+    locator.evaluate_all = AsyncMock()
+
+    page = MagicMock()
+    page.url = "https://example.com/p/1"
+    page.locator.return_value = locator
+    page.screenshot = AsyncMock(return_value=b"png")
+
+    executor = MagicMock()
+    executor.initialize = AsyncMock()
+    executor.execute_steps = AsyncMock()
+    executor.browser.get = AsyncMock()
+    executor.browser.quit = AsyncMock()
+    executor.browser.page = page
+    return executor
+
+
+class TestRunSelectorTest:
+    def test_reports_matches_and_closes_browser(self):
+        config = make_config()
+        executor = make_executor(match_count=8)
+        executor.config = config
+
+        with patch("scrapers.executor.workflow_executor.WorkflowExecutor", return_value=executor):
+            result = asyncio.run(run_selector_test(config, "https://example.com/p/1", ".title"))
+
+        assert result["success"] is True
+        assert result["match_count"] == 8
+        assert len(result["matches"]) == MAX_SAMPLE_MATCHES
+        assert result["screenshot_base64"] == "cG5n"
+        executor.execute_steps.assert_not_called()
+        executor.browser.quit.assert_awaited_once()
+
+    def test_xpath_selectors_are_prefixed(self):
+        config = make_config()
+        executor = make_executor(match_count=0)
+        executor.config = config
+
+        with patch("scrapers.executor.workflow_executor.WorkflowExecutor", return_value=executor):
+            result = asyncio.run(run_selector_test(config, "https://example.com/p/1", "//h1", selector_type="xpath"))
+
+        assert result["match_count"] == 0
+        executor.browser.page.locator.assert_called_once_with("xpath=//h1")
+
+    def test_timeout_still_closes_browser(self):
+        config = make_config()
+        executor = make_executor(match_count=1)
+        executor.config = config
+
+        async def hang():
+            await asyncio.sleep(10)
+
+        executor.initialize = AsyncMock(side_effect=hang)
+
+        with patch("scrapers.executor.workflow_executor.WorkflowExecutor", return_value=executor):
+            result = asyncio.run(run_selector_test(config, "https://example.com/p/1", ".title", timeout=0.05))
+
+        assert result["success"] is False
+        assert "timed out" in result["error"]
+        executor.browser.quit.assert_awaited_once()
@@ -0,0 +1,166 @@
+import asyncio
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+
+from core.session_cache import SessionCache, account_key
+from scrapers.actions.handlers.login import LoginAction
+from scrapers.exceptions import WorkflowExecutionError
+from scrapers.executor.workflow_executor import WorkflowExecutor
+from scrapers.models.config import ScraperConfig
+
+STATE = {"cookies": [{"name": "sid", "value": "abc", "domain": "example.com", "path": "/"}], "origins": []}
+
+
+class TestSessionCache:
+    def test_restore_returns_the_cached_session(self):
+        cache = SessionCache()
+        assert cache.restore("phillips") is None
+
+        cache.record_login("phillips", 4.25, STATE)
+
+        assert cache.restore("phillips") == STATE
+        assert cache.stats() == {"phillips": {"logins": 1, "login_seconds": 4.25, "sessions_reused": 0}}
+
+    def test_reuse_is_counted_only_when_recorded(self):
+        cache = SessionCache()
+        cache.record_login("phillips", 1.0, STATE)
+
+        cache.restore("phillips")
+        cache.record_reuse("phillips")
+
+        assert cache.stats()["phillips"]["sessions_reused"] == 1
+
+    def test_sessions_are_kept_per_account(self):
+        cache = SessionCache()
+        cache.record_login("phillips", 1.0, STATE, account_key("buyer"))
+
+        assert cache.restore("phillips", account_key("buyer")) == STATE
+        assert cache.restore("phillips", account_key("other-buyer")) is None
+        assert account_key("buyer") != "buyer"
+
+    def test_login_without_state_keeps_previous_session(self):
+        cache = SessionCache()
+        cache.record_login("phillips", 1.0, STATE)
+        cache.record_login("phillips", 1.0, None)
+
+        assert cache.restore("phillips") == STATE
+        assert cache.stats()["phillips"]["logins"] == 2
+
+
+def make_ctx(already_logged_in: bool) -> MagicMock:
+    ctx = MagicMock()
+    ctx.config = ScraperConfig(name="phillips", base_url="https://example.com")
+    ctx.context = {}
+    ctx.is_session_authenticated.return_value = False
+    ctx.record_login = AsyncMock()
+    ctx.record_session_reused = MagicMock()
+
+    async def execute_step(step):
+        # The quick 5s check for the success indicator only passes if already logged in
+        if step.action == "wait_for" and step.params.get("timeout") == 5 and not already_logged_in:
+            raise TimeoutError("indicator not found")
+
+    ctx._execute_step = AsyncMock(side_effect=execute_step)
+    return ctx
+
+
+LOGIN_PARAMS = {
+    "url": "https://example.com/login",
+    "username": "buyer",
+    "password": "secret",
+    "username_field": "#user",
+    "password_field": "#pass",
+    "submit_button": "#submit",
+    "success_indicator": ".account",
+}
+
+
+class TestLoginAction:
+    def test_fresh_login_fills_form_and_records_login(self):
+        ctx = make_ctx(already_logged_in=False)
+
+        asyncio.run(LoginAction(ctx).execute(dict(LOGIN_PARAMS)))
+
+        actions = [call.args[0].action for call in ctx._execute_step.await_args_list]
+        assert actions == ["navigate", "wait_for", "wait_for", "input_text", "input_text", "click", "wait_for"]
+        ctx.mark_session_authenticated.assert_called_once()
+        ctx.record_login.assert_awaited_once()
+
+    def test_restored_session_skips_the_form(self):
+        ctx = make_ctx(already_logged_in=True)
+
+        asyncio.run(LoginAction(ctx).execute(dict(LOGIN_PARAMS)))
+
+        actions = [call.args[0].action for call in ctx._execute_step.await_args_list]
+        assert actions == ["navigate", "wait_for"]
+        ctx.mark_session_authenticated.assert_called_once()
+        ctx.record_login.assert_not_awaited()
+        ctx.record_session_reused.assert_called_once()
+
+
+class TestRunnerLoginStats:
+    def test_reports_logins_since_job_start(self):
+        from runner import _logins_since
+
+        cache = SessionCache()
+        cache.record_login("phillips", 3.0, STATE)
+        before = cache.stats()
+        cache.record_reuse("phillips")
+        cache.record_login("orgill", 2.5, STATE)
+
+        with patch("runner.session_cache", cache):
+            logins = _logins_since(before)
+
+        assert logins == {
+            "phillips": {"logins": 0, "login_seconds": 0.0, "sessions_reused": 1},
+            "orgill": {"logins": 1, "login_seconds": 2.5, "sessions_reused": 0},
+        }
+
+
+class TestMidRunExpiry:
+    def make_executor(self):
+        config = ScraperConfig(
+            name="phillips",
+            base_url="https://example.com",
+            workflows=[
+                {"action": "login", "params": dict(LOGIN_PARAMS)},
+                {"action": "navigate", "params": {"url": "https://example.com/p/{sku}"}},
+            ],
+        )
+        executor = WorkflowExecutor(config, headless=True)
+        executor.browser = MagicMock()
+        executor.browser.current_url = "https://example.com/p/SKU1"
+        executor.mark_session_authenticated()
+        return executor
+
+    def test_logs_in_again_once_when_sent_back_to_the_login_form(self):
+        executor = self.make_executor()
+        runs = []
+
+        async def run_workflow(context):
+            runs.append(executor.session_authenticated)
+            if len(runs) == 1:
+                executor.browser.current_url = "https://example.com/login?next=/p/SKU1"
+                raise WorkflowExecutionError("selector not found")
+            return {"success": True}
+
+        with patch.object(executor, "_run_workflow", side_effect=run_workflow):
+            result = asyncio.run(executor.execute_workflow({"sku": "SKU1"}, quit_browser=False))
+
+        assert result == {"success": True}
+        # The second run starts unauthenticated, so its login step logs in again
+        assert runs == [True, False]
+
+    def test_other_failures_are_not_retried(self):
+        executor = self.make_executor()
+        executor.find_element_safe = AsyncMock(return_value=None)
+
+        with patch.object(executor, "_run_workflow", side_effect=WorkflowExecutionError("selector not found")) as run_workflow:
+            with pytest.raises(WorkflowExecutionError):
+                asyncio.run(executor.execute_workflow({"sku": "SKU1"}, quit_browser=False))
+
+        assert run_workflow.await_count == 1
+
+    def test_session_is_cached_per_username(self):
+        assert self.make_executor()._session_account() == account_key("buyer")
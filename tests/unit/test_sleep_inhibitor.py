@@ -0,0 +1,119 @@
+import subprocess
+import sys
+import threading
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from core.sleep_inhibitor import ES_CONTINUOUS, ES_SYSTEM_REQUIRED, SleepInhibitor, _start_assertion, _WindowsAssertion
+
+
+class TestSleepInhibitor:
+    def setup_method(self):
+        self.patches = [patch("core.sleep_inhibitor._start_assertion", return_value="handle"), patch("core.sleep_inhibitor._stop_assertion")]
+        self.start, self.stop = (p.start() for p in self.patches)
+
+    def teardown_method(self):
+        for p in self.patches:
+            p.stop()
+
+    def test_holds_only_while_a_job_runs(self):
+        inhibitor = SleepInhibitor(enabled=True)
+
+        with inhibitor.hold("job-1"):
+            assert inhibitor.held is True
+            self.start.assert_called_once_with("job-1")
+
+        assert inhibitor.held is False
+        self.stop.assert_called_once_with("handle")
+
+    def test_nested_holds_share_one_assertion(self):
+        inhibitor = SleepInhibitor(enabled=True)
+
+        inhibitor.acquire("job-1")
+        inhibitor.acquire("job-2")
+        inhibitor.release()
+        assert inhibitor.held is True
+
+        inhibitor.release()
+        assert self.start.call_count == 1
+        self.stop.assert_called_once()
+        assert inhibitor.held is False
+
+    def test_released_when_job_fails(self):
+        inhibitor = SleepInhibitor(enabled=True)
+
+        with pytest.raises(RuntimeError), inhibitor.hold("job-1"):
+            raise RuntimeError("boom")
+
+        self.stop.assert_called_once()
+        assert inhibitor.held is False
+
+    def test_disabled_takes_no_assertion(self):
+        with SleepInhibitor(enabled=False).hold("job-1"):
+            pass
+
+        self.start.assert_not_called()
+
+    def test_failure_to_take_assertion_doesnt_block_the_job(self):
+        inhibitor = SleepInhibitor(enabled=True)
+        self.start.side_effect = OSError("no dbus")
+
+        with inhibitor.hold("job-1"):
+            assert inhibitor.held is False
+
+    def test_env_opt_out(self, monkeypatch):
+        monkeypatch.setenv("PREVENT_SLEEP_DURING_JOBS", "false")
+        assert SleepInhibitor().enabled is False
+
+        monkeypatch.delenv("PREVENT_SLEEP_DURING_JOBS")
+        assert SleepInhibitor().enabled is True
+
+
+class FakeKernel32:
+    def __init__(self, result: int = 1):
+        self.result = result
+        self.calls: list[tuple[str, int]] = []
+
+    def SetThreadExecutionState(self, flags: int) -> int:
+        self.calls.append((threading.current_thread().name, flags))
+        return self.result
+
+
+class TestWindowsAssertion:
+    def test_set_and_cleared_on_one_dedicated_thread(self):
+        kernel32 = FakeKernel32()
+
+        assertion = _WindowsAssertion(kernel32)
+        assertion.stop()
+
+        assert kernel32.calls == [("sleep-inhibitor", ES_CONTINUOUS | ES_SYSTEM_REQUIRED), ("sleep-inhibitor", ES_CONTINUOUS)]
+
+    def test_failure_is_raised_to_the_caller(self):
+        with pytest.raises(OSError, match="SetThreadExecutionState failed"):
+            _WindowsAssertion(FakeKernel32(result=0))
+
+
+class TestSpawnedAssertion:
+    def setup_method(self):
+        self.patches = [patch.object(sys, "platform", "linux"), patch("shutil.which", return_value="/usr/bin/systemd-inhibit")]
+        for p in self.patches:
+            p.start()
+
+    def teardown_method(self):
+        for p in self.patches:
+            p.stop()
+
+    def test_inhibitor_that_exits_at_once_is_an_error(self):
+        process = MagicMock()
+        process.wait.return_value = 1
+
+        with patch("core.sleep_inhibitor.subprocess.Popen", return_value=process), pytest.raises(OSError, match="systemd-inhibit exited with status 1"):
+            _start_assertion("job-1")
+
+    def test_running_inhibitor_is_returned(self):
+        process = MagicMock()
+        process.wait.side_effect = subprocess.TimeoutExpired("systemd-inhibit", 0.5)
+
+        with patch("core.sleep_inhibitor.subprocess.Popen", return_value=process):
+            assert _start_assertion("job-1") is process
@@ -0,0 +1,100 @@
+import os
+from unittest.mock import patch
+
+from core import version_info
+from core.version_info import browsers_dir_conflict, collect_version_info, resolve_browsers_dir
+
+
+class TestCollectVersionInfo:
+    def test_reports_runtime_versions(self):
+        info = collect_version_info()
+
+        assert info["python_version"]
+        assert info["os"]
+        assert "sidecar_version" in info
+
+    def test_one_failing_field_does_not_fail_the_rest(self):
+        def broken():
+            raise RuntimeError("not installed")
+
+        with patch.dict(version_info.FIELDS, {"playwright_version": broken}):
+            info = collect_version_info()
+
+        assert info["playwright_version"] is None
+        assert info["errors"] == {"playwright_version": "RuntimeError: not installed"}
+        assert info["python_version"]
+
+    def test_lists_installed_browsers(self, tmp_path, monkeypatch):
+        (tmp_path / "chromium-1140").mkdir()
+        (tmp_path / ".links").mkdir()
+        monkeypatch.setenv("BROWSERS_DIR_OVERRIDE", str(tmp_path))
+
+        assert resolve_browsers_dir().path == tmp_path
+        assert collect_version_info()["browsers"] == ["chromium-1140"]
+        assert collect_version_info()["browsers_dir_source"] == "override"
+
+
+    def test_reports_recorded_download_host(self, tmp_path, monkeypatch):
+        monkeypatch.setenv("BROWSERS_DIR_OVERRIDE", str(tmp_path))
+        assert collect_version_info()["browser_download_host"] is None
+
+        (tmp_path / ".download-host").write_text("https://mirror.example.com/playwright\n")
+
+        assert collect_version_info()["browser_download_host"] == "https://mirror.example.com/playwright"
+        assert collect_version_info()["browsers"] == []
+
+
+class TestResolveBrowsersDir:
+    def setup_method(self):
+        self.env = patch.dict("os.environ", {}, clear=False)
+        self.env.start()
+        for name in ("BROWSERS_DIR_OVERRIDE", "HONOR_ENV_BROWSERS_PATH", "PLAYWRIGHT_BROWSERS_PATH"):
+            os.environ.pop(name, None)
+
+    def teardown_method(self):
+        self.env.stop()
+
+    def test_env_var_is_ignored_unless_honored(self, tmp_path):
+        (tmp_path / "default" / "chromium-1148").mkdir(parents=True)
+        os.environ["PLAYWRIGHT_BROWSERS_PATH"] = str(tmp_path / "env")
+
+        with patch("core.version_info.default_browsers_path", return_value=tmp_path / "default"):
+            assert resolve_browsers_dir().source == "default"
+
+            os.environ["HONOR_ENV_BROWSERS_PATH"] = "true"
+            assert resolve_browsers_dir() == (tmp_path / "env", "env")
+
+    def test_env_var_is_the_fallback_when_the_default_has_no_chromium(self, tmp_path):
+        os.environ["PLAYWRIGHT_BROWSERS_PATH"] = str(tmp_path / "env")
+
+        with patch("core.version_info.default_browsers_path", return_value=tmp_path / "default"):
+            assert resolve_browsers_dir() == (tmp_path / "env", "env_fallback")
+
+            (tmp_path / "default" / "chromium-1148").mkdir(parents=True)
+            assert resolve_browsers_dir() == (tmp_path / "default", "default")
+
+    def test_override_wins_over_env_var(self, tmp_path):
+        os.environ["PLAYWRIGHT_BROWSERS_PATH"] = str(tmp_path / "other")
+        os.environ["HONOR_ENV_BROWSERS_PATH"] = "true"
+        os.environ["BROWSERS_DIR_OVERRIDE"] = str(tmp_path / "ours")
+
+        assert resolve_browsers_dir() == (tmp_path / "ours", "override")
+
+    def test_conflict_needs_two_populated_directories(self, tmp_path):
+        (tmp_path / "ours" / "chromium-1148").mkdir(parents=True)
+        (tmp_path / "other").mkdir()
+        os.environ["BROWSERS_DIR_OVERRIDE"] = str(tmp_path / "ours")
+        os.environ["PLAYWRIGHT_BROWSERS_PATH"] = str(tmp_path / "other")
+
+        assert browsers_dir_conflict() is None
+
+        (tmp_path / "other" / "chromium-1140").mkdir()
+        assert browsers_dir_conflict() == {
+            "env_path": str(tmp_path / "other"),
+            "resolved_path": str(tmp_path / "ours"),
+            "source": "override",
+        }
+
+        os.environ["HONOR_ENV_BROWSERS_PATH"] = "true"
+        os.environ.pop("BROWSERS_DIR_OVERRIDE")
+        assert browsers_dir_conflict() is None
@@ -114,6 +114,20 @@ class ConfigNormalizer:
             "test_skus",
             "fake_skus",
             "edge_case_skus",
+            "browser_revision",
+            "maintenance_windows",
+            "run_windows",
+            "run_window_grace_minutes",
+            "adaptive_pacing",
+            "golden",
+            "redact_fields",
+            "redact_mode",
+            "log_redaction_patterns",
+            "output_dir",
+            "availability_mappings",
+            "dedup_policy",
+            "result_checks",
+            "min_coverage_percent",
         ]:
             if field in self.yaml_data:
                 normalized[field] = self.yaml_data[field]
@@ -9,6 +9,8 @@ from logging.handlers import RotatingFileHandler
 from pathlib import Path
 from typing import Any
 
+from utils.structured_logging import SensitiveDataFilter
+
 # Define project root
 PROJECT_ROOT = os.path.dirname(os.path.dirname(os.path.dirname(os.path.abspath(__file__))))
 
@@ -113,6 +115,9 @@ def setup_logging(
     if logger.hasHandlers():
         logger.handlers.clear()
 
+    # Every handler redacts secrets and PII before a record is written
+    sensitive_filter = SensitiveDataFilter()
+
     # Create formatter
     if json_output and not use_pretty:
         json_formatter = JSONFormatter()
@@ -120,6 +125,7 @@ def setup_logging(
         console_handler = logging.StreamHandler(sys.stdout)
         console_handler.setFormatter(json_formatter)
         console_handler.addFilter(NoHttpFilter())
+        console_handler.addFilter(sensitive_filter)
         logger.addHandler(console_handler)
     else:
         # Pretty output for local development
@@ -130,6 +136,7 @@ def setup_logging(
         console_handler = logging.StreamHandler(sys.stdout)
         console_handler.setFormatter(console_formatter)
         console_handler.addFilter(NoHttpFilter())
+        console_handler.addFilter(sensitive_filter)
         logger.addHandler(console_handler)
 
     # Optional: File handler for persistent logs
@@ -142,6 +149,7 @@ def setup_logging(
         file_formatter = JSONFormatter()
         file_handler = RotatingFileHandler(log_file, maxBytes=10 * 1024 * 1024, backupCount=5, encoding="utf-8")
         file_handler.setFormatter(file_formatter)
+        file_handler.addFilter(sensitive_filter)
         logger.addHandler(file_handler)
 
     _logging_configured = True
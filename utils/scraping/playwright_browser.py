@@ -9,6 +9,7 @@ from __future__ import annotations
 
 import asyncio
 import os
+import re
 import shutil
 import time
 from typing import Any
@@ -41,6 +42,68 @@ from playwright.sync_api import (
 )
 from playwright_stealth import Stealth
 
+# Root the desktop app installs pinned browser revisions under, one subdir per revision.
+BROWSER_REVISIONS_DIR_ENV = "BROWSER_REVISIONS_DIR"
+
+CHROMIUM_DIR_PATTERN = re.compile(r"^chromium-(\d+)$")
+# Where the Chromium binary sits inside a chromium-<revision> directory, per platform build
+CHROMIUM_EXECUTABLES = (
+    "chrome-linux/chrome",
+    "chrome-linux64/chrome",
+    "chrome-win/chrome.exe",
+    "chrome-win64/chrome.exe",
+    "chrome-mac/Chromium.app/Contents/MacOS/Chromium",
+    "chrome-mac-arm64/Chromium.app/Contents/MacOS/Chromium",
+    "chrome-mac-x64/Chromium.app/Contents/MacOS/Chromium",
+)
+
+
+def resolve_browsers_path(revision: str | None) -> str | None:
+    """Return the pinned revision's browsers directory if it is installed, else None to launch Playwright's own build."""
+    if not revision:
+        return None
+
+    root = os.environ.get(BROWSER_REVISIONS_DIR_ENV)
+    if not root:
+        print(f"[WARN] browser_revision '{revision}' requested but {BROWSER_REVISIONS_DIR_ENV} is not set; using default browser")
+        return None
+
+    path = os.path.join(root, revision)
+    if not os.path.isdir(path):
+        print(f"[WARN] browser_revision '{revision}' is not installed at {path}; using default browser")
+        return None
+    return path
+
+
+def export_browsers_dir() -> None:
+    """Point Playwright's own browser lookup at the resolved browsers dir (see core.version_info).
+
+    The driver reads PLAYWRIGHT_BROWSERS_PATH when it starts. Every launch sets
+    the same value, so concurrent launches can't pick up each other's directory.
+    """
+    from core.version_info import resolve_browsers_dir
+
+    path = resolve_browsers_dir().path
+    if path is not None:
+        os.environ["PLAYWRIGHT_BROWSERS_PATH"] = str(path)
+
+
+def chromium_executable(browsers_path: str) -> str | None:
+    """The Chromium binary of the newest revision installed under browsers_path, or None if there is none."""
+    try:
+        entries = os.listdir(browsers_path)
+    except OSError:
+        return None
+    revisions = sorted(
+        (int(match.group(1)), name) for name in entries if (match := CHROMIUM_DIR_PATTERN.match(name))
+    )
+    for _, name in reversed(revisions):
+        for relative in CHROMIUM_EXECUTABLES:
+            path = os.path.join(browsers_path, name, relative)
+            if os.path.isfile(path):
+                return path
+    return None
+
 
 class PlaywrightScraperBrowser:
     """
@@ -54,6 +117,9 @@ class PlaywrightScraperBrowser:
         profile_suffix: str | None = None,
         custom_options: list[str] | None = None,
         timeout: int = 30,
+        slow_mo_ms: int = 0,
+        browsers_path: str | None = None,
+        storage_state: dict[str, Any] | None = None,
     ) -> None:
         """
         Initialize browser for scraping.
@@ -64,12 +130,18 @@ class PlaywrightScraperBrowser:
             profile_suffix: Optional suffix for profile directory (unused in ephemeral context)
             custom_options: Additional Chrome args to add
             timeout: Default timeout in seconds
+            slow_mo_ms: Delay Playwright inserts between operations (debug runs only)
+            browsers_path: Pinned revision's browsers directory to launch Chromium from (see resolve_browsers_path)
+            storage_state: Cookies and local storage to start from, e.g. a cached login session
         """
         self.site_name = site_name
         self.headless = headless
         self.profile_suffix = profile_suffix
         self.timeout = timeout * 1000  # Convert to ms
         self.custom_options = custom_options or []
+        self.slow_mo_ms = max(0, slow_mo_ms)
+        self.browsers_path = browsers_path
+        self.storage_state = storage_state
 
         self.playwright: Playwright | None = None
         self.browser: Browser | None = None
@@ -84,6 +156,13 @@ class PlaywrightScraperBrowser:
         print(f"[WEB] [{self.site_name}] Initializing Playwright (Async)...")
 
         try:
+            # A pinned revision launches its binary directly. Otherwise Playwright resolves the build matching
+            # its own version (and the headless shell for headless runs) from PLAYWRIGHT_BROWSERS_PATH.
+            executable_path = chromium_executable(self.browsers_path) if self.browsers_path else None
+            if self.browsers_path and executable_path is None:
+                print(f"[WARN] [{self.site_name}] No Chromium found under {self.browsers_path}; using Playwright's default")
+            if executable_path is None:
+                export_browsers_dir()
             self.playwright = await async_playwright().start()
 
             # Construct launch arguments
@@ -104,6 +183,8 @@ class PlaywrightScraperBrowser:
             self.browser = await self.playwright.chromium.launch(
                 headless=self.headless,
                 args=args,
+                slow_mo=self.slow_mo_ms or None,
+                executable_path=executable_path,
             )
 
             # Create context with standard viewport and user agent
@@ -111,6 +192,7 @@ class PlaywrightScraperBrowser:
                 viewport={"width": 1920, "height": 1080},
                 user_agent="Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
                 device_scale_factor=1,
+                storage_state=self.storage_state,
             )
 
             # Initialize page
@@ -147,6 +229,12 @@ class PlaywrightScraperBrowser:
             print(f"[WARN] [{self.site_name}] Navigation error: {e}")
             raise
 
+    async def export_storage_state(self) -> dict[str, Any] | None:
+        """Current cookies and local storage, for reusing a login session."""
+        if not self.context:
+            return None
+        return await self.context.storage_state()
+
     async def check_http_status(self) -> int | None:
         """Check the HTTP status code of the last response."""
         if self._last_response:
@@ -199,6 +287,9 @@ async def create_playwright_browser(
     profile_suffix: str | None = None,
     custom_options: list[str] | None = None,
     timeout: int = 30,
+    slow_mo_ms: int = 0,
+    browsers_path: str | None = None,
+    storage_state: dict[str, Any] | None = None,
 ) -> PlaywrightScraperBrowser:
     """Factory for Async Browser."""
     browser = PlaywrightScraperBrowser(
@@ -207,6 +298,9 @@ async def create_playwright_browser(
         profile_suffix,
         custom_options,
         timeout,
+        slow_mo_ms,
+        browsers_path,
+        storage_state,
     )
     await browser.initialize()
     return browser
@@ -3,7 +3,9 @@ Structured logging utilities for BayStateScraper.
 
 Provides:
 - JSONFormatter: Formats logs as JSON for log aggregation
+- LogScrubber: Redacts secrets and PII from log text
 - SensitiveDataFilter: Redacts sensitive data from log records
+- LogBuffer: Job log entries with caps on message length and retained entries
 - generate_trace_id: Generates unique trace IDs for request tracking
 - setup_structured_logging: Configures structured logging for the application
 """
@@ -14,31 +16,191 @@ import json
 import logging
 import re
 import sys
+import threading
 import uuid
+from collections import Counter
+from collections.abc import Iterable
 from datetime import datetime
 from logging import LogRecord
-from typing import Any
+from typing import Any, SupportsIndex
+
+
+# Ordered (rule, pattern) pairs; earlier rules run first, so a Bearer token is
+# redacted before the Authorization header around it
+DEFAULT_REDACTION_RULES: list[tuple[str, str]] = [
+    ("api_key", r"bsr_[a-zA-Z0-9]{32,}"),
+    ("api_key", r'X-API-Key["\']?\s*[:=]\s*["\']?[a-zA-Z0-9_-]+'),
+    ("token", r"Bearer\s+[a-zA-Z0-9_\-\.]+"),
+    ("password", r'["\']?password["\']?\s*[:=]\s*["\']?[^\s"\']+'),
+    ("auth", r'Authorization["\']?\s*[:=]\s*["\']?(?:(?:Basic|Digest|Token)\s+)?[^\s"\']+'),
+    ("email", r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}"),
+    # 15-16 digits, optionally grouped by spaces or dashes. Shorter runs are UPCs/EANs and GTIN-14s.
+    ("card", r"(?<![\d-])\d(?:[ -]?\d){14,15}(?![\d-])"),
+]
+
+
+def _luhn_valid(digits: str) -> bool:
+    total = 0
+    for i, char in enumerate(reversed(digits)):
+        n = int(char)
+        if i % 2:
+            n = n * 2 - 9 if n > 4 else n * 2
+        total += n
+    return total % 10 == 0
+
+
+class LogScrubber:
+    """
+    Redacts secrets and PII from log text, replacing matches with [REDACTED:<rule>].
+
+    Patterns are compiled once. Scrapers can add their own rules (e.g. supplier
+    account numbers) which then apply to every line, since log lines can't be
+    reliably attributed to a scraper, until the job removes them again.
+    """
+
+    def __init__(self, rules: list[tuple[str, str]] | None = None) -> None:
+        self._default_rules = [(name, re.compile(pattern, re.IGNORECASE)) for name, pattern in (rules or DEFAULT_REDACTION_RULES)]
+        self._scraper_rules: dict[str, list[tuple[str, re.Pattern[str]]]] = {}
+        self._rules = list(self._default_rules)
+        self._lock = threading.Lock()
+
+    def set_scraper_rules(self, scraper: str, patterns: dict[str, str]) -> None:
+        """Replace a scraper's extra rules (rule name -> regex)."""
+        compiled = [(name, re.compile(pattern)) for name, pattern in patterns.items()]
+        with self._lock:
+            if compiled:
+                self._scraper_rules[scraper] = compiled
+            else:
+                self._scraper_rules.pop(scraper, None)
+            self._rules = self._default_rules + [rule for rules in self._scraper_rules.values() for rule in rules]
+
+    def scrub(self, text: str, hits: Counter[str] | None = None) -> str:
+        """Return text with every rule's matches replaced, adding the redactions per rule to `hits`."""
+        if not text:
+            return text
+        if hits is None:
+            hits = Counter()
+        for name, pattern in self._rules:
+
+            def _replace(match: re.Match[str], name: str = name) -> str:
+                if name == "card" and not _luhn_valid(re.sub(r"[ -]", "", match.group(0))):
+                    return match.group(0)
+                hits[name] += 1
+                return f"[REDACTED:{name}]"
+
+            text = pattern.sub(_replace, text)
+        return text
+
+
+# Process-wide scrubber shared by the log filter and the job log buffers
+log_scrubber = LogScrubber()
+
+
+# Caps on job log buffers, so a scraper stuck printing a huge stack trace in a
+# loop can't grow the results (and the desktop app's copy of them) unboundedly
+MAX_LOG_MESSAGE_CHARS = 10_000
+MAX_LOG_ENTRIES = 5_000
+# Characters scrubbed past the cut, longer than any secret the rules are meant to catch
+SCRUB_MARGIN_CHARS = 1_000
+
+
+def truncate_log_message(message: str, limit: int = MAX_LOG_MESSAGE_CHARS) -> tuple[str, int]:
+    """Message cut to `limit` characters with a marker, and how many characters were cut."""
+    if len(message) <= limit:
+        return message, 0
+    cut = len(message) - limit
+    return f"{message[:limit]}... [truncated {cut} chars]", cut
+
+
+def scrub_log_message(message: str, hits: Counter[str] | None = None, limit: int = MAX_LOG_MESSAGE_CHARS) -> tuple[str, int]:
+    """
+    Message scrubbed and then cut to `limit` characters, and how many characters were cut.
+
+    Scrubbing first means a cut can't split a secret so that no rule matches
+    what's left of it. Only the first limit + SCRUB_MARGIN_CHARS characters are
+    scrubbed, so a huge line costs no more than a long one, and a secret
+    straddling the cut is still whole in the scrubbed text however small the
+    limit is.
+    """
+    window = limit + SCRUB_MARGIN_CHARS
+    if len(message) <= window:
+        return truncate_log_message(log_scrubber.scrub(message, hits), limit)
+    cut = len(message) - limit
+    return f"{log_scrubber.scrub(message[:window], hits)[:limit]}... [truncated {cut} chars]", cut
+
+
+class LogBuffer(list):
+    """
+    Job log entries keeping only the most recent `max_entries`.
+
+    Oldest entries are dropped as new ones arrive, however they are added
+    (append, extend, += or insert). Dropped entries and entries
+    whose message was truncated are counted so the job can report them, as are
+    the redactions made in every entry appended, dropped or not.
+    """
+
+    def __init__(self, max_entries: int = MAX_LOG_ENTRIES) -> None:
+        super().__init__()
+        self.max_entries = max_entries
+        self.dropped = 0
+        self.truncated = 0
+        self.redactions: Counter[str] = Counter()
+
+    def append(self, entry: dict[str, Any]) -> None:  # type: ignore[override]
+        self._track(entry)
+        super().append(entry)
+        self._trim_batch()
+
+    def extend(self, entries: Iterable[dict[str, Any]]) -> None:  # type: ignore[override]
+        for entry in entries:
+            self.append(entry)
+
+    def __iadd__(self, entries: Iterable[dict[str, Any]]) -> LogBuffer:  # type: ignore[override]
+        self.extend(entries)
+        return self
+
+    def insert(self, index: SupportsIndex, entry: dict[str, Any]) -> None:
+        self._track(entry)
+        super().insert(index, entry)
+        self._trim_batch()
+
+    def _track(self, entry: dict[str, Any]) -> None:
+        if entry.get("truncated_chars"):
+            self.truncated += 1
+        self.redactions.update(entry.get("redactions") or {})
+
+    def _trim_batch(self) -> None:
+        # Trim in batches so a long run doesn't shift the whole list on every append
+        if len(self) > self.max_entries + max(1, self.max_entries // 10):
+            excess = len(self) - self.max_entries
+            del self[:excess]
+            self.dropped += excess
+
+    def stats(self) -> dict[str, int]:
+        """Entries dropped and entries truncated so far, with the buffer trimmed to its cap."""
+        if len(self) > self.max_entries:
+            excess = len(self) - self.max_entries
+            del self[:excess]
+            self.dropped += excess
+        return {"dropped_lines": self.dropped, "truncated_lines": self.truncated}
 
 
 class SensitiveDataFilter(logging.Filter):
     """
     Log filter that redacts sensitive data from log records.
 
-    Sensitive patterns include:
+    Uses the shared LogScrubber, so redaction covers:
     - API keys (bsr_*, X-API-Key headers)
     - Passwords
     - Tokens
     - Authorization headers
+    - Email addresses and card-like numbers
+    - Scraper-specific rules such as account numbers
     """
 
-    # Patterns that match sensitive data
-    SENSITIVE_PATTERNS = [
-        (r"bsr_[a-zA-Z0-9]{32,}", "[API_KEY_REDACTED]"),
-        (r'X-API-Key["\']?\s*[:=]\s*["\']?[a-zA-Z0-9_-]+', "[API_KEY_REDACTED]"),
-        (r"Bearer\s+[a-zA-Z0-9_\-\.]+", "[TOKEN_REDACTED]"),
-        (r'["\']?password["\']?\s*[:=]\s*["\']?[^\s"\']+', "[PASSWORD_REDACTED]"),
-        (r'Authorization["\']?\s*[:=]\s*["\']?[^\s"\']+', "[AUTH_REDACTED]"),
-    ]
+    def __init__(self, scrubber: LogScrubber | None = None) -> None:
+        super().__init__()
+        self.scrubber = scrubber or log_scrubber
 
     def filter(self, record: LogRecord) -> bool:
         """Redact sensitive data from log message and extra fields."""
@@ -57,14 +219,15 @@ class SensitiveDataFilter(logging.Filter):
         if hasattr(record, "trace_id") and record.trace_id:
             record.trace_id = self._redact(str(record.trace_id)) if record.trace_id else None
 
+        # Tracebacks often carry the same values as the message
+        if record.exc_info and not record.exc_text:
+            record.exc_text = self._redact(logging.Formatter().formatException(record.exc_info))
+
         return True
 
     def _redact(self, text: str) -> str:
         """Apply all redaction patterns to text."""
-        result = text
-        for pattern, replacement in self.SENSITIVE_PATTERNS:
-            result = re.sub(pattern, replacement, result, flags=re.IGNORECASE)
-        return result
+        return self.scrubber.scrub(text)
 
 
 class JSONFormatter(logging.Formatter):
@@ -106,7 +269,7 @@ class JSONFormatter(logging.Formatter):
 
         # Add exception info if present
         if record.exc_info:
-            log_data["exception"] = self.formatException(record.exc_info)
+            log_data["exception"] = record.exc_text or self.formatException(record.exc_info)
 
         # Add extra fields
         for key, value in record.__dict__.items():